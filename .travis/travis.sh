@@ -16,7 +16,7 @@ if [[ `git status --porcelain` ]]; then
   exit 1
 fi
 
-cargo test --all
+cargo test --all --all-features
 
 if [[ "$KOTLIN_TESTS" == "true" ]]; then
   cd platforms/kotlin
@@ -3,8 +3,7 @@ extern crate prettytable;
 
 use prettytable::Table;
 use snips_nlu_ontology::*;
-use std::fs::File;
-use std::io::prelude::*;
+use std::fs;
 
 fn main() {
     let mut readme = String::new();
@@ -13,11 +12,22 @@ fn main() {
     add_supported_builtin_entities(&mut readme);
     add_grammar_entity_documentation(&mut readme);
     add_gazetteer_entity_documentation(&mut readme);
+    add_value_type_documentation(&mut readme);
     add_builtin_entities_examples(&mut readme);
     add_footer(&mut readme);
 
-    let mut file = File::create("../README.rst").unwrap();
-    file.write_all(readme.as_bytes()).unwrap();
+    write_if_changed("../README.rst", &readme);
+
+    let entities_documentation = docs::render_markdown(&complete_entity_ontology());
+    write_if_changed("../ENTITIES.md", &entities_documentation);
+}
+
+/// Only rewrites the file when its content changed, so that unchanged documentation is left
+/// untouched
+fn write_if_changed(path: &str, content: &str) {
+    if fs::read_to_string(path).ok().as_deref() != Some(content) {
+        fs::write(path, content).unwrap();
+    }
 }
 
 fn add_header(readme: &mut String) {
@@ -63,10 +73,13 @@ fn add_supported_builtin_entities(readme: &mut String) {
     all_entities.sort_by(|a, b| a.identifier().cmp(b.identifier()));
 
     for entity in all_entities.clone() {
-        let category = BuiltinGazetteerEntityKind::from_identifier(entity.identifier())
-            .ok()
-            .map(|_| "`Gazetteer Entity`_".to_string())
-            .unwrap_or_else(|| "`Grammar Entity`_".to_string());
+        let category = if BuiltinGazetteerEntityKind::from_identifier(entity.identifier()).is_ok() {
+            "`Gazetteer Entity`_"
+        } else if GrammarEntityKind::from_identifier(entity.identifier()).is_ok() {
+            "`Grammar Entity`_"
+        } else {
+            "`Value Type`_"
+        };
         table.add_row(row![entity.to_string(), entity.identifier(), category]);
     }
     readme.push_str(&*table.to_string());
@@ -123,6 +136,20 @@ fn add_gazetteer_entity_documentation(readme: &mut String) {
     readme.push_str("\n");
 }
 
+fn add_value_type_documentation(readme: &mut String) {
+    readme.push_str("Value Type\n");
+    readme.push_str("----------\n");
+
+    readme.push('\n');
+
+    readme.push_str(
+        "Value types are builtin entities whose resolved values are part of the ontology, but \
+         which are not extracted by any grammar or gazetteer parser yet.\n",
+    );
+
+    readme.push('\n');
+}
+
 fn add_builtin_entity_results_examples(readme: &mut String, entity: BuiltinEntityKind) {
     let mut entity_title = Table::new();
     entity_title.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
@@ -135,7 +162,7 @@ fn add_builtin_entity_results_examples(readme: &mut String, entity: BuiltinEntit
     readme.push_str("\n");
     readme.push_str(".. code-block:: json\n");
     readme.push_str("\n   ");
-    readme.push_str(&*entity.result_description().replace("\n", "\n   "));
+    readme.push_str(&*entity.result_description_json().replace("\n", "\n   "));
     readme.push_str("\n\n");
 }
 
@@ -7,12 +7,12 @@ use ffi_utils::{point_to_string, CStringArray, RawPointerConverter};
 use lazy_static::lazy_static;
 use libc;
 use snips_nlu_ontology::{
-    BuiltinEntity, BuiltinEntityKind, BuiltinGazetteerEntityKind, GrammarEntityKind,
-    IntoBuiltinEntityKind,
+    complete_entity_ontology, BuiltinEntity, BuiltinEntityKind, BuiltinGazetteerEntityKind,
+    GrammarEntityKind, IntoBuiltinEntityKind,
 };
 use std::convert::From;
 use std::ffi::{CStr, CString};
-use std::slice;
+use std::ptr;
 
 #[repr(C)]
 #[derive(Debug)]
@@ -22,6 +22,12 @@ pub struct CBuiltinEntity {
     pub value: *const libc::c_char,
     pub range_start: i32,
     pub range_end: i32,
+    /// Whether the entity is explicitly negated, 0 for false and 1 for true
+    pub negated: libc::c_int,
+    /// Start index of the entity in the text in UTF-16 code units, or -1 when unknown
+    pub utf16_range_start: i32,
+    /// End index of the entity in the text in UTF-16 code units, or -1 when unknown
+    pub utf16_range_end: i32,
 }
 
 impl From<BuiltinEntity> for CBuiltinEntity {
@@ -32,10 +38,27 @@ impl From<BuiltinEntity> for CBuiltinEntity {
             value: CString::new(e.value).unwrap().into_raw(),
             range_start: e.range.start as i32,
             range_end: e.range.end as i32,
+            negated: e.negated as libc::c_int,
+            utf16_range_start: -1,
+            utf16_range_end: -1,
         }
     }
 }
 
+impl CBuiltinEntity {
+    /// Converts an entity extracted from the given text, along with its range in UTF-16 code
+    /// units
+    pub fn from_entity_in_text(entity: BuiltinEntity, text: &str) -> Self {
+        let utf16_range = entity.utf16_range(text).ok();
+        let mut c_entity = Self::from(entity);
+        if let Some(utf16_range) = utf16_range {
+            c_entity.utf16_range_start = utf16_range.start as i32;
+            c_entity.utf16_range_end = utf16_range.end as i32;
+        }
+        c_entity
+    }
+}
+
 impl Drop for CBuiltinEntity {
     fn drop(&mut self) {
         take_back_c_string!(self.value);
@@ -62,8 +85,8 @@ impl From<Vec<CBuiltinEntity>> for CBuiltinEntityArray {
 impl Drop for CBuiltinEntityArray {
     fn drop(&mut self) {
         let _ = unsafe {
-            Box::from_raw(slice::from_raw_parts_mut(
-                self.data as *mut CBuiltinEntityArray,
+            Box::from_raw(ptr::slice_from_raw_parts_mut(
+                self.data as *mut CBuiltinEntity,
                 self.size as usize,
             ))
         };
@@ -145,3 +168,8 @@ pub fn get_builtin_entity_shortname(
     let entity_kind = BuiltinEntityKind::from_identifier(entity_str)?;
     point_to_string(result, entity_kind.to_string())
 }
+
+pub fn get_complete_entity_ontology_json(result: *mut *const libc::c_char) -> Result<()> {
+    let ontology = serde_json::to_string(&complete_entity_ontology())?;
+    point_to_string(result, ontology)
+}
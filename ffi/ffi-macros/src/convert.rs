@@ -0,0 +1,312 @@
+//! Conversion of the ontology values to and from the C representations which are passed behind
+//! untyped pointers, such as the value of a `CSlotValue`
+//!
+//! The pointer casts and the transfers of ownership are written once here, so that supporting a
+//! new value only requires its C representation, along with `From` and `AsRust` conversions. The
+//! tests count the allocations of the current thread to check that every C representation is
+//! released exactly once, and can also be run under Miri with
+//! `cargo +nightly miri test -p snips-nlu-ontology-ffi-macros convert`.
+use crate::ontology::*;
+use failure::{bail, Fallible};
+use ffi_utils::{create_rust_string_from, AsRust, RawPointerConverter};
+use snips_nlu_ontology::*;
+use std::ffi::CString;
+
+/// C representation of a Rust value, allocated on the heap and passed behind an untyped pointer
+pub trait CReprConvert<T>: Sized {
+    /// Moves the C representation of the value to the heap, the returned pointer being owned by
+    /// the caller until it is given back to `drop_c_repr`
+    fn into_c_repr(value: T) -> *const libc::c_void;
+
+    /// Converts the C representation back to a Rust value, the pointer remaining owned by the
+    /// caller
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been returned by `into_c_repr` for the same C representation, and
+    /// must not have been dropped.
+    unsafe fn from_c_repr(pointer: *const libc::c_void) -> Fallible<T>;
+
+    /// Frees the C representation, after which the pointer must not be used anymore
+    ///
+    /// # Safety
+    ///
+    /// The pointer must have been returned by `into_c_repr` for the same C representation, and
+    /// must not have been dropped.
+    unsafe fn drop_c_repr(pointer: *const libc::c_void) -> Fallible<()>;
+}
+
+/// Implements `CReprConvert` for C representations which convert from and back to their Rust
+/// value using `From` and `AsRust`
+macro_rules! impl_c_repr_convert {
+    ($($c_repr:ty => $value:ty,)*) => {
+        $(
+            impl CReprConvert<$value> for $c_repr {
+                fn into_c_repr(value: $value) -> *const libc::c_void {
+                    <$c_repr>::from(value).into_raw_pointer() as _
+                }
+
+                unsafe fn from_c_repr(pointer: *const libc::c_void) -> Fallible<$value> {
+                    if pointer.is_null() {
+                        bail!("Unexpected null pointer to a {}", stringify!($c_repr));
+                    }
+                    (*(pointer as *const $c_repr)).as_rust()
+                }
+
+                unsafe fn drop_c_repr(pointer: *const libc::c_void) -> Fallible<()> {
+                    <$c_repr>::drop_raw_pointer(pointer as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_c_repr_convert!(
+    COrdinalValue => OrdinalValue,
+    CInstantTimeValue => InstantTimeValue,
+    CTimeIntervalValue => TimeIntervalValue,
+    CAmountOfMoneyValue => AmountOfMoneyValue,
+    CTemperatureValue => TemperatureValue,
+    CDurationValue => DurationValue,
+    CRelativeChangeValue => RelativeChangeValue,
+    CFlightNumberValue => FlightNumberValue,
+    CProductCodeValue => ProductCodeValue,
+    CVersionValue => VersionValue,
+    CMediaPositionValue => MediaPositionValue,
+    CScoreValue => ScoreValue,
+    CCookingQuantityValue => CookingQuantityValue,
+    CBloodPressureValue => BloodPressureValue,
+    CDosageValue => DosageValue,
+    CGeoCoordinateValue => GeoCoordinateValue,
+    CReferenceValue => ReferenceValue,
+);
+
+/// String values are represented by a `char *`
+impl CReprConvert<StringValue> for CString {
+    fn into_c_repr(value: StringValue) -> *const libc::c_void {
+        CString::new(value.value).unwrap().into_raw() as _
+    }
+
+    unsafe fn from_c_repr(pointer: *const libc::c_void) -> Fallible<StringValue> {
+        if pointer.is_null() {
+            bail!("Unexpected null pointer to a string value");
+        }
+        Ok(create_rust_string_from!(pointer as *const libc::c_char).into())
+    }
+
+    unsafe fn drop_c_repr(pointer: *const libc::c_void) -> Fallible<()> {
+        CString::drop_raw_pointer(pointer)
+    }
+}
+
+/// Numbers and percentages are represented by a `double *`
+macro_rules! impl_c_repr_convert_for_double {
+    ($($value:ident,)*) => {
+        $(
+            impl CReprConvert<$value> for libc::c_double {
+                fn into_c_repr(value: $value) -> *const libc::c_void {
+                    value.value.into_raw_pointer() as _
+                }
+
+                unsafe fn from_c_repr(pointer: *const libc::c_void) -> Fallible<$value> {
+                    if pointer.is_null() {
+                        bail!("Unexpected null pointer to a {}", stringify!($value));
+                    }
+                    Ok($value {
+                        value: *(pointer as *const libc::c_double),
+                    })
+                }
+
+                unsafe fn drop_c_repr(pointer: *const libc::c_void) -> Fallible<()> {
+                    libc::c_double::drop_raw_pointer(pointer as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_c_repr_convert_for_double!(NumberValue, PercentageValue,);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin_entity::{CBuiltinEntity, CBuiltinEntityArray};
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Allocator keeping track of the number of live allocations of each thread, so that tests
+    /// running in parallel do not interfere
+    struct CountingAllocator;
+
+    thread_local! {
+        static LIVE_ALLOCATIONS: Cell<isize> = const { Cell::new(0) };
+    }
+
+    fn add_live_allocations(delta: isize) {
+        // The counter is unavailable while the thread is being torn down
+        let _ = LIVE_ALLOCATIONS.try_with(|count| count.set(count.get() + delta));
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            add_live_allocations(1);
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            add_live_allocations(1);
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            add_live_allocations(-1);
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Runs the closure and checks that it released exactly the memory it allocated, which fails
+    /// on a leak as well as on a double free
+    fn assert_released<F: FnOnce()>(f: F) {
+        let before = LIVE_ALLOCATIONS.with(Cell::get);
+        f();
+        let after = LIVE_ALLOCATIONS.with(Cell::get);
+        assert_eq!(before, after, "unbalanced allocations");
+    }
+
+    fn c_repr_round_trip<T, U>(input: T)
+    where
+        T: Clone + PartialEq + std::fmt::Debug,
+        U: CReprConvert<T>,
+    {
+        assert_released(|| {
+            // Given
+            let pointer = U::into_c_repr(input.clone());
+
+            // When
+            let first = unsafe { U::from_c_repr(pointer) }.unwrap();
+            let second = unsafe { U::from_c_repr(pointer) }.unwrap();
+            unsafe { U::drop_c_repr(pointer) }.unwrap();
+
+            // Then
+            assert_eq!(input, first);
+            assert_eq!(input, second);
+        });
+    }
+
+    #[test]
+    fn test_allocations_are_counted() {
+        let mut leaked = None;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            assert_released(|| leaked = Some(Box::new(42)))
+        }));
+        assert!(result.is_err());
+        drop(leaked);
+    }
+
+    #[test]
+    fn test_c_repr_round_trips() {
+        c_repr_round_trip::<_, CString>(StringValue::from("Paris"));
+        c_repr_round_trip::<_, libc::c_double>(NumberValue { value: 42. });
+        c_repr_round_trip::<_, libc::c_double>(PercentageValue { value: 12.5 });
+        c_repr_round_trip::<_, COrdinalValue>(OrdinalValue {
+            value: 2,
+            direction: OrdinalDirection::FromEnd,
+        });
+        c_repr_round_trip::<_, CTimeIntervalValue>(TimeIntervalValue {
+            from: None,
+            to: Some("2017-06-08 00:00:00 +02:00".to_string()),
+            to_inclusive: false,
+        });
+        c_repr_round_trip::<_, CDosageValue>(DosageValue {
+            count: 2.,
+            dose: Some(500.),
+            unit: Some("mg".to_string()),
+            form: None,
+        });
+        c_repr_round_trip::<_, CReferenceValue>(ReferenceValue {
+            anaphora: Anaphora::Same,
+        });
+    }
+
+    #[test]
+    fn test_null_c_repr_is_rejected() {
+        let string: Fallible<StringValue> = unsafe { CString::from_c_repr(std::ptr::null()) };
+        let score: Fallible<ScoreValue> = unsafe { CScoreValue::from_c_repr(std::ptr::null()) };
+        assert!(string.is_err());
+        assert!(score.is_err());
+    }
+
+    #[test]
+    fn test_c_slot_values_are_released() {
+        // Given
+        let values = vec![
+            SlotValue::Custom("blue".into()),
+            SlotValue::Number(NumberValue { value: 3. }),
+            SlotValue::RelativeChange(RelativeChangeValue {
+                direction: ChangeDirection::Increase,
+                value: None,
+                unit: Some("degree".to_string()),
+                magnitude: Some(ChangeMagnitude::Slight),
+            }),
+            SlotValue::Score(ScoreValue {
+                first: 3,
+                second: 2,
+            }),
+        ];
+
+        assert_released(|| {
+            // When
+            let c_values = CSlotValueArray::from(values.clone());
+            let round_tripped = c_values.as_rust().unwrap();
+            drop(c_values);
+
+            // Then
+            assert_eq!(values, round_tripped);
+        });
+    }
+
+    #[test]
+    fn test_c_builtin_entities_are_released() {
+        // Given
+        let entities = vec![
+            BuiltinEntity::new(
+                "three".to_string(),
+                4..9,
+                SlotValue::Number(NumberValue { value: 3. }),
+                BuiltinEntityKind::Number,
+            ),
+            BuiltinEntity::new(
+                "tomorrow".to_string(),
+                10..18,
+                SlotValue::InstantTime(InstantTimeValue {
+                    value: "2017-06-14 00:00:00 +02:00".to_string(),
+                    grain: Grain::Day,
+                    precision: Precision::Exact,
+                }),
+                BuiltinEntityKind::Datetime,
+            ),
+        ];
+
+        assert_released(|| {
+            // When
+            let c_entities = CBuiltinEntityArray::from(
+                entities
+                    .clone()
+                    .into_iter()
+                    .map(|entity| CBuiltinEntity::from_entity_in_text(entity, "get three tomorrow"))
+                    .collect::<Vec<_>>(),
+            );
+
+            // Then
+            assert_eq!(entities.len(), c_entities.size as usize);
+            drop(c_entities);
+        });
+    }
+}
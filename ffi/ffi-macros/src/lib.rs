@@ -1,10 +1,14 @@
 mod builtin_entity;
+mod convert;
 mod language;
 mod ontology;
+mod unwind;
 pub use builtin_entity::*;
+pub use convert::*;
 pub use language::*;
 pub use ontology::*;
 use snips_nlu_ontology::*;
+pub use unwind::*;
 
 #[macro_export]
 macro_rules! export_nlu_ontology_c_symbols {
@@ -14,7 +18,9 @@ macro_rules! export_nlu_ontology_c_symbols {
             ptr: *mut ::ffi_utils::CStringArray,
         ) -> ::ffi_utils::SNIPS_RESULT {
             use ffi_utils::RawPointerConverter;
-            wrap!(unsafe { ::ffi_utils::CStringArray::from_raw_pointer(ptr) })
+            wrap!($crate::catch_panic(|| unsafe {
+                ::ffi_utils::CStringArray::from_raw_pointer(ptr).map(|_| ())
+            }))
         }
 
         #[no_mangle]
@@ -22,12 +28,14 @@ macro_rules! export_nlu_ontology_c_symbols {
             ptr: *mut libc::c_char,
         ) -> ::ffi_utils::SNIPS_RESULT {
             use ffi_utils::RawPointerConverter;
-            wrap!(unsafe { ::std::ffi::CString::from_raw_pointer(ptr) })
+            wrap!($crate::catch_panic(|| unsafe {
+                ::std::ffi::CString::from_raw_pointer(ptr).map(|_| ())
+            }))
         }
 
         #[no_mangle]
         pub extern "C" fn snips_nlu_ontology_supported_languages() -> ::ffi_utils::CStringArray {
-            $crate::supported_languages()
+            $crate::catch_panic_or($crate::empty_string_array(), $crate::supported_languages)
         }
 
         #[no_mangle]
@@ -35,22 +43,33 @@ macro_rules! export_nlu_ontology_c_symbols {
             entity_name: *const libc::c_char,
             result: *mut *const libc::c_char,
         ) -> ::ffi_utils::SNIPS_RESULT {
-            wrap!($crate::get_builtin_entity_shortname(entity_name, result))
+            wrap!($crate::catch_panic(
+                || $crate::get_builtin_entity_shortname(entity_name, result)
+            ))
+        }
+
+        #[no_mangle]
+        pub extern "C" fn snips_nlu_ontology_complete_entity_ontology_json(
+            result: *mut *const libc::c_char,
+        ) -> ::ffi_utils::SNIPS_RESULT {
+            wrap!($crate::catch_panic(|| {
+                $crate::get_complete_entity_ontology_json(result)
+            }))
         }
 
         #[no_mangle]
         pub extern "C" fn snips_nlu_ontology_all_builtin_entities() -> ::ffi_utils::CStringArray {
-            $crate::all_builtin_entities()
+            $crate::catch_panic_or($crate::empty_string_array(), $crate::all_builtin_entities)
         }
 
         #[no_mangle]
         pub extern "C" fn snips_nlu_ontology_all_grammar_entities() -> ::ffi_utils::CStringArray {
-            $crate::all_grammar_entities()
+            $crate::catch_panic_or($crate::empty_string_array(), $crate::all_grammar_entities)
         }
 
         #[no_mangle]
         pub extern "C" fn snips_nlu_ontology_all_gazetteer_entities() -> ::ffi_utils::CStringArray {
-            $crate::all_gazetteer_entities()
+            $crate::catch_panic_or($crate::empty_string_array(), $crate::all_gazetteer_entities)
         }
     };
 }
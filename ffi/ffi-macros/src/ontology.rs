@@ -1,6 +1,7 @@
 #![allow(non_camel_case_types)]
 
-use failure::{bail, Fallible, ResultExt};
+use crate::convert::CReprConvert;
+use failure::Fallible;
 use ffi_utils::{
     create_optional_rust_string_from, create_rust_string_from, take_back_c_string,
     take_back_nullable_c_string, AsRust, RawPointerConverter,
@@ -27,24 +28,28 @@ pub struct CIntentParserResult {
 
 impl From<IntentParserResult> for CIntentParserResult {
     fn from(input: IntentParserResult) -> Self {
+        let slots = CSlotList::from_slots_in_input(input.slots, &input.input);
+        let alternatives = CIntentParserAlternativeArray::from_alternatives_in_input(
+            input.alternatives,
+            &input.input,
+        );
         Self {
             input: CString::new(input.input).unwrap().into_raw(),
             intent: CIntentClassifierResult::from(input.intent).into_raw_pointer(),
-            slots: CSlotList::from(input.slots).into_raw_pointer(),
-            alternatives: CIntentParserAlternativeArray::from(input.alternatives)
-                .into_raw_pointer(),
+            slots: slots.into_raw_pointer(),
+            alternatives: alternatives.into_raw_pointer(),
         }
     }
 }
 
 impl AsRust<IntentParserResult> for CIntentParserResult {
     fn as_rust(&self) -> Fallible<IntentParserResult> {
-        Ok(IntentParserResult {
-            input: create_rust_string_from!(self.input),
-            intent: unsafe { &*self.intent }.as_rust()?,
-            slots: unsafe { &*self.slots }.as_rust()?,
-            alternatives: unsafe { &*self.alternatives }.as_rust()?,
-        })
+        Ok(IntentParserResult::new(
+            create_rust_string_from!(self.input),
+            unsafe { &*self.intent }.as_rust()?,
+            unsafe { &*self.slots }.as_rust()?,
+        )
+        .with_alternatives(unsafe { &*self.alternatives }.as_rust()?))
     }
 }
 
@@ -76,12 +81,23 @@ impl From<IntentParserAlternative> for CIntentParserAlternative {
     }
 }
 
+impl CIntentParserAlternative {
+    /// Converts an alternative parsing of the given input, along with the ranges of its slots in
+    /// UTF-16 code units
+    pub fn from_alternative_in_input(alternative: IntentParserAlternative, input: &str) -> Self {
+        Self {
+            intent: CIntentClassifierResult::from(alternative.intent).into_raw_pointer(),
+            slots: CSlotList::from_slots_in_input(alternative.slots, input).into_raw_pointer(),
+        }
+    }
+}
+
 impl AsRust<IntentParserAlternative> for CIntentParserAlternative {
     fn as_rust(&self) -> Fallible<IntentParserAlternative> {
-        Ok(IntentParserAlternative {
-            intent: unsafe { &*self.intent }.as_rust()?,
-            slots: unsafe { &*self.slots }.as_rust()?,
-        })
+        Ok(IntentParserAlternative::new(
+            unsafe { &*self.intent }.as_rust()?,
+            unsafe { &*self.slots }.as_rust()?,
+        ))
     }
 }
 
@@ -104,15 +120,37 @@ pub struct CIntentParserAlternativeArray {
 
 impl From<Vec<IntentParserAlternative>> for CIntentParserAlternativeArray {
     fn from(input: Vec<IntentParserAlternative>) -> Self {
+        Self::from_c_alternatives(
+            input
+                .into_iter()
+                .map(CIntentParserAlternative::from)
+                .collect(),
+        )
+    }
+}
+
+impl CIntentParserAlternativeArray {
+    /// Converts alternative parsings of the given input, along with the ranges of their slots in
+    /// UTF-16 code units
+    pub fn from_alternatives_in_input(
+        alternatives: Vec<IntentParserAlternative>,
+        input: &str,
+    ) -> Self {
+        Self::from_c_alternatives(
+            alternatives
+                .into_iter()
+                .map(|alternative| {
+                    CIntentParserAlternative::from_alternative_in_input(alternative, input)
+                })
+                .collect(),
+        )
+    }
+
+    fn from_c_alternatives(alternatives: Vec<CIntentParserAlternative>) -> Self {
         Self {
-            size: input.len() as i32,
-            intent_parser_alternatives: Box::into_raw(
-                input
-                    .into_iter()
-                    .map(CIntentParserAlternative::from)
-                    .collect::<Vec<_>>()
-                    .into_boxed_slice(),
-            ) as *const CIntentParserAlternative,
+            size: alternatives.len() as i32,
+            intent_parser_alternatives: Box::into_raw(alternatives.into_boxed_slice())
+                as *const CIntentParserAlternative,
         }
     }
 }
@@ -170,10 +208,10 @@ impl From<IntentClassifierResult> for CIntentClassifierResult {
 
 impl AsRust<IntentClassifierResult> for CIntentClassifierResult {
     fn as_rust(&self) -> Fallible<IntentClassifierResult> {
-        Ok(IntentClassifierResult {
-            intent_name: create_optional_rust_string_from!(self.intent_name),
-            confidence_score: self.confidence_score as f32,
-        })
+        Ok(IntentClassifierResult::new(
+            create_optional_rust_string_from!(self.intent_name),
+            self.confidence_score as f32,
+        ))
     }
 }
 
@@ -248,15 +286,26 @@ pub struct CSlotList {
 
 impl From<Vec<Slot>> for CSlotList {
     fn from(input: Vec<Slot>) -> Self {
+        Self::from_c_slots(input.into_iter().map(CSlot::from).collect())
+    }
+}
+
+impl CSlotList {
+    /// Converts slots extracted from the given input, along with their ranges in UTF-16 code
+    /// units
+    pub fn from_slots_in_input(slots: Vec<Slot>, input: &str) -> Self {
+        Self::from_c_slots(
+            slots
+                .into_iter()
+                .map(|slot| CSlot::from_slot_in_input(slot, input))
+                .collect(),
+        )
+    }
+
+    fn from_c_slots(slots: Vec<CSlot>) -> Self {
         Self {
-            size: input.len() as i32,
-            slots: Box::into_raw(
-                input
-                    .into_iter()
-                    .map(CSlot::from)
-                    .collect::<Vec<_>>()
-                    .into_boxed_slice(),
-            ) as *const CSlot,
+            size: slots.len() as i32,
+            slots: Box::into_raw(slots.into_boxed_slice()) as *const CSlot,
         }
     }
 }
@@ -305,6 +354,10 @@ pub struct CSlot {
     pub range_end: i32,
     /// Confidence score of the slot
     pub confidence_score: libc::c_float,
+    /// Start index of raw value in input text in UTF-16 code units, or -1 when unknown
+    pub utf16_range_start: i32,
+    /// End index of raw value in input text in UTF-16 code units, or -1 when unknown
+    pub utf16_range_end: i32,
 }
 
 impl From<Slot> for CSlot {
@@ -321,24 +374,39 @@ impl From<Slot> for CSlot {
                 .confidence_score
                 .map(|v| v as libc::c_float)
                 .unwrap_or(-1.),
+            utf16_range_start: -1,
+            utf16_range_end: -1,
+        }
+    }
+}
+
+impl CSlot {
+    /// Converts a slot extracted from the given input, along with its range in UTF-16 code units
+    pub fn from_slot_in_input(slot: Slot, input: &str) -> Self {
+        let utf16_range = slot.utf16_range(input).ok();
+        let mut c_slot = Self::from(slot);
+        if let Some(utf16_range) = utf16_range {
+            c_slot.utf16_range_start = utf16_range.start as i32;
+            c_slot.utf16_range_end = utf16_range.end as i32;
         }
+        c_slot
     }
 }
 
 impl AsRust<Slot> for CSlot {
     fn as_rust(&self) -> Fallible<Slot> {
-        Ok(Slot {
-            raw_value: create_rust_string_from!(self.raw_value),
-            value: unsafe { &*self.value }.as_rust()?,
-            alternatives: unsafe { &*self.alternatives }.as_rust()?,
-            range: (self.range_start as usize..self.range_end as usize),
-            entity: create_rust_string_from!(self.entity),
-            slot_name: create_rust_string_from!(self.slot_name),
-            confidence_score: if self.confidence_score < 0.0 {
-                None
-            } else {
-                Some(self.confidence_score)
-            },
+        let slot = Slot::new(
+            create_rust_string_from!(self.raw_value),
+            unsafe { &*self.value }.as_rust()?,
+            self.range_start as usize..self.range_end as usize,
+            create_rust_string_from!(self.entity),
+            create_rust_string_from!(self.slot_name),
+        )
+        .with_alternatives(unsafe { &*self.alternatives }.as_rust()?);
+        Ok(if self.confidence_score < 0.0 {
+            slot
+        } else {
+            slot.with_confidence_score(self.confidence_score)
         })
     }
 }
@@ -387,32 +455,38 @@ pub enum SNIPS_SLOT_VALUE_TYPE {
     SNIPS_SLOT_VALUE_TYPE_COUNTRY = 14,
     /// Region type represented by a char *
     SNIPS_SLOT_VALUE_TYPE_REGION = 15,
-}
-
-impl<'a> From<&'a SlotValue> for SNIPS_SLOT_VALUE_TYPE {
-    fn from(slot_value: &SlotValue) -> Self {
-        match slot_value {
-            &crate::SlotValue::Custom(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CUSTOM,
-            &SlotValue::Number(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_NUMBER,
-            &SlotValue::Ordinal(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_ORDINAL,
-            &SlotValue::InstantTime(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_INSTANTTIME,
-            &SlotValue::TimeInterval(_) => {
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TIMEINTERVAL
-            }
-            &SlotValue::AmountOfMoney(_) => {
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_AMOUNTOFMONEY
-            }
-            &SlotValue::Temperature(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TEMPERATURE,
-            &SlotValue::Duration(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_DURATION,
-            &SlotValue::Percentage(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_PERCENTAGE,
-            &SlotValue::MusicAlbum(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICALBUM,
-            &SlotValue::MusicArtist(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICARTIST,
-            &SlotValue::MusicTrack(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICTRACK,
-            &SlotValue::City(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CITY,
-            &SlotValue::Country(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_COUNTRY,
-            &SlotValue::Region(_) => SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_REGION,
-        }
-    }
+    /// Relative change type represented by a CRelativeChangeValue
+    SNIPS_SLOT_VALUE_TYPE_RELATIVECHANGE = 16,
+    /// Alphanumeric code type represented by a char *
+    SNIPS_SLOT_VALUE_TYPE_ALPHANUMERICCODE = 17,
+    /// Flight number type represented by a CFlightNumberValue
+    SNIPS_SLOT_VALUE_TYPE_FLIGHTNUMBER = 18,
+    /// Product code type represented by a CProductCodeValue
+    SNIPS_SLOT_VALUE_TYPE_PRODUCTCODE = 19,
+    /// IP address type represented by a char *
+    SNIPS_SLOT_VALUE_TYPE_IPADDRESS = 20,
+    /// Host name type represented by a char *
+    SNIPS_SLOT_VALUE_TYPE_HOSTNAME = 21,
+    /// Hashtag type represented by a char *
+    SNIPS_SLOT_VALUE_TYPE_HASHTAG = 22,
+    /// Mention type represented by a char *
+    SNIPS_SLOT_VALUE_TYPE_MENTION = 23,
+    /// Version type represented by a CVersionValue
+    SNIPS_SLOT_VALUE_TYPE_VERSION = 24,
+    /// Media position type represented by a CMediaPositionValue
+    SNIPS_SLOT_VALUE_TYPE_MEDIAPOSITION = 25,
+    /// Score type represented by a CScoreValue
+    SNIPS_SLOT_VALUE_TYPE_SCORE = 26,
+    /// Cooking quantity type represented by a CCookingQuantityValue
+    SNIPS_SLOT_VALUE_TYPE_COOKINGQUANTITY = 27,
+    /// Blood pressure type represented by a CBloodPressureValue
+    SNIPS_SLOT_VALUE_TYPE_BLOODPRESSURE = 28,
+    /// Dosage type represented by a CDosageValue
+    SNIPS_SLOT_VALUE_TYPE_DOSAGE = 29,
+    /// Geographic coordinates type represented by a CGeoCoordinateValue
+    SNIPS_SLOT_VALUE_TYPE_GEOCOORDINATE = 30,
+    /// Reference type represented by a CReferenceValue
+    SNIPS_SLOT_VALUE_TYPE_REFERENCE = 31,
 }
 
 /// Enum describing the precision of a resolved value
@@ -447,8 +521,66 @@ impl AsRust<Precision> for SNIPS_PRECISION {
 pub type CNumberValue = libc::c_double;
 /// Representation of a percentage value
 pub type CPercentageValue = libc::c_double;
+
+/// Enum describing from which end an ordinal value is counted
+#[repr(C)]
+#[derive(Debug)]
+pub enum SNIPS_ORDINAL_DIRECTION {
+    /// The position is counted from the start, e.g. "the second one"
+    SNIPS_ORDINAL_DIRECTION_FROM_START = 0,
+    /// The position is counted from the end, e.g. "the second to last one"
+    SNIPS_ORDINAL_DIRECTION_FROM_END = 1,
+}
+
+impl From<OrdinalDirection> for SNIPS_ORDINAL_DIRECTION {
+    fn from(value: OrdinalDirection) -> Self {
+        match value {
+            OrdinalDirection::FromStart => {
+                SNIPS_ORDINAL_DIRECTION::SNIPS_ORDINAL_DIRECTION_FROM_START
+            }
+            OrdinalDirection::FromEnd => SNIPS_ORDINAL_DIRECTION::SNIPS_ORDINAL_DIRECTION_FROM_END,
+        }
+    }
+}
+
+impl AsRust<OrdinalDirection> for SNIPS_ORDINAL_DIRECTION {
+    fn as_rust(&self) -> Fallible<OrdinalDirection> {
+        Ok(match self {
+            SNIPS_ORDINAL_DIRECTION::SNIPS_ORDINAL_DIRECTION_FROM_START => {
+                OrdinalDirection::FromStart
+            }
+            SNIPS_ORDINAL_DIRECTION::SNIPS_ORDINAL_DIRECTION_FROM_END => OrdinalDirection::FromEnd,
+        })
+    }
+}
+
 /// Representation of an ordinal value
-pub type COrdinalValue = i64;
+#[repr(C)]
+#[derive(Debug)]
+pub struct COrdinalValue {
+    /// The position, starting at 1
+    pub value: i64,
+    /// From which end the position is counted
+    pub direction: SNIPS_ORDINAL_DIRECTION,
+}
+
+impl From<OrdinalValue> for COrdinalValue {
+    fn from(value: OrdinalValue) -> Self {
+        Self {
+            value: value.value,
+            direction: SNIPS_ORDINAL_DIRECTION::from(value.direction),
+        }
+    }
+}
+
+impl AsRust<OrdinalValue> for COrdinalValue {
+    fn as_rust(&self) -> Fallible<OrdinalValue> {
+        Ok(OrdinalValue {
+            value: self.value,
+            direction: self.direction.as_rust()?,
+        })
+    }
+}
 
 /// Enum representing the grain of a resolved date related value
 #[repr(C)]
@@ -548,6 +680,8 @@ pub struct CTimeIntervalValue {
     pub from: *const libc::c_char,
     /// String representation of the end of the interval
     pub to: *const libc::c_char,
+    /// 1 if the end of the interval is included in it, 0 otherwise
+    pub to_inclusive: libc::c_int,
 }
 
 impl From<TimeIntervalValue> for CTimeIntervalValue {
@@ -563,6 +697,7 @@ impl From<TimeIntervalValue> for CTimeIntervalValue {
             } else {
                 null()
             },
+            to_inclusive: value.to_inclusive as libc::c_int,
         }
     }
 }
@@ -572,6 +707,7 @@ impl AsRust<TimeIntervalValue> for CTimeIntervalValue {
         Ok(TimeIntervalValue {
             from: create_optional_rust_string_from!(self.from),
             to: create_optional_rust_string_from!(self.to),
+            to_inclusive: self.to_inclusive != 0,
         })
     }
 }
@@ -593,6 +729,8 @@ pub struct CAmountOfMoneyValue {
     pub value: libc::c_float,
     /// The precision of the resolved value
     pub precision: SNIPS_PRECISION,
+    /// The amount of money expressed in minor units of the currency (e.g. cents)
+    pub minor_units: i64,
 }
 
 impl From<AmountOfMoneyValue> for CAmountOfMoneyValue {
@@ -600,6 +738,7 @@ impl From<AmountOfMoneyValue> for CAmountOfMoneyValue {
         Self {
             value: value.value as libc::c_float,
             precision: SNIPS_PRECISION::from(value.precision),
+            minor_units: value.minor_units,
             unit: if let Some(s) = value.unit {
                 CString::new(s).unwrap().into_raw()
             } else {
@@ -615,6 +754,7 @@ impl AsRust<AmountOfMoneyValue> for CAmountOfMoneyValue {
             value: self.value as f32,
             precision: self.precision.as_rust()?,
             unit: create_optional_rust_string_from!(self.unit),
+            minor_units: self.minor_units,
         })
     }
 }
@@ -625,6 +765,34 @@ impl Drop for CAmountOfMoneyValue {
     }
 }
 
+/// Enum describing whether a temperature value is a temperature or a change of temperature
+#[repr(C)]
+#[derive(Debug)]
+pub enum SNIPS_TEMPERATURE_MODE {
+    /// The value is a temperature, e.g. "set it to 20 degrees"
+    SNIPS_TEMPERATURE_MODE_ABSOLUTE = 0,
+    /// The value is a change of temperature, e.g. "raise it by 2 degrees"
+    SNIPS_TEMPERATURE_MODE_DELTA = 1,
+}
+
+impl From<TemperatureMode> for SNIPS_TEMPERATURE_MODE {
+    fn from(value: TemperatureMode) -> Self {
+        match value {
+            TemperatureMode::Absolute => SNIPS_TEMPERATURE_MODE::SNIPS_TEMPERATURE_MODE_ABSOLUTE,
+            TemperatureMode::Delta => SNIPS_TEMPERATURE_MODE::SNIPS_TEMPERATURE_MODE_DELTA,
+        }
+    }
+}
+
+impl AsRust<TemperatureMode> for SNIPS_TEMPERATURE_MODE {
+    fn as_rust(&self) -> Fallible<TemperatureMode> {
+        Ok(match self {
+            SNIPS_TEMPERATURE_MODE::SNIPS_TEMPERATURE_MODE_ABSOLUTE => TemperatureMode::Absolute,
+            SNIPS_TEMPERATURE_MODE::SNIPS_TEMPERATURE_MODE_DELTA => TemperatureMode::Delta,
+        })
+    }
+}
+
 /// Representation of a temperature value
 #[repr(C)]
 #[derive(Debug)]
@@ -633,6 +801,8 @@ pub struct CTemperatureValue {
     pub unit: *const libc::c_char,
     /// The temperature resolved
     pub value: libc::c_float,
+    /// Whether the value is a temperature or a change of temperature
+    pub mode: SNIPS_TEMPERATURE_MODE,
 }
 
 impl From<TemperatureValue> for CTemperatureValue {
@@ -644,6 +814,7 @@ impl From<TemperatureValue> for CTemperatureValue {
             } else {
                 null()
             },
+            mode: SNIPS_TEMPERATURE_MODE::from(value.mode),
         }
     }
 }
@@ -653,6 +824,7 @@ impl AsRust<TemperatureValue> for CTemperatureValue {
         Ok(TemperatureValue {
             value: self.value as f32,
             unit: create_optional_rust_string_from!(self.unit),
+            mode: self.mode.as_rust()?,
         })
     }
 }
@@ -719,166 +891,750 @@ impl AsRust<DurationValue> for CDurationValue {
     }
 }
 
-/// A slot value
+/// Enum describing whether a relative change increases or decreases a value
 #[repr(C)]
 #[derive(Debug)]
-pub struct CSlotValue {
-    /// Points to either a *const char, a CNumberValue, a COrdinalValue,
-    /// a CInstantTimeValue, a CTimeIntervalValue, a CAmountOfMoneyValue,
-    /// a CTemperatureValue or a CDurationValue depending on value_type
-    value: *const libc::c_void,
-    /// The type of the value
-    value_type: SNIPS_SLOT_VALUE_TYPE,
+pub enum SNIPS_CHANGE_DIRECTION {
+    /// The value is increased, e.g. "two degrees warmer"
+    SNIPS_CHANGE_DIRECTION_INCREASE = 0,
+    /// The value is decreased, e.g. "a bit lower"
+    SNIPS_CHANGE_DIRECTION_DECREASE = 1,
 }
 
-impl From<SlotValue> for CSlotValue {
-    fn from(slot_value: SlotValue) -> Self {
-        let value_type = SNIPS_SLOT_VALUE_TYPE::from(&slot_value);
-        let value: *const libc::c_void = match slot_value {
-            SlotValue::Custom(v) => CString::new(v.value).unwrap().into_raw() as _,
-            SlotValue::Number(v) => (v.value as CNumberValue).into_raw_pointer() as _,
-            SlotValue::Ordinal(v) => (v.value as COrdinalValue).into_raw_pointer() as _,
-            SlotValue::InstantTime(v) => CInstantTimeValue::from(v).into_raw_pointer() as _,
-            SlotValue::TimeInterval(v) => CTimeIntervalValue::from(v).into_raw_pointer() as _,
-            SlotValue::AmountOfMoney(v) => CAmountOfMoneyValue::from(v).into_raw_pointer() as _,
-            SlotValue::Temperature(v) => CTemperatureValue::from(v).into_raw_pointer() as _,
-            SlotValue::Duration(v) => CDurationValue::from(v).into_raw_pointer() as _,
-            SlotValue::Percentage(v) => (v.value as CPercentageValue).into_raw_pointer() as _,
-            SlotValue::MusicAlbum(v) => CString::new(v.value).unwrap().into_raw() as _,
-            SlotValue::MusicArtist(v) => CString::new(v.value).unwrap().into_raw() as _,
-            SlotValue::MusicTrack(v) => CString::new(v.value).unwrap().into_raw() as _,
-            SlotValue::City(v) => CString::new(v.value).unwrap().into_raw() as _,
-            SlotValue::Country(v) => CString::new(v.value).unwrap().into_raw() as _,
-            SlotValue::Region(v) => CString::new(v.value).unwrap().into_raw() as _,
-        };
-        Self { value_type, value }
+impl From<ChangeDirection> for SNIPS_CHANGE_DIRECTION {
+    fn from(value: ChangeDirection) -> Self {
+        match value {
+            ChangeDirection::Increase => SNIPS_CHANGE_DIRECTION::SNIPS_CHANGE_DIRECTION_INCREASE,
+            ChangeDirection::Decrease => SNIPS_CHANGE_DIRECTION::SNIPS_CHANGE_DIRECTION_DECREASE,
+        }
     }
 }
 
-impl AsRust<SlotValue> for CSlotValue {
-    fn as_rust(&self) -> Fallible<SlotValue> {
-        match self.value_type {
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CUSTOM => Ok(SlotValue::Custom(
-                create_rust_string_from!(self.value as *const libc::c_char).into(),
-            )),
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_NUMBER => {
-                let number_value: f64 = unsafe { *(self.value as *const CNumberValue) };
-                Ok(SlotValue::Number(NumberValue {
-                    value: number_value,
-                }))
-            }
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_ORDINAL => {
-                let ordinal_value: i64 = unsafe { *(self.value as *const COrdinalValue) };
-                Ok(SlotValue::Ordinal(OrdinalValue {
-                    value: ordinal_value,
-                }))
+impl AsRust<ChangeDirection> for SNIPS_CHANGE_DIRECTION {
+    fn as_rust(&self) -> Fallible<ChangeDirection> {
+        Ok(match self {
+            SNIPS_CHANGE_DIRECTION::SNIPS_CHANGE_DIRECTION_INCREASE => ChangeDirection::Increase,
+            SNIPS_CHANGE_DIRECTION::SNIPS_CHANGE_DIRECTION_DECREASE => ChangeDirection::Decrease,
+        })
+    }
+}
+
+/// Enum describing the magnitude of a relative change whose size is not specified
+#[repr(C)]
+#[derive(Debug)]
+pub enum SNIPS_CHANGE_MAGNITUDE {
+    /// No magnitude was expressed, e.g. "louder"
+    SNIPS_CHANGE_MAGNITUDE_UNSPECIFIED = 0,
+    /// The change is small, e.g. "a bit louder"
+    SNIPS_CHANGE_MAGNITUDE_SLIGHT = 1,
+    /// The change is large, e.g. "a lot louder"
+    SNIPS_CHANGE_MAGNITUDE_LARGE = 2,
+}
+
+impl From<Option<ChangeMagnitude>> for SNIPS_CHANGE_MAGNITUDE {
+    fn from(value: Option<ChangeMagnitude>) -> Self {
+        match value {
+            None => SNIPS_CHANGE_MAGNITUDE::SNIPS_CHANGE_MAGNITUDE_UNSPECIFIED,
+            Some(ChangeMagnitude::Slight) => SNIPS_CHANGE_MAGNITUDE::SNIPS_CHANGE_MAGNITUDE_SLIGHT,
+            Some(ChangeMagnitude::Large) => SNIPS_CHANGE_MAGNITUDE::SNIPS_CHANGE_MAGNITUDE_LARGE,
+        }
+    }
+}
+
+impl AsRust<Option<ChangeMagnitude>> for SNIPS_CHANGE_MAGNITUDE {
+    fn as_rust(&self) -> Fallible<Option<ChangeMagnitude>> {
+        Ok(match self {
+            SNIPS_CHANGE_MAGNITUDE::SNIPS_CHANGE_MAGNITUDE_UNSPECIFIED => None,
+            SNIPS_CHANGE_MAGNITUDE::SNIPS_CHANGE_MAGNITUDE_SLIGHT => Some(ChangeMagnitude::Slight),
+            SNIPS_CHANGE_MAGNITUDE::SNIPS_CHANGE_MAGNITUDE_LARGE => Some(ChangeMagnitude::Large),
+        })
+    }
+}
+
+/// Representation of a relative change value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CRelativeChangeValue {
+    /// Whether the value is increased or decreased
+    pub direction: SNIPS_CHANGE_DIRECTION,
+    /// The size of the change, or a negative number if it was not specified
+    pub value: libc::c_double,
+    /// The unit of the change, can be null
+    pub unit: *const libc::c_char,
+    /// The magnitude of the change, only relevant when its size was not specified
+    pub magnitude: SNIPS_CHANGE_MAGNITUDE,
+}
+
+impl From<RelativeChangeValue> for CRelativeChangeValue {
+    fn from(value: RelativeChangeValue) -> Self {
+        Self {
+            direction: SNIPS_CHANGE_DIRECTION::from(value.direction),
+            value: value.value.unwrap_or(-1.0),
+            unit: if let Some(s) = value.unit {
+                CString::new(s).unwrap().into_raw()
+            } else {
+                null()
+            },
+            magnitude: SNIPS_CHANGE_MAGNITUDE::from(value.magnitude),
+        }
+    }
+}
+
+impl AsRust<RelativeChangeValue> for CRelativeChangeValue {
+    fn as_rust(&self) -> Fallible<RelativeChangeValue> {
+        Ok(RelativeChangeValue {
+            direction: self.direction.as_rust()?,
+            value: if self.value < 0.0 {
+                None
+            } else {
+                Some(self.value)
+            },
+            unit: create_optional_rust_string_from!(self.unit),
+            magnitude: self.magnitude.as_rust()?,
+        })
+    }
+}
+
+impl Drop for CRelativeChangeValue {
+    fn drop(&mut self) {
+        take_back_nullable_c_string!(self.unit);
+    }
+}
+
+/// Representation of a flight number value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CFlightNumberValue {
+    /// The code of the airline
+    pub carrier: *const libc::c_char,
+    /// The number of the flight
+    pub number: u32,
+}
+
+impl From<FlightNumberValue> for CFlightNumberValue {
+    fn from(value: FlightNumberValue) -> Self {
+        Self {
+            carrier: CString::new(value.carrier).unwrap().into_raw(),
+            number: value.number,
+        }
+    }
+}
+
+impl AsRust<FlightNumberValue> for CFlightNumberValue {
+    fn as_rust(&self) -> Fallible<FlightNumberValue> {
+        Ok(FlightNumberValue {
+            carrier: create_rust_string_from!(self.carrier),
+            number: self.number,
+        })
+    }
+}
+
+impl Drop for CFlightNumberValue {
+    fn drop(&mut self) {
+        take_back_c_string!(self.carrier);
+    }
+}
+
+/// Enum describing the format of a product code
+#[repr(C)]
+#[derive(Debug)]
+pub enum SNIPS_PRODUCT_CODE_FORMAT {
+    /// 10 characters ISBN
+    SNIPS_PRODUCT_CODE_FORMAT_ISBN_10 = 0,
+    /// 13 digits ISBN
+    SNIPS_PRODUCT_CODE_FORMAT_ISBN_13 = 1,
+    /// 13 digits EAN
+    SNIPS_PRODUCT_CODE_FORMAT_EAN_13 = 2,
+}
+
+impl From<ProductCodeFormat> for SNIPS_PRODUCT_CODE_FORMAT {
+    fn from(value: ProductCodeFormat) -> Self {
+        match value {
+            ProductCodeFormat::Isbn10 => {
+                SNIPS_PRODUCT_CODE_FORMAT::SNIPS_PRODUCT_CODE_FORMAT_ISBN_10
             }
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_INSTANTTIME => {
-                let c_instant_time_value = unsafe { &*(self.value as *const CInstantTimeValue) };
-                let instant_time_value = c_instant_time_value.as_rust()?;
-                Ok(SlotValue::InstantTime(instant_time_value))
+            ProductCodeFormat::Isbn13 => {
+                SNIPS_PRODUCT_CODE_FORMAT::SNIPS_PRODUCT_CODE_FORMAT_ISBN_13
             }
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TIMEINTERVAL => {
-                let c_time_interval_value = unsafe { &*(self.value as *const CTimeIntervalValue) };
-                let time_interval_value = c_time_interval_value.as_rust()?;
-                Ok(SlotValue::TimeInterval(time_interval_value))
+            ProductCodeFormat::Ean13 => SNIPS_PRODUCT_CODE_FORMAT::SNIPS_PRODUCT_CODE_FORMAT_EAN_13,
+        }
+    }
+}
+
+impl AsRust<ProductCodeFormat> for SNIPS_PRODUCT_CODE_FORMAT {
+    fn as_rust(&self) -> Fallible<ProductCodeFormat> {
+        Ok(match self {
+            SNIPS_PRODUCT_CODE_FORMAT::SNIPS_PRODUCT_CODE_FORMAT_ISBN_10 => {
+                ProductCodeFormat::Isbn10
             }
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_AMOUNTOFMONEY => {
-                let c_amount_of_money_value =
-                    unsafe { &*(self.value as *const CAmountOfMoneyValue) };
-                let amount_of_money_value = c_amount_of_money_value.as_rust()?;
-                Ok(SlotValue::AmountOfMoney(amount_of_money_value))
+            SNIPS_PRODUCT_CODE_FORMAT::SNIPS_PRODUCT_CODE_FORMAT_ISBN_13 => {
+                ProductCodeFormat::Isbn13
             }
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TEMPERATURE => {
-                let c_temperature_value = unsafe { &*(self.value as *const CTemperatureValue) };
-                let temperature_value = c_temperature_value.as_rust()?;
-                Ok(SlotValue::Temperature(temperature_value))
+            SNIPS_PRODUCT_CODE_FORMAT::SNIPS_PRODUCT_CODE_FORMAT_EAN_13 => ProductCodeFormat::Ean13,
+        })
+    }
+}
+
+/// Representation of a product code value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CProductCodeValue {
+    /// The format of the code
+    pub format: SNIPS_PRODUCT_CODE_FORMAT,
+    /// The characters of the code, without separators
+    pub value: *const libc::c_char,
+}
+
+impl From<ProductCodeValue> for CProductCodeValue {
+    fn from(value: ProductCodeValue) -> Self {
+        Self {
+            format: SNIPS_PRODUCT_CODE_FORMAT::from(value.format),
+            value: CString::new(value.value).unwrap().into_raw(),
+        }
+    }
+}
+
+impl AsRust<ProductCodeValue> for CProductCodeValue {
+    fn as_rust(&self) -> Fallible<ProductCodeValue> {
+        Ok(ProductCodeValue {
+            format: self.format.as_rust()?,
+            value: create_rust_string_from!(self.value),
+        })
+    }
+}
+
+impl Drop for CProductCodeValue {
+    fn drop(&mut self) {
+        take_back_c_string!(self.value);
+    }
+}
+
+/// Enum describing the unit of a cooking quantity
+#[repr(C)]
+#[derive(Debug)]
+pub enum SNIPS_COOKING_UNIT {
+    SNIPS_COOKING_UNIT_CUP = 0,
+    SNIPS_COOKING_UNIT_TABLESPOON = 1,
+    SNIPS_COOKING_UNIT_TEASPOON = 2,
+    SNIPS_COOKING_UNIT_PINCH = 3,
+    SNIPS_COOKING_UNIT_DASH = 4,
+    SNIPS_COOKING_UNIT_FLUID_OUNCE = 5,
+    SNIPS_COOKING_UNIT_MILLILITER = 6,
+    SNIPS_COOKING_UNIT_CENTILITER = 7,
+    SNIPS_COOKING_UNIT_DECILITER = 8,
+    SNIPS_COOKING_UNIT_LITER = 9,
+    SNIPS_COOKING_UNIT_OUNCE = 10,
+    SNIPS_COOKING_UNIT_POUND = 11,
+    SNIPS_COOKING_UNIT_GRAM = 12,
+    SNIPS_COOKING_UNIT_KILOGRAM = 13,
+}
+
+impl From<CookingUnit> for SNIPS_COOKING_UNIT {
+    fn from(value: CookingUnit) -> Self {
+        match value {
+            CookingUnit::Cup => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_CUP,
+            CookingUnit::Tablespoon => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_TABLESPOON,
+            CookingUnit::Teaspoon => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_TEASPOON,
+            CookingUnit::Pinch => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_PINCH,
+            CookingUnit::Dash => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_DASH,
+            CookingUnit::FluidOunce => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_FLUID_OUNCE,
+            CookingUnit::Milliliter => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_MILLILITER,
+            CookingUnit::Centiliter => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_CENTILITER,
+            CookingUnit::Deciliter => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_DECILITER,
+            CookingUnit::Liter => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_LITER,
+            CookingUnit::Ounce => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_OUNCE,
+            CookingUnit::Pound => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_POUND,
+            CookingUnit::Gram => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_GRAM,
+            CookingUnit::Kilogram => SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_KILOGRAM,
+        }
+    }
+}
+
+impl AsRust<CookingUnit> for SNIPS_COOKING_UNIT {
+    fn as_rust(&self) -> Fallible<CookingUnit> {
+        Ok(match self {
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_CUP => CookingUnit::Cup,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_TABLESPOON => CookingUnit::Tablespoon,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_TEASPOON => CookingUnit::Teaspoon,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_PINCH => CookingUnit::Pinch,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_DASH => CookingUnit::Dash,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_FLUID_OUNCE => CookingUnit::FluidOunce,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_MILLILITER => CookingUnit::Milliliter,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_CENTILITER => CookingUnit::Centiliter,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_DECILITER => CookingUnit::Deciliter,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_LITER => CookingUnit::Liter,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_OUNCE => CookingUnit::Ounce,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_POUND => CookingUnit::Pound,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_GRAM => CookingUnit::Gram,
+            SNIPS_COOKING_UNIT::SNIPS_COOKING_UNIT_KILOGRAM => CookingUnit::Kilogram,
+        })
+    }
+}
+
+/// Enum describing the unit of the metric equivalent of a cooking quantity
+#[repr(C)]
+#[derive(Debug)]
+pub enum SNIPS_COOKING_METRIC_UNIT {
+    /// Milliliters, for volumes
+    SNIPS_COOKING_METRIC_UNIT_MILLILITER = 0,
+    /// Grams, for weights
+    SNIPS_COOKING_METRIC_UNIT_GRAM = 1,
+}
+
+impl From<CookingMetricUnit> for SNIPS_COOKING_METRIC_UNIT {
+    fn from(value: CookingMetricUnit) -> Self {
+        match value {
+            CookingMetricUnit::Milliliter => {
+                SNIPS_COOKING_METRIC_UNIT::SNIPS_COOKING_METRIC_UNIT_MILLILITER
             }
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_DURATION => {
-                let c_duration_value = unsafe { &*(self.value as *const CDurationValue) };
-                let duration_value = c_duration_value.as_rust()?;
-                Ok(SlotValue::Duration(duration_value))
+            CookingMetricUnit::Gram => SNIPS_COOKING_METRIC_UNIT::SNIPS_COOKING_METRIC_UNIT_GRAM,
+        }
+    }
+}
+
+impl AsRust<CookingMetricUnit> for SNIPS_COOKING_METRIC_UNIT {
+    fn as_rust(&self) -> Fallible<CookingMetricUnit> {
+        Ok(match self {
+            SNIPS_COOKING_METRIC_UNIT::SNIPS_COOKING_METRIC_UNIT_MILLILITER => {
+                CookingMetricUnit::Milliliter
             }
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICALBUM => Ok(SlotValue::MusicAlbum(
-                create_rust_string_from!(self.value as *const libc::c_char).into(),
-            )),
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICARTIST => Ok(SlotValue::MusicArtist(
-                create_rust_string_from!(self.value as *const libc::c_char).into(),
-            )),
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICTRACK => Ok(SlotValue::MusicTrack(
-                create_rust_string_from!(self.value as *const libc::c_char).into(),
-            )),
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CITY => Ok(SlotValue::City(
-                create_rust_string_from!(self.value as *const libc::c_char).into(),
-            )),
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_COUNTRY => Ok(SlotValue::Country(
-                create_rust_string_from!(self.value as *const libc::c_char).into(),
-            )),
-            SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_REGION => Ok(SlotValue::Region(
-                create_rust_string_from!(self.value as *const libc::c_char).into(),
-            )),
-            _ => bail!(
-                "Unknown slot value type: {:?}. Cannot perform conversion to Rust object.",
-                self.value_type
-            ),
+            SNIPS_COOKING_METRIC_UNIT::SNIPS_COOKING_METRIC_UNIT_GRAM => CookingMetricUnit::Gram,
+        })
+    }
+}
+
+/// Representation of a cooking quantity value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CCookingQuantityValue {
+    /// The quantity, in the unit used
+    pub value: libc::c_double,
+    /// The unit used
+    pub unit: SNIPS_COOKING_UNIT,
+    /// The metric equivalent of the quantity
+    pub metric_value: libc::c_double,
+    /// The unit of the metric equivalent
+    pub metric_unit: SNIPS_COOKING_METRIC_UNIT,
+}
+
+impl From<CookingQuantityValue> for CCookingQuantityValue {
+    fn from(value: CookingQuantityValue) -> Self {
+        Self {
+            value: value.value,
+            unit: SNIPS_COOKING_UNIT::from(value.unit),
+            metric_value: value.metric_value,
+            metric_unit: SNIPS_COOKING_METRIC_UNIT::from(value.metric_unit),
+        }
+    }
+}
+
+impl AsRust<CookingQuantityValue> for CCookingQuantityValue {
+    fn as_rust(&self) -> Fallible<CookingQuantityValue> {
+        Ok(CookingQuantityValue {
+            value: self.value,
+            unit: self.unit.as_rust()?,
+            metric_value: self.metric_value,
+            metric_unit: self.metric_unit.as_rust()?,
+        })
+    }
+}
+
+/// Representation of a blood pressure value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CBloodPressureValue {
+    /// The systolic pressure, in millimeters of mercury
+    pub systolic: u32,
+    /// The diastolic pressure, in millimeters of mercury
+    pub diastolic: u32,
+}
+
+impl From<BloodPressureValue> for CBloodPressureValue {
+    fn from(value: BloodPressureValue) -> Self {
+        Self {
+            systolic: value.systolic,
+            diastolic: value.diastolic,
+        }
+    }
+}
+
+impl AsRust<BloodPressureValue> for CBloodPressureValue {
+    fn as_rust(&self) -> Fallible<BloodPressureValue> {
+        Ok(BloodPressureValue {
+            systolic: self.systolic,
+            diastolic: self.diastolic,
+        })
+    }
+}
+
+/// Representation of a dosage value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CDosageValue {
+    /// The number of units taken
+    pub count: libc::c_double,
+    /// The dose per unit, negative when not specified
+    pub dose: libc::c_double,
+    /// The unit of the dose, can be null
+    pub unit: *const libc::c_char,
+    /// The form of the medication, can be null
+    pub form: *const libc::c_char,
+}
+
+impl From<DosageValue> for CDosageValue {
+    fn from(value: DosageValue) -> Self {
+        Self {
+            count: value.count,
+            dose: value.dose.unwrap_or(-1.),
+            unit: if let Some(s) = value.unit {
+                CString::new(s).unwrap().into_raw()
+            } else {
+                null()
+            },
+            form: if let Some(s) = value.form {
+                CString::new(s).unwrap().into_raw()
+            } else {
+                null()
+            },
         }
     }
 }
 
-impl Drop for CSlotValue {
+impl AsRust<DosageValue> for CDosageValue {
+    fn as_rust(&self) -> Fallible<DosageValue> {
+        Ok(DosageValue {
+            count: self.count,
+            dose: if self.dose >= 0. {
+                Some(self.dose)
+            } else {
+                None
+            },
+            unit: create_optional_rust_string_from!(self.unit),
+            form: create_optional_rust_string_from!(self.form),
+        })
+    }
+}
+
+impl Drop for CDosageValue {
     fn drop(&mut self) {
-        let _ = unsafe {
-            match self.value_type {
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CUSTOM => {
-                    CString::drop_raw_pointer(self.value)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_NUMBER => {
-                    CNumberValue::drop_raw_pointer(self.value as _)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_ORDINAL => {
-                    COrdinalValue::drop_raw_pointer(self.value as _)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_INSTANTTIME => {
-                    CInstantTimeValue::drop_raw_pointer(self.value as _)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TIMEINTERVAL => {
-                    CTimeIntervalValue::drop_raw_pointer(self.value as _)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_AMOUNTOFMONEY => {
-                    CAmountOfMoneyValue::drop_raw_pointer(self.value as _)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_TEMPERATURE => {
-                    CTemperatureValue::drop_raw_pointer(self.value as _)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_DURATION => {
-                    CDurationValue::drop_raw_pointer(self.value as _)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_PERCENTAGE => {
-                    CPercentageValue::drop_raw_pointer(self.value as _)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICALBUM => {
-                    CString::drop_raw_pointer(self.value)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICARTIST => {
-                    CString::drop_raw_pointer(self.value)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_MUSICTRACK => {
-                    CString::drop_raw_pointer(self.value)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_CITY => {
-                    CString::drop_raw_pointer(self.value)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_COUNTRY => {
-                    CString::drop_raw_pointer(self.value)
-                }
-                SNIPS_SLOT_VALUE_TYPE::SNIPS_SLOT_VALUE_TYPE_REGION => {
-                    CString::drop_raw_pointer(self.value)
-                }
+        take_back_nullable_c_string!(self.unit);
+        take_back_nullable_c_string!(self.form);
+    }
+}
+
+/// Representation of geographic coordinates
+#[repr(C)]
+#[derive(Debug)]
+pub struct CGeoCoordinateValue {
+    /// The latitude in decimal degrees, negative in the southern hemisphere
+    pub latitude: libc::c_double,
+    /// The longitude in decimal degrees, negative in the western hemisphere
+    pub longitude: libc::c_double,
+}
+
+impl From<GeoCoordinateValue> for CGeoCoordinateValue {
+    fn from(value: GeoCoordinateValue) -> Self {
+        Self {
+            latitude: value.latitude,
+            longitude: value.longitude,
+        }
+    }
+}
+
+impl AsRust<GeoCoordinateValue> for CGeoCoordinateValue {
+    fn as_rust(&self) -> Fallible<GeoCoordinateValue> {
+        Ok(GeoCoordinateValue {
+            latitude: self.latitude,
+            longitude: self.longitude,
+        })
+    }
+}
+
+/// Representation of a score value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CScoreValue {
+    /// The first score said
+    pub first: u32,
+    /// The second score said
+    pub second: u32,
+}
+
+impl From<ScoreValue> for CScoreValue {
+    fn from(value: ScoreValue) -> Self {
+        Self {
+            first: value.first,
+            second: value.second,
+        }
+    }
+}
+
+impl AsRust<ScoreValue> for CScoreValue {
+    fn as_rust(&self) -> Fallible<ScoreValue> {
+        Ok(ScoreValue {
+            first: self.first,
+            second: self.second,
+        })
+    }
+}
+
+/// Enum describing whether a media position is a position within the media or an offset from
+/// the current position
+#[repr(C)]
+#[derive(Debug)]
+pub enum SNIPS_MEDIA_POSITION_MODE {
+    /// The value is a position within the media, e.g. "skip to one hour twenty"
+    SNIPS_MEDIA_POSITION_MODE_ABSOLUTE = 0,
+    /// The value is an offset from the current position, e.g. "go back 30 seconds"
+    SNIPS_MEDIA_POSITION_MODE_OFFSET = 1,
+}
+
+impl From<MediaPositionMode> for SNIPS_MEDIA_POSITION_MODE {
+    fn from(value: MediaPositionMode) -> Self {
+        match value {
+            MediaPositionMode::Absolute => {
+                SNIPS_MEDIA_POSITION_MODE::SNIPS_MEDIA_POSITION_MODE_ABSOLUTE
             }
-        };
+            MediaPositionMode::Offset => {
+                SNIPS_MEDIA_POSITION_MODE::SNIPS_MEDIA_POSITION_MODE_OFFSET
+            }
+        }
+    }
+}
+
+impl AsRust<MediaPositionMode> for SNIPS_MEDIA_POSITION_MODE {
+    fn as_rust(&self) -> Fallible<MediaPositionMode> {
+        Ok(match self {
+            SNIPS_MEDIA_POSITION_MODE::SNIPS_MEDIA_POSITION_MODE_ABSOLUTE => {
+                MediaPositionMode::Absolute
+            }
+            SNIPS_MEDIA_POSITION_MODE::SNIPS_MEDIA_POSITION_MODE_OFFSET => {
+                MediaPositionMode::Offset
+            }
+        })
+    }
+}
+
+/// Representation of a media position value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CMediaPositionValue {
+    /// Whether the value is a position within the media or an offset from the current position
+    pub mode: SNIPS_MEDIA_POSITION_MODE,
+    /// The number of seconds from the start of the media, or from the current position, negative
+    /// offsets going backwards
+    pub seconds: i64,
+}
+
+impl From<MediaPositionValue> for CMediaPositionValue {
+    fn from(value: MediaPositionValue) -> Self {
+        Self {
+            mode: SNIPS_MEDIA_POSITION_MODE::from(value.mode),
+            seconds: value.seconds,
+        }
+    }
+}
+
+impl AsRust<MediaPositionValue> for CMediaPositionValue {
+    fn as_rust(&self) -> Fallible<MediaPositionValue> {
+        Ok(MediaPositionValue {
+            mode: self.mode.as_rust()?,
+            seconds: self.seconds,
+        })
+    }
+}
+
+/// Enum describing the way in which an anaphora refers to a value
+#[repr(C)]
+#[derive(Debug)]
+pub enum SNIPS_ANAPHORA {
+    /// The value mentioned before, e.g. "the same time"
+    SNIPS_ANAPHORA_SAME = 0,
+    /// The value pointed at, e.g. "that one"
+    SNIPS_ANAPHORA_DEMONSTRATIVE = 1,
+    /// A value differing from the one mentioned before, e.g. "another one"
+    SNIPS_ANAPHORA_OTHER = 2,
+}
+
+impl From<Anaphora> for SNIPS_ANAPHORA {
+    fn from(value: Anaphora) -> Self {
+        match value {
+            Anaphora::Same => SNIPS_ANAPHORA::SNIPS_ANAPHORA_SAME,
+            Anaphora::Demonstrative => SNIPS_ANAPHORA::SNIPS_ANAPHORA_DEMONSTRATIVE,
+            Anaphora::Other => SNIPS_ANAPHORA::SNIPS_ANAPHORA_OTHER,
+        }
+    }
+}
+
+impl AsRust<Anaphora> for SNIPS_ANAPHORA {
+    fn as_rust(&self) -> Fallible<Anaphora> {
+        Ok(match self {
+            SNIPS_ANAPHORA::SNIPS_ANAPHORA_SAME => Anaphora::Same,
+            SNIPS_ANAPHORA::SNIPS_ANAPHORA_DEMONSTRATIVE => Anaphora::Demonstrative,
+            SNIPS_ANAPHORA::SNIPS_ANAPHORA_OTHER => Anaphora::Other,
+        })
+    }
+}
+
+/// Representation of a reference value, standing for a value which was not said
+#[repr(C)]
+#[derive(Debug)]
+pub struct CReferenceValue {
+    /// The way in which the value is referred to
+    pub anaphora: SNIPS_ANAPHORA,
+}
+
+impl From<ReferenceValue> for CReferenceValue {
+    fn from(value: ReferenceValue) -> Self {
+        Self {
+            anaphora: SNIPS_ANAPHORA::from(value.anaphora),
+        }
+    }
+}
+
+impl AsRust<ReferenceValue> for CReferenceValue {
+    fn as_rust(&self) -> Fallible<ReferenceValue> {
+        Ok(ReferenceValue {
+            anaphora: self.anaphora.as_rust()?,
+        })
+    }
+}
+
+/// Representation of a version value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CVersionValue {
+    /// The major version number
+    pub major: u32,
+    /// The minor version number, 0 when missing
+    pub minor: u32,
+    /// The patch version number, 0 when missing
+    pub patch: u32,
+}
+
+impl From<VersionValue> for CVersionValue {
+    fn from(value: VersionValue) -> Self {
+        Self {
+            major: value.major,
+            minor: value.minor,
+            patch: value.patch,
+        }
+    }
+}
+
+impl AsRust<VersionValue> for CVersionValue {
+    fn as_rust(&self) -> Fallible<VersionValue> {
+        Ok(VersionValue {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+        })
     }
 }
 
+/// A slot value
+#[repr(C)]
+#[derive(Debug)]
+pub struct CSlotValue {
+    /// Points to either a *const char, a CNumberValue, a COrdinalValue,
+    /// a CInstantTimeValue, a CTimeIntervalValue, a CAmountOfMoneyValue,
+    /// a CTemperatureValue, a CDurationValue, a CRelativeChangeValue, a CFlightNumberValue,
+    /// a CProductCodeValue, a CVersionValue, a CMediaPositionValue, a CScoreValue,
+    /// a CCookingQuantityValue, a CBloodPressureValue, a CDosageValue, a CGeoCoordinateValue
+    /// or a CReferenceValue depending on value_type
+    value: *const libc::c_void,
+    /// The type of the value
+    value_type: SNIPS_SLOT_VALUE_TYPE,
+}
+
+/// Generates the conversions between slot values and `CSlotValue`, from the value type and the C
+/// representation of each kind of slot value
+macro_rules! c_slot_values {
+    ($($variant:ident($value:ty) => $value_type:ident as $c_repr:ty,)*) => {
+        impl<'a> From<&'a SlotValue> for SNIPS_SLOT_VALUE_TYPE {
+            fn from(slot_value: &SlotValue) -> Self {
+                match slot_value {
+                    $( SlotValue::$variant(_) => SNIPS_SLOT_VALUE_TYPE::$value_type, )*
+                }
+            }
+        }
+
+        impl From<SlotValue> for CSlotValue {
+            fn from(slot_value: SlotValue) -> Self {
+                let value_type = SNIPS_SLOT_VALUE_TYPE::from(&slot_value);
+                let value = match slot_value {
+                    $(
+                        SlotValue::$variant(v) => {
+                            <$c_repr as CReprConvert<$value>>::into_c_repr(v)
+                        }
+                    )*
+                };
+                Self { value, value_type }
+            }
+        }
+
+        // The value always points to the C representation matching the value type, as it is only
+        // built by `CReprConvert::into_c_repr` above
+        impl AsRust<SlotValue> for CSlotValue {
+            fn as_rust(&self) -> Fallible<SlotValue> {
+                Ok(match self.value_type {
+                    $(
+                        SNIPS_SLOT_VALUE_TYPE::$value_type => SlotValue::$variant(unsafe {
+                            <$c_repr as CReprConvert<$value>>::from_c_repr(self.value)
+                        }?),
+                    )*
+                })
+            }
+        }
+
+        impl Drop for CSlotValue {
+            fn drop(&mut self) {
+                let _ = match self.value_type {
+                    $(
+                        SNIPS_SLOT_VALUE_TYPE::$value_type => unsafe {
+                            <$c_repr as CReprConvert<$value>>::drop_c_repr(self.value)
+                        },
+                    )*
+                };
+            }
+        }
+    };
+}
+
+c_slot_values! {
+    Custom(StringValue) => SNIPS_SLOT_VALUE_TYPE_CUSTOM as CString,
+    Number(NumberValue) => SNIPS_SLOT_VALUE_TYPE_NUMBER as CNumberValue,
+    Ordinal(OrdinalValue) => SNIPS_SLOT_VALUE_TYPE_ORDINAL as COrdinalValue,
+    Percentage(PercentageValue) => SNIPS_SLOT_VALUE_TYPE_PERCENTAGE as CPercentageValue,
+    InstantTime(InstantTimeValue) => SNIPS_SLOT_VALUE_TYPE_INSTANTTIME as CInstantTimeValue,
+    TimeInterval(TimeIntervalValue) => SNIPS_SLOT_VALUE_TYPE_TIMEINTERVAL as CTimeIntervalValue,
+    AmountOfMoney(AmountOfMoneyValue) => SNIPS_SLOT_VALUE_TYPE_AMOUNTOFMONEY as CAmountOfMoneyValue,
+    Temperature(TemperatureValue) => SNIPS_SLOT_VALUE_TYPE_TEMPERATURE as CTemperatureValue,
+    Duration(DurationValue) => SNIPS_SLOT_VALUE_TYPE_DURATION as CDurationValue,
+    MusicAlbum(StringValue) => SNIPS_SLOT_VALUE_TYPE_MUSICALBUM as CString,
+    MusicArtist(StringValue) => SNIPS_SLOT_VALUE_TYPE_MUSICARTIST as CString,
+    MusicTrack(StringValue) => SNIPS_SLOT_VALUE_TYPE_MUSICTRACK as CString,
+    City(StringValue) => SNIPS_SLOT_VALUE_TYPE_CITY as CString,
+    Country(StringValue) => SNIPS_SLOT_VALUE_TYPE_COUNTRY as CString,
+    Region(StringValue) => SNIPS_SLOT_VALUE_TYPE_REGION as CString,
+    RelativeChange(RelativeChangeValue) => SNIPS_SLOT_VALUE_TYPE_RELATIVECHANGE as CRelativeChangeValue,
+    AlphanumericCode(StringValue) => SNIPS_SLOT_VALUE_TYPE_ALPHANUMERICCODE as CString,
+    FlightNumber(FlightNumberValue) => SNIPS_SLOT_VALUE_TYPE_FLIGHTNUMBER as CFlightNumberValue,
+    ProductCode(ProductCodeValue) => SNIPS_SLOT_VALUE_TYPE_PRODUCTCODE as CProductCodeValue,
+    IpAddress(StringValue) => SNIPS_SLOT_VALUE_TYPE_IPADDRESS as CString,
+    Hostname(StringValue) => SNIPS_SLOT_VALUE_TYPE_HOSTNAME as CString,
+    Hashtag(StringValue) => SNIPS_SLOT_VALUE_TYPE_HASHTAG as CString,
+    Mention(StringValue) => SNIPS_SLOT_VALUE_TYPE_MENTION as CString,
+    Version(VersionValue) => SNIPS_SLOT_VALUE_TYPE_VERSION as CVersionValue,
+    MediaPosition(MediaPositionValue) => SNIPS_SLOT_VALUE_TYPE_MEDIAPOSITION as CMediaPositionValue,
+    Score(ScoreValue) => SNIPS_SLOT_VALUE_TYPE_SCORE as CScoreValue,
+    CookingQuantity(CookingQuantityValue) => SNIPS_SLOT_VALUE_TYPE_COOKINGQUANTITY as CCookingQuantityValue,
+    BloodPressure(BloodPressureValue) => SNIPS_SLOT_VALUE_TYPE_BLOODPRESSURE as CBloodPressureValue,
+    Dosage(DosageValue) => SNIPS_SLOT_VALUE_TYPE_DOSAGE as CDosageValue,
+    GeoCoordinate(GeoCoordinateValue) => SNIPS_SLOT_VALUE_TYPE_GEOCOORDINATE as CGeoCoordinateValue,
+    Reference(ReferenceValue) => SNIPS_SLOT_VALUE_TYPE_REFERENCE as CReferenceValue,
+}
+
 /// Wrapper around a list of SlotValue
 #[repr(C)]
 #[derive(Debug)]
@@ -964,6 +1720,105 @@ mod tests {
         round_trip_test::<_, CTemperatureValue>(TemperatureValue {
             value: 20.0,
             unit: Some("°C".to_string()),
+            mode: TemperatureMode::Delta,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_relative_change_value() {
+        round_trip_test::<_, CRelativeChangeValue>(RelativeChangeValue {
+            direction: ChangeDirection::Decrease,
+            value: Some(2.0),
+            unit: Some("degree".to_string()),
+            magnitude: None,
+        });
+        round_trip_test::<_, CRelativeChangeValue>(RelativeChangeValue {
+            direction: ChangeDirection::Increase,
+            value: None,
+            unit: None,
+            magnitude: Some(ChangeMagnitude::Large),
+        })
+    }
+
+    #[test]
+    fn round_trip_c_flight_number_value() {
+        round_trip_test::<_, CFlightNumberValue>(FlightNumberValue {
+            carrier: "AF".to_string(),
+            number: 1234,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_product_code_value() {
+        round_trip_test::<_, CProductCodeValue>(ProductCodeValue {
+            format: ProductCodeFormat::Isbn10,
+            value: "207036822X".to_string(),
+        })
+    }
+
+    #[test]
+    fn round_trip_c_geo_coordinate_value() {
+        round_trip_test::<_, CGeoCoordinateValue>(GeoCoordinateValue {
+            latitude: -33.8688,
+            longitude: 151.2093,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_blood_pressure_value() {
+        round_trip_test::<_, CBloodPressureValue>(BloodPressureValue {
+            systolic: 120,
+            diastolic: 80,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_dosage_value() {
+        round_trip_test::<_, CDosageValue>(DosageValue {
+            count: 2.,
+            dose: Some(500.),
+            unit: Some("mg".to_string()),
+            form: None,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_cooking_quantity_value() {
+        round_trip_test::<_, CCookingQuantityValue>(CookingQuantityValue::new(
+            2.,
+            CookingUnit::Tablespoon,
+        ))
+    }
+
+    #[test]
+    fn round_trip_c_score_value() {
+        round_trip_test::<_, CScoreValue>(ScoreValue {
+            first: 3,
+            second: 2,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_reference_value() {
+        round_trip_test::<_, CReferenceValue>(ReferenceValue {
+            anaphora: Anaphora::Demonstrative,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_media_position_value() {
+        round_trip_test::<_, CMediaPositionValue>(MediaPositionValue {
+            mode: MediaPositionMode::Offset,
+            seconds: -30,
+        })
+    }
+
+    #[test]
+    fn round_trip_c_version_value() {
+        round_trip_test::<_, CVersionValue>(VersionValue {
+            major: 2,
+            minor: 3,
+            patch: 1,
         })
     }
 
@@ -973,6 +1828,7 @@ mod tests {
             value: 1234.0,
             precision: Precision::Exact,
             unit: Some("€".to_string()),
+            minor_units: 123400,
         })
     }
 
@@ -981,6 +1837,7 @@ mod tests {
         round_trip_test::<_, CTimeIntervalValue>(TimeIntervalValue {
             from: Some("from".to_string()),
             to: Some("to".to_string()),
+            to_inclusive: true,
         })
     }
 
@@ -993,6 +1850,14 @@ mod tests {
         })
     }
 
+    #[test]
+    fn round_trip_c_ordinal_value() {
+        round_trip_test::<_, COrdinalValue>(OrdinalValue {
+            value: 2,
+            direction: OrdinalDirection::FromEnd,
+        })
+    }
+
     #[test]
     fn round_trip_snips_grain() {
         round_trip_test::<_, SNIPS_GRAIN>(Grain::Second)
@@ -1005,67 +1870,70 @@ mod tests {
 
     #[test]
     fn round_trip_c_slot() {
-        round_trip_test::<_, CSlot>(Slot {
-            raw_value: "raw_value".to_string(),
-            value: SlotValue::Custom("slot_value".to_string().into()),
-            alternatives: vec![],
-            range: 0..1,
-            entity: "entity".to_string(),
-            slot_name: "slot_name".to_string(),
-            confidence_score: Some(0.5),
-        });
-
-        round_trip_test::<_, CSlot>(Slot {
-            raw_value: "raw_value".to_string(),
-            value: SlotValue::Custom("slot_value".to_string().into()),
-            alternatives: vec![],
-            range: 0..1,
-            entity: "entity".to_string(),
-            slot_name: "slot_name".to_string(),
-            confidence_score: None,
-        });
-
-        round_trip_test::<_, CSlot>(Slot {
-            raw_value: "raw_value".to_string(),
-            value: SlotValue::Custom("slot_value".to_string().into()),
-            alternatives: vec![
+        round_trip_test::<_, CSlot>(
+            Slot::new(
+                "raw_value".to_string(),
+                SlotValue::Custom("slot_value".to_string().into()),
+                0..1,
+                "entity".to_string(),
+                "slot_name".to_string(),
+            )
+            .with_confidence_score(0.5),
+        );
+
+        round_trip_test::<_, CSlot>(Slot::new(
+            "raw_value".to_string(),
+            SlotValue::Custom("slot_value".to_string().into()),
+            0..1,
+            "entity".to_string(),
+            "slot_name".to_string(),
+        ));
+
+        round_trip_test::<_, CSlot>(
+            Slot::new(
+                "raw_value".to_string(),
+                SlotValue::Custom("slot_value".to_string().into()),
+                0..1,
+                "entity".to_string(),
+                "slot_name".to_string(),
+            )
+            .with_alternatives(vec![
                 SlotValue::Custom("alternative_1".to_string().into()),
                 SlotValue::Custom("alternative_2".to_string().into()),
-            ],
-            range: 0..1,
-            entity: "entity".to_string(),
-            slot_name: "slot_name".to_string(),
-            confidence_score: None,
-        });
+            ]),
+        );
 
         let instant_time_value = InstantTimeValue {
             value: "value".to_string(),
             grain: Grain::Year,
             precision: Precision::Approximate,
         };
-        round_trip_test::<_, CSlot>(Slot {
-            raw_value: "raw_value".to_string(),
-            value: SlotValue::InstantTime(instant_time_value),
-            alternatives: vec![],
-            range: 0..1,
-            entity: "entity".to_string(),
-            slot_name: "slot_name".to_string(),
-            confidence_score: Some(0.5),
-        });
+        round_trip_test::<_, CSlot>(
+            Slot::new(
+                "raw_value".to_string(),
+                SlotValue::InstantTime(instant_time_value),
+                0..1,
+                "entity".to_string(),
+                "slot_name".to_string(),
+            )
+            .with_confidence_score(0.5),
+        );
 
         let instant_time_value = TimeIntervalValue {
             from: Some("lol".to_string()),
             to: Some("lol".to_string()),
+            to_inclusive: true,
         };
-        round_trip_test::<_, CSlot>(Slot {
-            raw_value: "raw_value".to_string(),
-            value: SlotValue::TimeInterval(instant_time_value),
-            alternatives: vec![],
-            range: 0..1,
-            entity: "entity".to_string(),
-            slot_name: "slot_name".to_string(),
-            confidence_score: Some(0.5),
-        });
+        round_trip_test::<_, CSlot>(
+            Slot::new(
+                "raw_value".to_string(),
+                SlotValue::TimeInterval(instant_time_value),
+                0..1,
+                "entity".to_string(),
+                "slot_name".to_string(),
+            )
+            .with_confidence_score(0.5),
+        );
     }
 
     #[test]
@@ -1087,144 +1955,148 @@ mod tests {
         let temperature_value = TemperatureValue {
             value: 20.0,
             unit: Some("°C".to_string()),
+            mode: TemperatureMode::Absolute,
         };
 
         round_trip_test::<_, CSlotList>(vec![
-            Slot {
-                raw_value: "raw_value_slot".to_string(),
-                value: SlotValue::Custom("custom_value".to_string().into()),
-                alternatives: vec![SlotValue::Custom("alternative".to_string().into())],
-                range: 0..42,
-                entity: "entity".to_string(),
-                slot_name: "slot_name".to_string(),
-                confidence_score: Some(1.0),
-            },
-            Slot {
-                raw_value: "".to_string(),
-                value: SlotValue::Temperature(temperature_value),
-                alternatives: vec![],
-                range: (0..42),
-                entity: "entity".to_string(),
-                slot_name: "slot_name".to_string(),
-                confidence_score: Some(0.5),
-            },
+            Slot::new(
+                "raw_value_slot".to_string(),
+                SlotValue::Custom("custom_value".to_string().into()),
+                0..42,
+                "entity".to_string(),
+                "slot_name".to_string(),
+            )
+            .with_alternatives(vec![SlotValue::Custom("alternative".to_string().into())])
+            .with_confidence_score(1.0),
+            Slot::new(
+                "".to_string(),
+                SlotValue::Temperature(temperature_value),
+                (0..42),
+                "entity".to_string(),
+                "slot_name".to_string(),
+            )
+            .with_confidence_score(0.5),
         ])
     }
 
     #[test]
     fn round_trip_c_intent_classifier_result() {
-        round_trip_test::<_, CIntentClassifierResult>(IntentClassifierResult {
-            intent_name: Some("intent_name".to_string()),
-            confidence_score: 0.5,
-        });
-        round_trip_test::<_, CIntentClassifierResult>(IntentClassifierResult {
-            intent_name: None,
-            confidence_score: 0.5,
-        });
+        round_trip_test::<_, CIntentClassifierResult>(IntentClassifierResult::new(
+            Some("intent_name".to_string()),
+            0.5,
+        ));
+        round_trip_test::<_, CIntentClassifierResult>(IntentClassifierResult::new(None, 0.5));
     }
 
     #[test]
     fn round_trip_c_intent_classifier_result_array() {
         round_trip_test::<_, CIntentClassifierResultArray>(vec![
-            IntentClassifierResult {
-                intent_name: Some("intent_name".to_string()),
-                confidence_score: 0.5,
-            },
-            IntentClassifierResult {
-                intent_name: None,
-                confidence_score: 0.5,
-            },
+            IntentClassifierResult::new(Some("intent_name".to_string()), 0.5),
+            IntentClassifierResult::new(None, 0.5),
         ])
     }
 
     #[test]
     fn round_trip_c_intent_parser_result() {
-        round_trip_test::<_, CIntentParserResult>(IntentParserResult {
-            input: "input".to_string(),
-            intent: IntentClassifierResult {
-                intent_name: Some("intent_name".to_string()),
-                confidence_score: 0.5,
-            },
-            slots: vec![Slot {
-                raw_value: "raw_value".to_string(),
-                value: SlotValue::Custom(StringValue {
-                    value: "custom_slot".to_string(),
-                }),
-                alternatives: vec![SlotValue::Custom(StringValue {
-                    value: "alternative".to_string(),
-                })],
-                range: 0..42,
-                entity: "entity".to_string(),
-                slot_name: "slot_name".to_string(),
-                confidence_score: Some(1.0),
-            }],
-            alternatives: vec![],
-        });
-        round_trip_test::<_, CIntentParserResult>(IntentParserResult {
-            input: "input".to_string(),
-            intent: IntentClassifierResult {
-                intent_name: Some("intent_name".to_string()),
-                confidence_score: 0.6,
-            },
-            slots: vec![],
-            alternatives: vec![IntentParserAlternative {
-                intent: IntentClassifierResult {
-                    intent_name: Some("other_intent_name".to_string()),
-                    confidence_score: 0.4,
-                },
-                slots: vec![],
-            }],
-        })
+        round_trip_test::<_, CIntentParserResult>(IntentParserResult::new(
+            "input".to_string(),
+            IntentClassifierResult::new(Some("intent_name".to_string()), 0.5),
+            vec![Slot::new(
+                "raw_value".to_string(),
+                SlotValue::Custom(StringValue::from("custom_slot")),
+                0..42,
+                "entity".to_string(),
+                "slot_name".to_string(),
+            )
+            .with_alternatives(vec![SlotValue::Custom(StringValue::from("alternative"))])
+            .with_confidence_score(1.0)],
+        ));
+        round_trip_test::<_, CIntentParserResult>(
+            IntentParserResult::new(
+                "input".to_string(),
+                IntentClassifierResult::new(Some("intent_name".to_string()), 0.6),
+                vec![],
+            )
+            .with_alternatives(vec![IntentParserAlternative::new(
+                IntentClassifierResult::new(Some("other_intent_name".to_string()), 0.4),
+                vec![],
+            )]),
+        )
+    }
+
+    #[test]
+    fn c_slots_have_utf16_ranges() {
+        // Given
+        let input = "wake me up 🙂 at 7am";
+        let slot = Slot::new(
+            "at 7am".to_string(),
+            SlotValue::Custom(StringValue::from("at 7am")),
+            13..19,
+            "snips/datetime".to_string(),
+            "time".to_string(),
+        );
+        let intent = IntentClassifierResult::new(Some("setAlarm".to_string()), 0.9);
+        let result = IntentParserResult::new(input.to_string(), intent.clone(), vec![slot.clone()])
+            .with_alternatives(vec![IntentParserAlternative::new(
+                intent,
+                vec![slot.clone()],
+            )]);
+
+        // When
+        let c_result = CIntentParserResult::from(result);
+        let c_slot = CSlot::from(slot);
+
+        // Then
+        let slots = unsafe { &*c_result.slots };
+        let slot = unsafe { &*slots.slots };
+        let alternatives = unsafe { &*c_result.alternatives };
+        let alternative_slots = unsafe { &*(*alternatives.intent_parser_alternatives).slots };
+        let alternative_slot = unsafe { &*alternative_slots.slots };
+        assert_eq!((13, 19), (slot.range_start, slot.range_end));
+        assert_eq!((14, 20), (slot.utf16_range_start, slot.utf16_range_end));
+        assert_eq!(
+            (14, 20),
+            (
+                alternative_slot.utf16_range_start,
+                alternative_slot.utf16_range_end
+            )
+        );
+        assert_eq!((-1, -1), (c_slot.utf16_range_start, c_slot.utf16_range_end));
     }
 
     #[test]
     fn round_trip_c_intent_parser_alternative() {
-        round_trip_test::<_, CIntentParserAlternative>(IntentParserAlternative {
-            intent: IntentClassifierResult {
-                intent_name: Some("intent_name".to_string()),
-                confidence_score: 0.5,
-            },
-            slots: vec![Slot {
-                raw_value: "raw_value".to_string(),
-                value: SlotValue::Custom(StringValue {
-                    value: "custom_slot".to_string(),
-                }),
-                alternatives: vec![],
-                range: 0..42,
-                entity: "entity".to_string(),
-                slot_name: "slot_name".to_string(),
-                confidence_score: Some(1.0),
-            }],
-        });
+        round_trip_test::<_, CIntentParserAlternative>(IntentParserAlternative::new(
+            IntentClassifierResult::new(Some("intent_name".to_string()), 0.5),
+            vec![Slot::new(
+                "raw_value".to_string(),
+                SlotValue::Custom(StringValue::from("custom_slot")),
+                0..42,
+                "entity".to_string(),
+                "slot_name".to_string(),
+            )
+            .with_confidence_score(1.0)],
+        ));
     }
 
     #[test]
     fn round_trip_c_intent_parser_alternative_array() {
         round_trip_test::<_, CIntentParserAlternativeArray>(vec![
-            IntentParserAlternative {
-                intent: IntentClassifierResult {
-                    intent_name: Some("intent_name".to_string()),
-                    confidence_score: 0.5,
-                },
-                slots: vec![Slot {
-                    raw_value: "raw_value".to_string(),
-                    value: SlotValue::Custom(StringValue {
-                        value: "custom_slot".to_string(),
-                    }),
-                    alternatives: vec![],
-                    range: 0..42,
-                    entity: "entity".to_string(),
-                    slot_name: "slot_name".to_string(),
-                    confidence_score: Some(1.0),
-                }],
-            },
-            IntentParserAlternative {
-                intent: IntentClassifierResult {
-                    intent_name: Some("other_intent_name".to_string()),
-                    confidence_score: 0.4,
-                },
-                slots: vec![],
-            },
+            IntentParserAlternative::new(
+                IntentClassifierResult::new(Some("intent_name".to_string()), 0.5),
+                vec![Slot::new(
+                    "raw_value".to_string(),
+                    SlotValue::Custom(StringValue::from("custom_slot")),
+                    0..42,
+                    "entity".to_string(),
+                    "slot_name".to_string(),
+                )
+                .with_confidence_score(1.0)],
+            ),
+            IntentParserAlternative::new(
+                IntentClassifierResult::new(Some("other_intent_name".to_string()), 0.4),
+                vec![],
+            ),
         ]);
     }
 }
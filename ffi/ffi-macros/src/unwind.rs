@@ -0,0 +1,96 @@
+use crate::errors::*;
+use failure::bail;
+use ffi_utils::CStringArray;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Runs the body of a C entry point, turning a panic into an error
+///
+/// Unwinding across the C boundary is undefined behavior and aborts most host applications, so
+/// every exported function must run its body through this helper, or through `catch_panic_or`
+/// when it cannot report errors. Panics are not expected, but the `From` conversions of the
+/// ontology to its C representation panic on strings containing a nul byte, which C strings
+/// cannot hold, so they must only be used within this helper.
+pub fn catch_panic<T, F>(f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(cause) => bail!("Unexpected panic: {}", panic_message(&*cause)),
+    }
+}
+
+/// Runs the body of a C entry point which cannot report errors, such as the ones returning a
+/// `CStringArray`, the fallback value being returned when the body panics
+pub fn catch_panic_or<T, F>(fallback: T, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    catch_panic(|| Ok(f())).unwrap_or(fallback)
+}
+
+/// Array without any string, returned by the entry points listing strings when they fail
+pub fn empty_string_array() -> CStringArray {
+    CStringArray {
+        data: std::ptr::null(),
+        size: 0,
+    }
+}
+
+fn panic_message(cause: &(dyn Any + Send)) -> &str {
+    if let Some(message) = cause.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = cause.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CSlot;
+    use snips_nlu_ontology::{Slot, SlotValue};
+
+    #[test]
+    fn test_catch_panic() {
+        // When
+        let ok = catch_panic(|| Ok(42));
+        let panicked: Result<()> = catch_panic(|| panic!("boom"));
+
+        // Then
+        assert_eq!(42, ok.unwrap());
+        assert_eq!("Unexpected panic: boom", panicked.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_catch_panic_or() {
+        // When
+        let ok = catch_panic_or(0, || 42);
+        let panicked = catch_panic_or(0, || panic!("boom"));
+
+        // Then
+        assert_eq!(42, ok);
+        assert_eq!(0, panicked);
+    }
+
+    #[test]
+    fn test_catch_panic_in_conversion() {
+        // Given
+        let slot = Slot::new(
+            "nul\0byte".to_string(),
+            SlotValue::Custom("nul byte".into()),
+            0..8,
+            "entity".to_string(),
+            "slot_name".to_string(),
+        );
+
+        // When
+        let converted = catch_panic(|| Ok(CSlot::from(slot)));
+
+        // Then
+        assert!(converted.is_err());
+    }
+}
@@ -1,35 +1,67 @@
 package ai.snips.nlu.ontology
 
+import ai.snips.nlu.ontology.SlotValue.AlphanumericCodeValue
 import ai.snips.nlu.ontology.SlotValue.AmountOfMoneyValue
+import ai.snips.nlu.ontology.SlotValue.BloodPressureValue
 import ai.snips.nlu.ontology.SlotValue.CityValue
+import ai.snips.nlu.ontology.SlotValue.CookingQuantityValue
 import ai.snips.nlu.ontology.SlotValue.CountryValue
 import ai.snips.nlu.ontology.SlotValue.CustomValue
+import ai.snips.nlu.ontology.SlotValue.DosageValue
 import ai.snips.nlu.ontology.SlotValue.DurationValue
+import ai.snips.nlu.ontology.SlotValue.FlightNumberValue
+import ai.snips.nlu.ontology.SlotValue.GeoCoordinateValue
+import ai.snips.nlu.ontology.SlotValue.HashtagValue
+import ai.snips.nlu.ontology.SlotValue.HostnameValue
 import ai.snips.nlu.ontology.SlotValue.InstantTimeValue
+import ai.snips.nlu.ontology.SlotValue.IpAddressValue
+import ai.snips.nlu.ontology.SlotValue.MediaPositionValue
+import ai.snips.nlu.ontology.SlotValue.MentionValue
 import ai.snips.nlu.ontology.SlotValue.MusicAlbumValue
 import ai.snips.nlu.ontology.SlotValue.MusicArtistValue
 import ai.snips.nlu.ontology.SlotValue.MusicTrackValue
 import ai.snips.nlu.ontology.SlotValue.NumberValue
 import ai.snips.nlu.ontology.SlotValue.OrdinalValue
 import ai.snips.nlu.ontology.SlotValue.PercentageValue
+import ai.snips.nlu.ontology.SlotValue.ProductCodeValue
+import ai.snips.nlu.ontology.SlotValue.ReferenceValue
 import ai.snips.nlu.ontology.SlotValue.RegionValue
+import ai.snips.nlu.ontology.SlotValue.RelativeChangeValue
+import ai.snips.nlu.ontology.SlotValue.ScoreValue
 import ai.snips.nlu.ontology.SlotValue.TemperatureValue
 import ai.snips.nlu.ontology.SlotValue.TimeIntervalValue
+import ai.snips.nlu.ontology.SlotValue.VersionValue
+import ai.snips.nlu.ontology.SlotValue.Type.ALPHANUMERIC_CODE
 import ai.snips.nlu.ontology.SlotValue.Type.AMOUNT_OF_MONEY
+import ai.snips.nlu.ontology.SlotValue.Type.BLOOD_PRESSURE
 import ai.snips.nlu.ontology.SlotValue.Type.CITY
+import ai.snips.nlu.ontology.SlotValue.Type.COOKING_QUANTITY
 import ai.snips.nlu.ontology.SlotValue.Type.COUNTRY
 import ai.snips.nlu.ontology.SlotValue.Type.CUSTOM
+import ai.snips.nlu.ontology.SlotValue.Type.DOSAGE
 import ai.snips.nlu.ontology.SlotValue.Type.DURATION
+import ai.snips.nlu.ontology.SlotValue.Type.FLIGHT_NUMBER
+import ai.snips.nlu.ontology.SlotValue.Type.GEO_COORDINATE
+import ai.snips.nlu.ontology.SlotValue.Type.HASHTAG
+import ai.snips.nlu.ontology.SlotValue.Type.HOSTNAME
 import ai.snips.nlu.ontology.SlotValue.Type.INSTANT_TIME
+import ai.snips.nlu.ontology.SlotValue.Type.IP_ADDRESS
+import ai.snips.nlu.ontology.SlotValue.Type.MEDIA_POSITION
+import ai.snips.nlu.ontology.SlotValue.Type.MENTION
 import ai.snips.nlu.ontology.SlotValue.Type.MUSICALBUM
 import ai.snips.nlu.ontology.SlotValue.Type.MUSICARTIST
 import ai.snips.nlu.ontology.SlotValue.Type.MUSICTRACK
 import ai.snips.nlu.ontology.SlotValue.Type.NUMBER
 import ai.snips.nlu.ontology.SlotValue.Type.ORDINAL
 import ai.snips.nlu.ontology.SlotValue.Type.PERCENTAGE
+import ai.snips.nlu.ontology.SlotValue.Type.PRODUCT_CODE
+import ai.snips.nlu.ontology.SlotValue.Type.REFERENCE
 import ai.snips.nlu.ontology.SlotValue.Type.REGION
+import ai.snips.nlu.ontology.SlotValue.Type.RELATIVE_CHANGE
+import ai.snips.nlu.ontology.SlotValue.Type.SCORE
 import ai.snips.nlu.ontology.SlotValue.Type.TEMPERATURE
 import ai.snips.nlu.ontology.SlotValue.Type.TIME_INTERVAL
+import ai.snips.nlu.ontology.SlotValue.Type.VERSION
 import com.fasterxml.jackson.annotation.JsonIgnore
 import com.fasterxml.jackson.annotation.JsonProperty
 import com.fasterxml.jackson.annotation.JsonSubTypes
@@ -52,12 +84,32 @@ data class Slot @ParcelConstructor constructor(@ParcelProperty("rawValue") val r
                                                @ParcelProperty("range") val range: Range,
                                                @ParcelProperty("entity") val entity: String,
                                                @ParcelProperty("slotName") val slotName: String,
-                                               @ParcelProperty("confidenceScore") val confidenceScore: Float?)
+                                               @ParcelProperty("confidenceScore") val confidenceScore: Float?,
+                                               // range in UTF-16 code units, as used by String.substring, when known
+                                               @ParcelProperty("utf16Range") val utf16Range: Range? = null)
 
 enum class Precision { APPROXIMATE, EXACT }
 
 enum class Grain { YEAR, QUARTER, MONTH, WEEK, DAY, HOUR, MINUTE, SECOND }
 
+enum class OrdinalDirection { FROM_START, FROM_END }
+
+enum class TemperatureMode { ABSOLUTE, DELTA }
+
+enum class ChangeDirection { INCREASE, DECREASE }
+
+enum class MediaPositionMode { ABSOLUTE, OFFSET }
+
+enum class Anaphora { SAME, DEMONSTRATIVE, OTHER }
+
+enum class CookingUnit { CUP, TABLESPOON, TEASPOON, PINCH, DASH, FLUID_OUNCE, MILLILITER, CENTILITER, DECILITER, LITER, OUNCE, POUND, GRAM, KILOGRAM }
+
+enum class CookingMetricUnit { MILLILITER, GRAM }
+
+enum class ChangeMagnitude { SLIGHT, LARGE }
+
+enum class ProductCodeFormat { ISBN_10, ISBN_13, EAN_13 }
+
 // TODO : add converters to JSR310 / ThreeTen types
 @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.EXISTING_PROPERTY, property = "kind")
 @JsonSubTypes(
@@ -75,7 +127,23 @@ enum class Grain { YEAR, QUARTER, MONTH, WEEK, DAY, HOUR, MINUTE, SECOND }
         Type(value = MusicTrackValue::class, name = "MusicTrack"),
         Type(value = CityValue::class, name = "City"),
         Type(value = CountryValue::class, name = "Country"),
-        Type(value = RegionValue::class, name = "Region")
+        Type(value = RegionValue::class, name = "Region"),
+        Type(value = RelativeChangeValue::class, name = "RelativeChange"),
+        Type(value = AlphanumericCodeValue::class, name = "AlphanumericCode"),
+        Type(value = FlightNumberValue::class, name = "FlightNumber"),
+        Type(value = ProductCodeValue::class, name = "ProductCode"),
+        Type(value = IpAddressValue::class, name = "IpAddress"),
+        Type(value = HostnameValue::class, name = "Hostname"),
+        Type(value = HashtagValue::class, name = "Hashtag"),
+        Type(value = MentionValue::class, name = "Mention"),
+        Type(value = VersionValue::class, name = "Version"),
+        Type(value = MediaPositionValue::class, name = "MediaPosition"),
+        Type(value = ScoreValue::class, name = "Score"),
+        Type(value = CookingQuantityValue::class, name = "CookingQuantity"),
+        Type(value = BloodPressureValue::class, name = "BloodPressure"),
+        Type(value = DosageValue::class, name = "Dosage"),
+        Type(value = GeoCoordinateValue::class, name = "GeoCoordinate"),
+        Type(value = ReferenceValue::class, name = "Reference")
 )
 sealed class SlotValue(val kind: Type) {
 
@@ -99,7 +167,23 @@ sealed class SlotValue(val kind: Type) {
         @JsonProperty("MusicTrack") MUSICTRACK,
         @JsonProperty("City") CITY,
         @JsonProperty("Country") COUNTRY,
-        @JsonProperty("Region") REGION
+        @JsonProperty("Region") REGION,
+        @JsonProperty("RelativeChange") RELATIVE_CHANGE,
+        @JsonProperty("AlphanumericCode") ALPHANUMERIC_CODE,
+        @JsonProperty("FlightNumber") FLIGHT_NUMBER,
+        @JsonProperty("ProductCode") PRODUCT_CODE,
+        @JsonProperty("IpAddress") IP_ADDRESS,
+        @JsonProperty("Hostname") HOSTNAME,
+        @JsonProperty("Hashtag") HASHTAG,
+        @JsonProperty("Mention") MENTION,
+        @JsonProperty("Version") VERSION,
+        @JsonProperty("MediaPosition") MEDIA_POSITION,
+        @JsonProperty("Score") SCORE,
+        @JsonProperty("CookingQuantity") COOKING_QUANTITY,
+        @JsonProperty("BloodPressure") BLOOD_PRESSURE,
+        @JsonProperty("Dosage") DOSAGE,
+        @JsonProperty("GeoCoordinate") GEO_COORDINATE,
+        @JsonProperty("Reference") REFERENCE
     }
 
     @Parcel(BEAN)
@@ -112,7 +196,9 @@ sealed class SlotValue(val kind: Type) {
     data class PercentageValue @ParcelConstructor constructor(@ParcelProperty("value") val value: Double) : SlotValue(PERCENTAGE)
 
     @Parcel(BEAN)
-    data class OrdinalValue @ParcelConstructor constructor(@ParcelProperty("value") val value: Long) : SlotValue(ORDINAL)
+    data class OrdinalValue @ParcelConstructor constructor(
+            @ParcelProperty("value") val value: Long,
+            @ParcelProperty("direction") val direction: OrdinalDirection = OrdinalDirection.FROM_START) : SlotValue(ORDINAL)
 
     @Parcel(BEAN)
     data class InstantTimeValue @ParcelConstructor constructor(
@@ -123,18 +209,21 @@ sealed class SlotValue(val kind: Type) {
     @Parcel(BEAN)
     data class TimeIntervalValue @ParcelConstructor constructor(
             @ParcelProperty("from") val from: String?,
-            @ParcelProperty("to") val to: String?) : SlotValue(TIME_INTERVAL)
+            @ParcelProperty("to") val to: String?,
+            @ParcelProperty("toInclusive") val toInclusive: Boolean = false) : SlotValue(TIME_INTERVAL)
 
     @Parcel(BEAN)
     data class AmountOfMoneyValue @ParcelConstructor constructor(
             @ParcelProperty("value") val value: Float,
             @ParcelProperty("precision") val precision: Precision,
-            @ParcelProperty("unit") val unit: String?) : SlotValue(AMOUNT_OF_MONEY)
+            @ParcelProperty("unit") val unit: String?,
+            @ParcelProperty("minorUnits") val minorUnits: Long) : SlotValue(AMOUNT_OF_MONEY)
 
     @Parcel(BEAN)
     data class TemperatureValue @ParcelConstructor constructor(
             @ParcelProperty("value") val value: Float,
-            @ParcelProperty("unit") val unit: String?) : SlotValue(TEMPERATURE)
+            @ParcelProperty("unit") val unit: String?,
+            @ParcelProperty("mode") val mode: TemperatureMode = TemperatureMode.ABSOLUTE) : SlotValue(TEMPERATURE)
 
     @Parcel(BEAN)
     data class DurationValue @ParcelConstructor constructor(
@@ -165,6 +254,82 @@ sealed class SlotValue(val kind: Type) {
 
     @Parcel(BEAN)
     data class RegionValue @ParcelConstructor constructor(@ParcelProperty("value") val value: String) : SlotValue(REGION)
+
+    @Parcel(BEAN)
+    data class RelativeChangeValue @ParcelConstructor constructor(
+            @ParcelProperty("direction") val direction: ChangeDirection,
+            @ParcelProperty("value") val value: Double?,
+            @ParcelProperty("unit") val unit: String?,
+            @ParcelProperty("magnitude") val magnitude: ChangeMagnitude?) : SlotValue(RELATIVE_CHANGE)
+
+    @Parcel(BEAN)
+    data class AlphanumericCodeValue @ParcelConstructor constructor(@ParcelProperty("value") val value: String) : SlotValue(ALPHANUMERIC_CODE)
+
+    @Parcel(BEAN)
+    data class FlightNumberValue @ParcelConstructor constructor(
+            @ParcelProperty("carrier") val carrier: String,
+            @ParcelProperty("number") val number: Int) : SlotValue(FLIGHT_NUMBER)
+
+    @Parcel(BEAN)
+    data class ProductCodeValue @ParcelConstructor constructor(
+            @ParcelProperty("format") val format: ProductCodeFormat,
+            @ParcelProperty("value") val value: String) : SlotValue(PRODUCT_CODE)
+
+    @Parcel(BEAN)
+    data class IpAddressValue @ParcelConstructor constructor(@ParcelProperty("value") val value: String) : SlotValue(IP_ADDRESS)
+
+    @Parcel(BEAN)
+    data class HostnameValue @ParcelConstructor constructor(@ParcelProperty("value") val value: String) : SlotValue(HOSTNAME)
+
+    @Parcel(BEAN)
+    data class HashtagValue @ParcelConstructor constructor(@ParcelProperty("value") val value: String) : SlotValue(HASHTAG)
+
+    @Parcel(BEAN)
+    data class MentionValue @ParcelConstructor constructor(@ParcelProperty("value") val value: String) : SlotValue(MENTION)
+
+    @Parcel(BEAN)
+    data class VersionValue @ParcelConstructor constructor(
+            @ParcelProperty("major") val major: Int,
+            @ParcelProperty("minor") val minor: Int,
+            @ParcelProperty("patch") val patch: Int) : SlotValue(VERSION)
+
+    @Parcel(BEAN)
+    data class MediaPositionValue @ParcelConstructor constructor(
+            @ParcelProperty("mode") val mode: MediaPositionMode,
+            @ParcelProperty("seconds") val seconds: Long) : SlotValue(MEDIA_POSITION)
+
+    @Parcel(BEAN)
+    data class ScoreValue @ParcelConstructor constructor(
+            @ParcelProperty("first") val first: Int,
+            @ParcelProperty("second") val second: Int) : SlotValue(SCORE)
+
+    @Parcel(BEAN)
+    data class CookingQuantityValue @ParcelConstructor constructor(
+            @ParcelProperty("value") val value: Double,
+            @ParcelProperty("unit") val unit: CookingUnit,
+            @ParcelProperty("metricValue") val metricValue: Double,
+            @ParcelProperty("metricUnit") val metricUnit: CookingMetricUnit) : SlotValue(COOKING_QUANTITY)
+
+    @Parcel(BEAN)
+    data class BloodPressureValue @ParcelConstructor constructor(
+            @ParcelProperty("systolic") val systolic: Int,
+            @ParcelProperty("diastolic") val diastolic: Int) : SlotValue(BLOOD_PRESSURE)
+
+    @Parcel(BEAN)
+    data class DosageValue @ParcelConstructor constructor(
+            @ParcelProperty("count") val count: Double,
+            @ParcelProperty("dose") val dose: Double?,
+            @ParcelProperty("unit") val unit: String?,
+            @ParcelProperty("form") val form: String?) : SlotValue(DOSAGE)
+
+    @Parcel(BEAN)
+    data class GeoCoordinateValue @ParcelConstructor constructor(
+            @ParcelProperty("latitude") val latitude: Double,
+            @ParcelProperty("longitude") val longitude: Double) : SlotValue(GEO_COORDINATE)
+
+    @Parcel(BEAN)
+    data class ReferenceValue @ParcelConstructor constructor(
+            @ParcelProperty("anaphora") val anaphora: Anaphora) : SlotValue(REFERENCE)
 }
 
 @Parcel(BEAN)
@@ -1,10 +1,14 @@
 package ai.snips.nlu.ontology.ffi
 
+import ai.snips.nlu.ontology.ChangeDirection
+import ai.snips.nlu.ontology.ChangeMagnitude
 import ai.snips.nlu.ontology.Grain
 import ai.snips.nlu.ontology.IntentClassifierResult
 import ai.snips.nlu.ontology.IntentParserAlternative
 import ai.snips.nlu.ontology.IntentParserResult
+import ai.snips.nlu.ontology.OrdinalDirection
 import ai.snips.nlu.ontology.Precision
+import ai.snips.nlu.ontology.ProductCodeFormat
 import ai.snips.nlu.ontology.Range
 import ai.snips.nlu.ontology.Slot
 import ai.snips.nlu.ontology.SlotValue
@@ -23,6 +27,27 @@ import ai.snips.nlu.ontology.SlotValue.RegionValue
 import ai.snips.nlu.ontology.SlotValue.OrdinalValue
 import ai.snips.nlu.ontology.SlotValue.TemperatureValue
 import ai.snips.nlu.ontology.SlotValue.TimeIntervalValue
+import ai.snips.nlu.ontology.SlotValue.ReferenceValue
+import ai.snips.nlu.ontology.SlotValue.GeoCoordinateValue
+import ai.snips.nlu.ontology.SlotValue.DosageValue
+import ai.snips.nlu.ontology.SlotValue.BloodPressureValue
+import ai.snips.nlu.ontology.SlotValue.CookingQuantityValue
+import ai.snips.nlu.ontology.SlotValue.ScoreValue
+import ai.snips.nlu.ontology.SlotValue.MediaPositionValue
+import ai.snips.nlu.ontology.SlotValue.VersionValue
+import ai.snips.nlu.ontology.SlotValue.MentionValue
+import ai.snips.nlu.ontology.SlotValue.HashtagValue
+import ai.snips.nlu.ontology.SlotValue.HostnameValue
+import ai.snips.nlu.ontology.SlotValue.IpAddressValue
+import ai.snips.nlu.ontology.SlotValue.ProductCodeValue
+import ai.snips.nlu.ontology.SlotValue.FlightNumberValue
+import ai.snips.nlu.ontology.SlotValue.AlphanumericCodeValue
+import ai.snips.nlu.ontology.TemperatureMode
+import ai.snips.nlu.ontology.MediaPositionMode
+import ai.snips.nlu.ontology.Anaphora
+import ai.snips.nlu.ontology.CookingUnit
+import ai.snips.nlu.ontology.CookingMetricUnit
+import ai.snips.nlu.ontology.SlotValue.RelativeChangeValue
 import com.sun.jna.Pointer
 import com.sun.jna.Structure
 import com.sun.jna.toJnaPointer
@@ -34,7 +59,9 @@ fun Pointer?.readString(): String = this!!.getString(0, RUST_ENCODING)
 fun String.toPointer(): Pointer = this.toJnaPointer(RUST_ENCODING)
 fun Int?.readGrain(): Grain = CGrain.toGrain(this!!)
 fun Int?.readPrecision(): Precision = CPrecision.toPrecision(this!!)
+fun Int?.readOrdinalDirection(): OrdinalDirection = COrdinalDirection.toOrdinalDirection(this!!)
 fun Int?.readRangeTo(end: Int?): Range = Range(this!!, end!!)
+fun Int?.readOptionalRangeTo(end: Int?): Range? = if (this!! < 0) null else Range(this!!, end!!)
 fun Float?.readFloat(): Float? = if (this!! < 0) null else this!!
 fun CSlotValue?.readSlotValue(): SlotValue = this!!.toSlotValue()
 
@@ -188,6 +215,98 @@ object CPrecision {
     }
 }
 
+object COrdinalDirection {
+    const val FROM_START = 0
+    const val FROM_END = 1
+
+    fun toOrdinalDirection(input: Int) = when (input) {
+        FROM_START -> OrdinalDirection.FROM_START
+        FROM_END -> OrdinalDirection.FROM_END
+        else -> throw IllegalArgumentException("unknown ordinal direction $input")
+    }
+}
+
+object CTemperatureMode {
+    const val ABSOLUTE = 0
+    const val DELTA = 1
+
+    fun toTemperatureMode(input: Int) = when (input) {
+        ABSOLUTE -> TemperatureMode.ABSOLUTE
+        DELTA -> TemperatureMode.DELTA
+        else -> throw IllegalArgumentException("unknown temperature mode $input")
+    }
+}
+
+object CMediaPositionMode {
+    const val ABSOLUTE = 0
+    const val OFFSET = 1
+
+    fun toMediaPositionMode(input: Int) = when (input) {
+        ABSOLUTE -> MediaPositionMode.ABSOLUTE
+        OFFSET -> MediaPositionMode.OFFSET
+        else -> throw IllegalArgumentException("unknown media position mode $input")
+    }
+}
+
+object CAnaphora {
+    const val SAME = 0
+    const val DEMONSTRATIVE = 1
+    const val OTHER = 2
+
+    fun toAnaphora(input: Int) = when (input) {
+        SAME -> Anaphora.SAME
+        DEMONSTRATIVE -> Anaphora.DEMONSTRATIVE
+        OTHER -> Anaphora.OTHER
+        else -> throw IllegalArgumentException("unknown anaphora $input")
+    }
+}
+
+object CCookingUnit {
+    const val CUP = 0
+    const val TABLESPOON = 1
+    const val TEASPOON = 2
+    const val PINCH = 3
+    const val DASH = 4
+    const val FLUID_OUNCE = 5
+    const val MILLILITER = 6
+    const val CENTILITER = 7
+    const val DECILITER = 8
+    const val LITER = 9
+    const val OUNCE = 10
+    const val POUND = 11
+    const val GRAM = 12
+    const val KILOGRAM = 13
+
+    fun toCookingUnit(input: Int) = when (input) {
+        CUP -> CookingUnit.CUP
+        TABLESPOON -> CookingUnit.TABLESPOON
+        TEASPOON -> CookingUnit.TEASPOON
+        PINCH -> CookingUnit.PINCH
+        DASH -> CookingUnit.DASH
+        FLUID_OUNCE -> CookingUnit.FLUID_OUNCE
+        MILLILITER -> CookingUnit.MILLILITER
+        CENTILITER -> CookingUnit.CENTILITER
+        DECILITER -> CookingUnit.DECILITER
+        LITER -> CookingUnit.LITER
+        OUNCE -> CookingUnit.OUNCE
+        POUND -> CookingUnit.POUND
+        GRAM -> CookingUnit.GRAM
+        KILOGRAM -> CookingUnit.KILOGRAM
+        else -> throw IllegalArgumentException("unknown cooking unit $input")
+    }
+}
+
+object CCookingMetricUnit {
+    const val MILLILITER = 0
+    const val GRAM = 1
+
+    fun toCookingMetricUnit(input: Int) = when (input) {
+        MILLILITER -> CookingMetricUnit.MILLILITER
+        GRAM -> CookingMetricUnit.GRAM
+        else -> throw IllegalArgumentException("unknown cooking metric unit $input")
+    }
+}
+
 class CSlotValue(p: Pointer) : Structure(p), Structure.ByReference {
     companion object {
         const val CUSTOM = 1
@@ -205,6 +324,22 @@ class CSlotValue(p: Pointer) : Structure(p), Structure.ByReference {
         const val CITY = 13
         const val COUNTRY = 14
         const val REGION = 15
+        const val RELATIVECHANGE = 16
+        const val ALPHANUMERICCODE = 17
+        const val FLIGHTNUMBER = 18
+        const val PRODUCTCODE = 19
+        const val IPADDRESS = 20
+        const val HOSTNAME = 21
+        const val HASHTAG = 22
+        const val MENTION = 23
+        const val VERSION = 24
+        const val MEDIAPOSITION = 25
+        const val SCORE = 26
+        const val COOKINGQUANTITY = 27
+        const val BLOODPRESSURE = 28
+        const val DOSAGE = 29
+        const val GEOCOORDINATE = 30
+        const val REFERENCE = 31
     }
 
     @JvmField var value_type: Int? = null
@@ -219,7 +354,7 @@ class CSlotValue(p: Pointer) : Structure(p), Structure.ByReference {
     fun toSlotValue(): SlotValue = when (value_type!!) {
         CUSTOM -> CustomValue(value.readString())
         NUMBER -> NumberValue(value!!.getDouble(0))
-        ORDINAL -> OrdinalValue(value!!.getLong(0))
+        ORDINAL -> COrdinalValue(value!!).toOrdinalValue()
         INSTANTTIME -> CInstantTimeValue(value!!).toInstantTimeValue()
         TIMEINTERVAL -> CTimeIntervalValue(value!!).toTimeIntervalValue()
         AMOUNTOFMONEY -> CAmountOfMoneyValue(value!!).toAmountOfMoneyValue()
@@ -232,10 +367,41 @@ class CSlotValue(p: Pointer) : Structure(p), Structure.ByReference {
         CITY -> CityValue(value.readString())
         COUNTRY -> CountryValue(value.readString())
         REGION -> RegionValue(value.readString())
+        RELATIVECHANGE -> CRelativeChangeValue(value!!).toRelativeChangeValue()
+        ALPHANUMERICCODE -> AlphanumericCodeValue(value.readString())
+        FLIGHTNUMBER -> CFlightNumberValue(value!!).toFlightNumberValue()
+        PRODUCTCODE -> CProductCodeValue(value!!).toProductCodeValue()
+        IPADDRESS -> IpAddressValue(value.readString())
+        HOSTNAME -> HostnameValue(value.readString())
+        HASHTAG -> HashtagValue(value.readString())
+        MENTION -> MentionValue(value.readString())
+        VERSION -> CVersionValue(value!!).toVersionValue()
+        MEDIAPOSITION -> CMediaPositionValue(value!!).toMediaPositionValue()
+        SCORE -> CScoreValue(value!!).toScoreValue()
+        COOKINGQUANTITY -> CCookingQuantityValue(value!!).toCookingQuantityValue()
+        BLOODPRESSURE -> CBloodPressureValue(value!!).toBloodPressureValue()
+        DOSAGE -> CDosageValue(value!!).toDosageValue()
+        GEOCOORDINATE -> CGeoCoordinateValue(value!!).toGeoCoordinateValue()
+        REFERENCE -> CReferenceValue(value!!).toReferenceValue()
         else -> throw IllegalArgumentException("unknown value type $value_type")
     }
 }
 
+class COrdinalValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var value: Long? = null
+    @JvmField var direction: Int? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("value", "direction")
+
+    fun toOrdinalValue() = OrdinalValue(value = value!!,
+                                        direction = direction.readOrdinalDirection())
+}
+
 class CInstantTimeValue(p: Pointer) : Structure(p), Structure.ByReference {
 
     @JvmField var value: Pointer? = null
@@ -260,14 +426,17 @@ class CTimeIntervalValue(p: Pointer) : Structure(p), Structure.ByReference {
 
     @JvmField var from: Pointer? = null
     @JvmField var to: Pointer? = null
+    @JvmField var to_inclusive: Int? = null
 
     init {
         read()
     }
 
-    override fun getFieldOrder() = listOf("from", "to")
+    override fun getFieldOrder() = listOf("from", "to", "to_inclusive")
 
-    fun toTimeIntervalValue() = TimeIntervalValue(from = from?.readString(), to = to?.readString())
+    fun toTimeIntervalValue() = TimeIntervalValue(from = from?.readString(),
+                                                  to = to?.readString(),
+                                                  toInclusive = to_inclusive!! != 0)
 }
 
 class CAmountOfMoneyValue(p: Pointer) : Structure(p), Structure.ByReference {
@@ -275,31 +444,35 @@ class CAmountOfMoneyValue(p: Pointer) : Structure(p), Structure.ByReference {
     @JvmField var value: Float? = null
     @JvmField var precision: Int? = null
     @JvmField var unit: Pointer? = null
+    @JvmField var minor_units: Long? = null
 
     init {
         read()
     }
 
-    override fun getFieldOrder() = listOf("unit", "value", "precision")
+    override fun getFieldOrder() = listOf("unit", "value", "precision", "minor_units")
 
     fun toAmountOfMoneyValue() = AmountOfMoneyValue(value = value!!,
                                                     precision = precision.readPrecision(),
-                                                    unit = unit?.readString())
+                                                    unit = unit?.readString(),
+                                                    minorUnits = minor_units!!)
 }
 
 class CTemperatureValue(p: Pointer) : Structure(p), Structure.ByReference {
 
     @JvmField var value: Float? = null
     @JvmField var unit: Pointer? = null
+    @JvmField var mode: Int? = null
 
     init {
         read()
     }
 
-    override fun getFieldOrder() = listOf("unit", "value")
+    override fun getFieldOrder() = listOf("unit", "value", "mode")
 
     fun toTemperatureValue() = TemperatureValue(value = value!!,
-                                                unit = unit?.readString())
+                                                unit = unit?.readString(),
+                                                mode = CTemperatureMode.toTemperatureMode(mode!!))
 
 }
 
@@ -341,6 +514,226 @@ class CDurationValue(p: Pointer) : Structure(p), Structure.ByReference {
 }
 
 
+object CChangeDirection {
+    const val INCREASE = 0
+    const val DECREASE = 1
+
+    fun toChangeDirection(input: Int) = when (input) {
+        INCREASE -> ChangeDirection.INCREASE
+        DECREASE -> ChangeDirection.DECREASE
+        else -> throw IllegalArgumentException("unknown change direction $input")
+    }
+}
+
+object CChangeMagnitude {
+    const val UNSPECIFIED = 0
+    const val SLIGHT = 1
+    const val LARGE = 2
+
+    fun toChangeMagnitude(input: Int) = when (input) {
+        UNSPECIFIED -> null
+        SLIGHT -> ChangeMagnitude.SLIGHT
+        LARGE -> ChangeMagnitude.LARGE
+        else -> throw IllegalArgumentException("unknown change magnitude $input")
+    }
+}
+
+class CRelativeChangeValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var direction: Int? = null
+    @JvmField var value: Double? = null
+    @JvmField var unit: Pointer? = null
+    @JvmField var magnitude: Int? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("direction", "value", "unit", "magnitude")
+
+    fun toRelativeChangeValue() = RelativeChangeValue(direction = CChangeDirection.toChangeDirection(direction!!),
+                                                      value = if (value!! < 0) null else value,
+                                                      unit = unit?.readString(),
+                                                      magnitude = CChangeMagnitude.toChangeMagnitude(magnitude!!))
+}
+
+
+class CFlightNumberValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var carrier: Pointer? = null
+    @JvmField var number: Int? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("carrier", "number")
+
+    fun toFlightNumberValue() = FlightNumberValue(carrier = carrier.readString(),
+                                                  number = number!!)
+}
+
+
+object CProductCodeFormat {
+    const val ISBN_10 = 0
+    const val ISBN_13 = 1
+    const val EAN_13 = 2
+
+    fun toProductCodeFormat(input: Int) = when (input) {
+        ISBN_10 -> ProductCodeFormat.ISBN_10
+        ISBN_13 -> ProductCodeFormat.ISBN_13
+        EAN_13 -> ProductCodeFormat.EAN_13
+        else -> throw IllegalArgumentException("unknown product code format $input")
+    }
+}
+
+class CProductCodeValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var format: Int? = null
+    @JvmField var value: Pointer? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("format", "value")
+
+    fun toProductCodeValue() = ProductCodeValue(format = CProductCodeFormat.toProductCodeFormat(format!!),
+                                                value = value.readString())
+}
+
+
+class CVersionValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var major: Int? = null
+    @JvmField var minor: Int? = null
+    @JvmField var patch: Int? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("major", "minor", "patch")
+
+    fun toVersionValue() = VersionValue(major = major!!, minor = minor!!, patch = patch!!)
+}
+
+
+class CMediaPositionValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var mode: Int? = null
+    @JvmField var seconds: Long? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("mode", "seconds")
+
+    fun toMediaPositionValue() = MediaPositionValue(mode = CMediaPositionMode.toMediaPositionMode(mode!!),
+                                                    seconds = seconds!!)
+}
+
+
+class CScoreValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var first: Int? = null
+    @JvmField var second: Int? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("first", "second")
+
+    fun toScoreValue() = ScoreValue(first = first!!, second = second!!)
+}
+
+
+class CCookingQuantityValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var value: Double? = null
+    @JvmField var unit: Int? = null
+    @JvmField var metric_value: Double? = null
+    @JvmField var metric_unit: Int? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("value", "unit", "metric_value", "metric_unit")
+
+    fun toCookingQuantityValue() = CookingQuantityValue(value = value!!,
+                                                        unit = CCookingUnit.toCookingUnit(unit!!),
+                                                        metricValue = metric_value!!,
+                                                        metricUnit = CCookingMetricUnit.toCookingMetricUnit(metric_unit!!))
+}
+
+
+class CBloodPressureValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var systolic: Int? = null
+    @JvmField var diastolic: Int? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("systolic", "diastolic")
+
+    fun toBloodPressureValue() = BloodPressureValue(systolic = systolic!!, diastolic = diastolic!!)
+}
+
+
+class CDosageValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var count: Double? = null
+    @JvmField var dose: Double? = null
+    @JvmField var unit: Pointer? = null
+    @JvmField var form: Pointer? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("count", "dose", "unit", "form")
+
+    fun toDosageValue() = DosageValue(count = count!!,
+                                      dose = if (dose!! < 0) null else dose,
+                                      unit = unit?.readString(),
+                                      form = form?.readString())
+}
+
+
+class CGeoCoordinateValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var latitude: Double? = null
+    @JvmField var longitude: Double? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("latitude", "longitude")
+
+    fun toGeoCoordinateValue() = GeoCoordinateValue(latitude = latitude!!, longitude = longitude!!)
+}
+
+
+class CReferenceValue(p: Pointer) : Structure(p), Structure.ByReference {
+
+    @JvmField var anaphora: Int? = null
+
+    init {
+        read()
+    }
+
+    override fun getFieldOrder() = listOf("anaphora")
+
+    fun toReferenceValue() = ReferenceValue(anaphora = CAnaphora.toAnaphora(anaphora!!))
+}
+
+
 class CSlot(p: Pointer) : Structure(p), Structure.ByReference {
 
     @JvmField var value: CSlotValue? = null
@@ -351,6 +744,8 @@ class CSlot(p: Pointer) : Structure(p), Structure.ByReference {
     @JvmField var range_start: Int? = null
     @JvmField var range_end: Int? = null
     @JvmField var confidence_score: Float? = null
+    @JvmField var utf16_range_start: Int? = null
+    @JvmField var utf16_range_end: Int? = null
 
     init {
         read()
@@ -363,7 +758,9 @@ class CSlot(p: Pointer) : Structure(p), Structure.ByReference {
                                           "slot_name",
                                           "range_start",
                                           "range_end",
-                                          "confidence_score")
+                                          "confidence_score",
+                                          "utf16_range_start",
+                                          "utf16_range_end")
 
     fun toSlot() = Slot(value = value.readSlotValue(),
                         rawValue = raw_value.readString(),
@@ -371,7 +768,8 @@ class CSlot(p: Pointer) : Structure(p), Structure.ByReference {
                         entity = entity.readString(),
                         slotName = slot_name.readString(),
                         range = range_start.readRangeTo(range_end),
-                        confidenceScore = confidence_score.readFloat())
+                        confidenceScore = confidence_score.readFloat(),
+                        utf16Range = utf16_range_start.readOptionalRangeTo(utf16_range_end))
 }
 
 class CSlotValueArray(p: Pointer?) : Structure(p), Structure.ByReference {
@@ -5,7 +5,10 @@ ROOT_DIR=$SCRIPTS_DIR/..
 
 pushd $ROOT_DIR # Go where Cargo.lock is
 
+# The generated header is referenced by platforms/c/module.modulemap, which
+# allows Swift and Objective-C code to `import SnipsNluOntology` directly
 cbindgen \
+    --config ffi/cbindgen.toml \
     --crate snips-nlu-ontology-ffi \
     --output platforms/c/libsnips_nlu_ontology.h
 
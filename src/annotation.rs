@@ -0,0 +1,241 @@
+//! Utilities to render builtin entities within the text they were extracted from
+//!
+//! Entity ranges are expressed in characters, not bytes.
+use crate::entity::builtin_entity::BuiltinEntity;
+use crate::errors::*;
+use crate::ontology::Slot;
+use failure::format_err;
+use std::ops::Range;
+
+/// Selects a set of non overlapping entities, giving priority to the longest ones
+///
+/// The selected entities are returned in the order in which they appear in the text.
+pub fn non_overlapping_entities(entities: &[BuiltinEntity]) -> Vec<&BuiltinEntity> {
+    let mut candidates = entities.iter().collect::<Vec<_>>();
+    candidates.sort_by(|a, b| {
+        b.range
+            .len()
+            .cmp(&a.range.len())
+            .then_with(|| a.canonical_cmp(b))
+    });
+    let mut selected: Vec<&BuiltinEntity> = vec![];
+    for candidate in candidates {
+        if selected
+            .iter()
+            .all(|e| e.range.end <= candidate.range.start || candidate.range.end <= e.range.start)
+        {
+            selected.push(candidate);
+        }
+    }
+    selected.sort_by(|a, b| a.canonical_cmp(b));
+    selected
+}
+
+/// Annotates the text with markdown-like markers around entities, e.g.
+/// `"wake me up [at 7am](snips/datetime)"`
+///
+/// When entities overlap, the ones returned by `non_overlapping_entities` are annotated.
+pub fn annotate_text(text: &str, entities: &[BuiltinEntity]) -> Result<String> {
+    let mut annotated = String::with_capacity(text.len());
+    let mut current_byte_index = 0;
+    for entity in non_overlapping_entities(entities) {
+        let byte_range = char_range_to_byte_range(text, &entity.range)?;
+        annotated.push_str(&text[current_byte_index..byte_range.start]);
+        annotated.push('[');
+        annotated.push_str(&text[byte_range.clone()]);
+        annotated.push_str("](");
+        annotated.push_str(entity.entity_kind.identifier());
+        annotated.push(')');
+        current_byte_index = byte_range.end;
+    }
+    annotated.push_str(&text[current_byte_index..]);
+    Ok(annotated)
+}
+
+/// Text in which builtin entities have been replaced by placeholders such as
+/// `<snips/datetime>`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactedText {
+    pub text: String,
+    pub redactions: Vec<Redaction>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Redaction {
+    pub placeholder: String,
+    /// The text which has been replaced
+    pub original_value: String,
+    /// Range of the replaced text in the original text
+    pub original_range: Range<usize>,
+    /// Range of the placeholder in the redacted text
+    pub redacted_range: Range<usize>,
+}
+
+/// Replaces entities with a placeholder made of their kind identifier, e.g.
+/// `"call me at <snips/time>"`, in order to log utterances without personal data
+///
+/// When entities overlap, the ones returned by `non_overlapping_entities` are redacted.
+pub fn redact_text(text: &str, entities: &[BuiltinEntity]) -> Result<RedactedText> {
+    let mut redacted = String::with_capacity(text.len());
+    let mut redacted_char_len = 0;
+    let mut redactions = vec![];
+    let mut current_byte_index = 0;
+    for entity in non_overlapping_entities(entities) {
+        let byte_range = char_range_to_byte_range(text, &entity.range)?;
+        let preceding_text = &text[current_byte_index..byte_range.start];
+        redacted.push_str(preceding_text);
+        redacted_char_len += preceding_text.chars().count();
+        let placeholder = format!("<{}>", entity.entity_kind.identifier());
+        let placeholder_char_len = placeholder.chars().count();
+        redacted.push_str(&placeholder);
+        redactions.push(Redaction {
+            placeholder,
+            original_value: text[byte_range.clone()].to_string(),
+            original_range: entity.range.clone(),
+            redacted_range: redacted_char_len..redacted_char_len + placeholder_char_len,
+        });
+        redacted_char_len += placeholder_char_len;
+        current_byte_index = byte_range.end;
+    }
+    redacted.push_str(&text[current_byte_index..]);
+    Ok(RedactedText {
+        text: redacted,
+        redactions,
+    })
+}
+
+/// Converts a character range of the text into a range of UTF-16 code units, which is how
+/// JavaScript and Java index strings
+///
+/// Characters outside of the Basic Multilingual Plane, such as most emojis, take two code units.
+pub fn char_range_to_utf16_range(text: &str, range: &Range<usize>) -> Result<Range<usize>> {
+    let byte_range = char_range_to_byte_range(text, range)?;
+    let start = text[..byte_range.start].encode_utf16().count();
+    let length = text[byte_range].encode_utf16().count();
+    Ok(start..start + length)
+}
+
+impl BuiltinEntity {
+    /// Range of the entity in the text it was extracted from, in UTF-16 code units
+    pub fn utf16_range(&self, text: &str) -> Result<Range<usize>> {
+        char_range_to_utf16_range(text, &self.range)
+    }
+}
+
+impl Slot {
+    /// Range of the slot in the input it was extracted from, in UTF-16 code units
+    pub fn utf16_range(&self, input: &str) -> Result<Range<usize>> {
+        char_range_to_utf16_range(input, &self.range)
+    }
+}
+
+pub(crate) fn char_range_to_byte_range(text: &str, range: &Range<usize>) -> Result<Range<usize>> {
+    let byte_index = |char_index: usize| {
+        text.char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(text.len()))
+            .nth(char_index)
+    };
+    match (byte_index(range.start), byte_index(range.end)) {
+        (Some(start), Some(end)) if start <= end => Ok(start..end),
+        _ => Err(format_err!(
+            "Range {:?} is not a valid character range of '{}'",
+            range,
+            text
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::builtin_entity::BuiltinEntityKind;
+    use crate::ontology::*;
+
+    fn entity(text: &str, range: Range<usize>, entity_kind: BuiltinEntityKind) -> BuiltinEntity {
+        BuiltinEntity {
+            value: text.chars().skip(range.start).take(range.len()).collect(),
+            range,
+            entity: SlotValue::Number(NumberValue { value: 3.0 }),
+            alternatives: vec![],
+            entity_kind,
+            negated: false,
+        }
+    }
+
+    #[test]
+    fn test_annotate_text() {
+        // Given
+        let text = "réveille-moi à 7h pendant 3 jours";
+        let entities = vec![
+            entity(text, 15..17, BuiltinEntityKind::Number),
+            entity(text, 13..17, BuiltinEntityKind::Time),
+            entity(text, 26..33, BuiltinEntityKind::Duration),
+            entity(text, 26..27, BuiltinEntityKind::Number),
+        ];
+
+        // When
+        let annotated = annotate_text(text, &entities).unwrap();
+
+        // Then
+        let expected_annotated =
+            "réveille-moi [à 7h](snips/time) pendant [3 jours](snips/duration)";
+        assert_eq!(expected_annotated, annotated);
+    }
+
+    #[test]
+    fn test_redact_text() {
+        // Given
+        let text = "réveille-moi à 7h pendant 3 jours";
+        let entities = vec![
+            entity(text, 13..17, BuiltinEntityKind::Time),
+            entity(text, 26..33, BuiltinEntityKind::Duration),
+        ];
+
+        // When
+        let redacted = redact_text(text, &entities).unwrap();
+
+        // Then
+        assert_eq!(
+            "réveille-moi <snips/time> pendant <snips/duration>",
+            redacted.text
+        );
+        assert_eq!(2, redacted.redactions.len());
+        assert_eq!("3 jours", redacted.redactions[1].original_value);
+        assert_eq!(26..33, redacted.redactions[1].original_range);
+        assert_eq!(34..50, redacted.redactions[1].redacted_range);
+    }
+
+    #[test]
+    fn test_utf16_range() {
+        // Given
+        let text = "wake me up 🙂 at 7am";
+        let time = entity(text, 13..19, BuiltinEntityKind::Time);
+
+        // When
+        let utf16_range = time.utf16_range(text).unwrap();
+
+        // Then
+        assert_eq!(14..20, utf16_range);
+        let utf16_text = text.encode_utf16().collect::<Vec<_>>();
+        assert_eq!(
+            "at 7am",
+            String::from_utf16(&utf16_text[utf16_range]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_annotate_text_with_invalid_range() {
+        // Given
+        let text = "three";
+        let entities = vec![entity(text, 0..8, BuiltinEntityKind::Number)];
+
+        // When
+        let annotated = annotate_text(text, &entities);
+
+        // Then
+        assert!(annotated.is_err());
+    }
+}
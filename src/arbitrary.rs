@@ -0,0 +1,630 @@
+//! `proptest` strategies generating realistic ontology values, enabled with the `proptest`
+//! feature
+use crate::entity::builtin_entity::{BuiltinEntity, BuiltinEntityKind};
+use crate::ontology::*;
+use proptest::prelude::*;
+use proptest::sample::select;
+use std::net::IpAddr;
+
+/// Floating values with at most two decimals, which survive a JSON round trip unchanged
+fn decimal_value() -> impl Strategy<Value = f64> {
+    (-1_000_000i64..1_000_000).prop_map(|v| v as f64 / 100.)
+}
+
+fn datetime_value() -> impl Strategy<Value = String> {
+    (
+        (1990i32..2050, 1u32..=12, 1u32..=28),
+        (0u32..24, 0u32..60, 0u32..60),
+        -11i32..=14,
+    )
+        .prop_map(|((year, month, day), (hour, minute, second), offset)| {
+            format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}:{:02} {:+03}:00",
+                year, month, day, hour, minute, second, offset
+            )
+        })
+}
+
+fn string_value(pattern: &'static str) -> impl Strategy<Value = StringValue> {
+    pattern.prop_map(StringValue::from)
+}
+
+impl Arbitrary for Grain {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(vec![
+            Grain::Year,
+            Grain::Quarter,
+            Grain::Month,
+            Grain::Week,
+            Grain::Day,
+            Grain::Hour,
+            Grain::Minute,
+            Grain::Second,
+        ])
+        .boxed()
+    }
+}
+
+impl Arbitrary for Precision {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(vec![Precision::Approximate, Precision::Exact]).boxed()
+    }
+}
+
+impl Arbitrary for BuiltinEntityKind {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(BuiltinEntityKind::all()).boxed()
+    }
+}
+
+impl Arbitrary for NumberValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        decimal_value()
+            .prop_map(|value| NumberValue { value })
+            .boxed()
+    }
+}
+
+impl Arbitrary for OrdinalDirection {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(vec![OrdinalDirection::FromStart, OrdinalDirection::FromEnd]).boxed()
+    }
+}
+
+impl Arbitrary for OrdinalValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (1i64..1000, any::<OrdinalDirection>())
+            .prop_map(|(value, direction)| OrdinalValue { value, direction })
+            .boxed()
+    }
+}
+
+impl Arbitrary for PercentageValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (0i64..20_000)
+            .prop_map(|v| PercentageValue {
+                value: v as f64 / 100.,
+            })
+            .boxed()
+    }
+}
+
+/// Percentages resolved for device levels, which are bounded between 0 and 100
+fn level_value() -> impl Strategy<Value = PercentageValue> {
+    (0i64..=10_000).prop_map(|v| PercentageValue {
+        value: v as f64 / 100.,
+    })
+}
+
+impl Arbitrary for InstantTimeValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (datetime_value(), any::<Grain>(), any::<Precision>())
+            .prop_map(|(value, grain, precision)| InstantTimeValue {
+                value,
+                grain,
+                precision,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for TimeIntervalValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(datetime_value()),
+            proptest::option::of(datetime_value()),
+            any::<bool>(),
+        )
+            .prop_map(|(from, to, to_inclusive)| TimeIntervalValue {
+                from,
+                to,
+                to_inclusive,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for AmountOfMoneyValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            0i64..10_000_000,
+            any::<Precision>(),
+            proptest::option::of(select(vec!["€", "$", "£", "¥", "USD", "EUR", "cent"])),
+        )
+            .prop_map(|(minor_units, precision, unit)| {
+                AmountOfMoneyValue::from_minor_units(
+                    minor_units,
+                    precision,
+                    unit.map(|u| u.to_string()),
+                )
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for TemperatureMode {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(vec![TemperatureMode::Absolute, TemperatureMode::Delta]).boxed()
+    }
+}
+
+impl Arbitrary for TemperatureValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            (-500i32..1500).prop_map(|v| v as f32 / 10.),
+            proptest::option::of(select(vec!["celsius", "fahrenheit", "kelvin", "degree"])),
+            any::<TemperatureMode>(),
+        )
+            .prop_map(|(value, unit, mode)| TemperatureValue {
+                value,
+                unit: unit.map(|u| u.to_string()),
+                mode,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for DurationValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            (0i64..5, 0i64..4, 0i64..12, 0i64..5),
+            (0i64..7, 0i64..24, 0i64..60, 0i64..60),
+            any::<Precision>(),
+        )
+            .prop_map(
+                |((years, quarters, months, weeks), (days, hours, minutes, seconds), precision)| {
+                    DurationValue {
+                        years,
+                        quarters,
+                        months,
+                        weeks,
+                        days,
+                        hours,
+                        minutes,
+                        seconds,
+                        precision,
+                    }
+                },
+            )
+            .boxed()
+    }
+}
+
+impl Arbitrary for ChangeDirection {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(vec![ChangeDirection::Increase, ChangeDirection::Decrease]).boxed()
+    }
+}
+
+impl Arbitrary for ChangeMagnitude {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        select(vec![ChangeMagnitude::Slight, ChangeMagnitude::Large]).boxed()
+    }
+}
+
+impl Arbitrary for RelativeChangeValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let precise_change = (
+            any::<ChangeDirection>(),
+            (1i64..10_000).prop_map(|v| v as f64 / 100.),
+            proptest::option::of(select(vec!["degree", "%", "celsius"])),
+        )
+            .prop_map(|(direction, value, unit)| RelativeChangeValue {
+                direction,
+                value: Some(value),
+                unit: unit.map(|unit| unit.to_string()),
+                magnitude: None,
+            });
+        let vague_change = (
+            any::<ChangeDirection>(),
+            proptest::option::of(any::<ChangeMagnitude>()),
+        )
+            .prop_map(|(direction, magnitude)| RelativeChangeValue {
+                direction,
+                value: None,
+                unit: None,
+                magnitude,
+            });
+        prop_oneof![precise_change, vague_change].boxed()
+    }
+}
+
+impl Arbitrary for FlightNumberValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        ("[A-Z][A-Z0-9]|[A-Z]{3}", 1u32..10_000)
+            .prop_map(|(carrier, number)| FlightNumberValue { carrier, number })
+            .boxed()
+    }
+}
+
+impl Arbitrary for DosageValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let dose = prop::option::of((1u32..1000, select(vec!["mg", "g", "ml", "µg", "IU"])));
+        let form = prop::option::of(select(vec!["tablet", "capsule", "drop", "puff"]));
+        (1u32..5, dose, form)
+            .prop_filter("A dosage has a dose or a form", |(_, dose, form)| {
+                dose.is_some() || form.is_some()
+            })
+            .prop_map(|(count, dose, form)| DosageValue {
+                count: count as f64,
+                dose: dose.map(|(dose, _)| dose as f64),
+                unit: dose.map(|(_, unit)| unit.to_string()),
+                form: form.map(|form| form.to_string()),
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for MediaPositionValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let position = (0i64..36_000).prop_map(|seconds| MediaPositionValue {
+            mode: MediaPositionMode::Absolute,
+            seconds,
+        });
+        let offset = (-3_600i64..3_600).prop_map(|seconds| MediaPositionValue {
+            mode: MediaPositionMode::Offset,
+            seconds,
+        });
+        prop_oneof![position, offset].boxed()
+    }
+}
+
+impl Arbitrary for VersionValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (0u32..100, 0u32..100, 0u32..100)
+            .prop_map(|(major, minor, patch)| VersionValue {
+                major,
+                minor,
+                patch,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for ProductCodeValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof!["[0-9]{9}", "97[89][0-9]{9}", "[0-9]{12}"]
+            .prop_filter_map("Invalid product code", |digits| {
+                (0..=10)
+                    .map(|check| match check {
+                        10 => format!("{}X", digits),
+                        _ => format!("{}{}", digits, check),
+                    })
+                    .find_map(|code| ProductCodeValue::from_code(&code).ok())
+            })
+            .boxed()
+    }
+}
+
+/// Strategy generating slot values which can be resolved for the provided entity kind
+pub fn slot_value_for_kind(kind: BuiltinEntityKind) -> BoxedStrategy<SlotValue> {
+    match kind {
+        BuiltinEntityKind::AmountOfMoney => any::<AmountOfMoneyValue>()
+            .prop_map(SlotValue::AmountOfMoney)
+            .boxed(),
+        BuiltinEntityKind::Duration => any::<DurationValue>().prop_map(SlotValue::Duration).boxed(),
+        BuiltinEntityKind::Number => any::<NumberValue>().prop_map(SlotValue::Number).boxed(),
+        BuiltinEntityKind::Ordinal => any::<OrdinalValue>().prop_map(SlotValue::Ordinal).boxed(),
+        BuiltinEntityKind::Temperature => any::<TemperatureValue>()
+            .prop_map(SlotValue::Temperature)
+            .boxed(),
+        BuiltinEntityKind::Datetime => prop_oneof![
+            any::<InstantTimeValue>().prop_map(SlotValue::InstantTime),
+            any::<TimeIntervalValue>().prop_map(SlotValue::TimeInterval),
+        ]
+        .boxed(),
+        BuiltinEntityKind::Date | BuiltinEntityKind::Time => any::<InstantTimeValue>()
+            .prop_map(SlotValue::InstantTime)
+            .boxed(),
+        BuiltinEntityKind::DatePeriod | BuiltinEntityKind::TimePeriod => any::<TimeIntervalValue>()
+            .prop_map(SlotValue::TimeInterval)
+            .boxed(),
+        BuiltinEntityKind::Percentage => any::<PercentageValue>()
+            .prop_map(SlotValue::Percentage)
+            .boxed(),
+        BuiltinEntityKind::MusicAlbum => string_value("[A-Z][a-z]{2,10}( [a-z]{2,10}){0,3}")
+            .prop_map(SlotValue::MusicAlbum)
+            .boxed(),
+        BuiltinEntityKind::MusicArtist => string_value("[A-Z][a-z]{2,10}( [A-Z][a-z]{2,10})?")
+            .prop_map(SlotValue::MusicArtist)
+            .boxed(),
+        BuiltinEntityKind::MusicTrack => string_value("[A-Z][a-z]{2,10}( [a-z]{2,10}){0,4}")
+            .prop_map(SlotValue::MusicTrack)
+            .boxed(),
+        BuiltinEntityKind::City => string_value("[A-Z][a-z]{2,12}")
+            .prop_map(SlotValue::City)
+            .boxed(),
+        BuiltinEntityKind::Country => string_value("[A-Z][a-z]{2,12}")
+            .prop_map(SlotValue::Country)
+            .boxed(),
+        BuiltinEntityKind::Region => string_value("[A-Z][a-z]{2,12}")
+            .prop_map(SlotValue::Region)
+            .boxed(),
+        BuiltinEntityKind::VolumeLevel => level_value().prop_map(SlotValue::Percentage).boxed(),
+        BuiltinEntityKind::Brightness => level_value().prop_map(SlotValue::Percentage).boxed(),
+        BuiltinEntityKind::RelativeChange => any::<RelativeChangeValue>()
+            .prop_map(SlotValue::RelativeChange)
+            .boxed(),
+        BuiltinEntityKind::AlphanumericCode => string_value("[A-Z0-9]{4,8}")
+            .prop_map(SlotValue::AlphanumericCode)
+            .boxed(),
+        BuiltinEntityKind::FlightNumber => any::<FlightNumberValue>()
+            .prop_map(SlotValue::FlightNumber)
+            .boxed(),
+        BuiltinEntityKind::ProductCode => any::<ProductCodeValue>()
+            .prop_map(SlotValue::ProductCode)
+            .boxed(),
+        BuiltinEntityKind::IpAddress => any::<IpAddr>()
+            .prop_map(|address| SlotValue::IpAddress(StringValue::from(address.to_string())))
+            .boxed(),
+        BuiltinEntityKind::Hostname => string_value("[a-z][a-z0-9]{2,10}\\.(com|org|net|ai)")
+            .prop_map(SlotValue::Hostname)
+            .boxed(),
+        BuiltinEntityKind::Hashtag => string_value("#[a-z][a-z0-9_]{2,15}")
+            .prop_map(SlotValue::Hashtag)
+            .boxed(),
+        BuiltinEntityKind::Mention => string_value("@[a-z][a-z0-9_]{2,15}")
+            .prop_map(SlotValue::Mention)
+            .boxed(),
+        BuiltinEntityKind::Version => any::<VersionValue>().prop_map(SlotValue::Version).boxed(),
+        BuiltinEntityKind::MediaPosition => any::<MediaPositionValue>()
+            .prop_map(SlotValue::MediaPosition)
+            .boxed(),
+        BuiltinEntityKind::Score => (0u32..10, 0u32..10)
+            .prop_map(|(first, second)| SlotValue::Score(ScoreValue { first, second }))
+            .boxed(),
+        BuiltinEntityKind::CookingQuantity => (
+            1u32..1000,
+            select(vec![
+                CookingUnit::Cup,
+                CookingUnit::Tablespoon,
+                CookingUnit::Teaspoon,
+                CookingUnit::Pinch,
+                CookingUnit::Ounce,
+                CookingUnit::Gram,
+                CookingUnit::Milliliter,
+            ]),
+        )
+            .prop_map(|(value, unit)| {
+                SlotValue::CookingQuantity(CookingQuantityValue::new(value as f64, unit))
+            })
+            .boxed(),
+        BuiltinEntityKind::BloodPressure => (90u32..200, 50u32..120)
+            .prop_map(|(systolic, diastolic)| {
+                SlotValue::BloodPressure(BloodPressureValue {
+                    systolic,
+                    diastolic,
+                })
+            })
+            .boxed(),
+        BuiltinEntityKind::Dosage => any::<DosageValue>().prop_map(SlotValue::Dosage).boxed(),
+        BuiltinEntityKind::GeoCoordinate => (-360i32..=360, -720i32..=720)
+            .prop_map(|(latitude, longitude)| {
+                SlotValue::GeoCoordinate(GeoCoordinateValue {
+                    latitude: latitude as f64 / 4.,
+                    longitude: longitude as f64 / 4.,
+                })
+            })
+            .boxed(),
+    }
+}
+
+impl Arbitrary for SlotValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            string_value("[a-z]{1,10}( [a-z]{1,10}){0,2}").prop_map(SlotValue::Custom),
+            any::<BuiltinEntityKind>().prop_flat_map(slot_value_for_kind),
+            prop_oneof![
+                Just(Anaphora::Same),
+                Just(Anaphora::Demonstrative),
+                Just(Anaphora::Other),
+            ]
+            .prop_map(|anaphora| SlotValue::Reference(ReferenceValue { anaphora })),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for BuiltinEntity {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<BuiltinEntityKind>(),
+            "[a-z0-9]{1,10}( [a-z0-9]{1,10}){0,3}",
+            0usize..100,
+        )
+            .prop_flat_map(|(entity_kind, value, start)| {
+                let range = start..start + value.chars().count();
+                (
+                    slot_value_for_kind(entity_kind),
+                    proptest::collection::vec(slot_value_for_kind(entity_kind), 0..3),
+                    any::<bool>(),
+                )
+                    .prop_map(move |(entity, alternatives, negated)| {
+                        BuiltinEntity {
+                            value: value.clone(),
+                            range: range.clone(),
+                            entity,
+                            alternatives,
+                            entity_kind,
+                            negated,
+                        }
+                    })
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for IntentClassifierResult {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of("[A-Z][A-Za-z]{2,20}"), 0u32..=100)
+            .prop_map(|(intent_name, score)| IntentClassifierResult {
+                intent_name,
+                confidence_score: score as f32 / 100.,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Slot {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            "[a-z0-9]{1,10}( [a-z0-9]{1,10}){0,3}",
+            0usize..100,
+            any::<SlotValue>(),
+            proptest::collection::vec(any::<SlotValue>(), 0..3),
+            "[a-z][a-zA-Z]{2,15}",
+            proptest::option::of(0u32..=100),
+        )
+            .prop_map(
+                |(raw_value, start, value, alternatives, slot_name, score)| {
+                    let range = start..start + raw_value.chars().count();
+                    Slot {
+                        raw_value,
+                        value,
+                        alternatives,
+                        range,
+                        entity: slot_name.clone(),
+                        slot_name,
+                        confidence_score: score.map(|s| s as f32 / 100.),
+                    }
+                },
+            )
+            .boxed()
+    }
+}
+
+impl Arbitrary for IntentParserAlternative {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<IntentClassifierResult>(),
+            proptest::collection::vec(any::<Slot>(), 0..4),
+        )
+            .prop_map(|(intent, slots)| IntentParserAlternative { intent, slots })
+            .boxed()
+    }
+}
+
+impl Arbitrary for IntentParserResult {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            "[a-z]{1,10}( [a-z0-9]{1,10}){0,10}",
+            any::<IntentClassifierResult>(),
+            proptest::collection::vec(any::<Slot>(), 0..4),
+            proptest::collection::vec(any::<IntentParserAlternative>(), 0..3),
+        )
+            .prop_map(|(input, intent, slots, alternatives)| IntentParserResult {
+                input,
+                intent,
+                slots,
+                alternatives,
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    proptest! {
+        #[test]
+        fn slot_value_ser_de_round_trip(value in any::<SlotValue>()) {
+            let json = serde_json::to_string(&value).unwrap();
+            prop_assert_eq!(value, serde_json::from_str(&json).unwrap());
+        }
+
+        #[test]
+        fn builtin_entity_ser_de_round_trip(entity in any::<BuiltinEntity>()) {
+            let json = serde_json::to_string(&entity).unwrap();
+            prop_assert_eq!(entity, serde_json::from_str(&json).unwrap());
+        }
+
+        #[test]
+        fn intent_parser_result_ser_de_round_trip(result in any::<IntentParserResult>()) {
+            let json = serde_json::to_string(&result).unwrap();
+            prop_assert_eq!(result, serde_json::from_str(&json).unwrap());
+        }
+    }
+}
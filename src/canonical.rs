@@ -0,0 +1,626 @@
+//! Parsing of slot values from terse canonical strings, for configuration files and tests
+use crate::codes::{
+    normalize_hashtag, normalize_hostname, normalize_ip_address, normalize_mention,
+};
+use crate::datetime::DatetimeComponents;
+use crate::errors::*;
+use crate::language::Language;
+use crate::ontology::*;
+use failure::{bail, format_err};
+
+impl SlotValue {
+    /// Parses a slot value of the given kind from its canonical string representation
+    ///
+    /// Datetimes are expressed as in the resolved values, e.g. "2017-06-13 18:00:00 +02:00",
+    /// and the grain of instant times is the finest non-zero time component of the datetime, or
+    /// the day at midnight. Time intervals separate their bounds with a slash, either of them
+    /// being optional, e.g. "2017-06-07 18:00:00 +02:00/". Other values use their English
+    /// `Display` representation, e.g. "3 months 1 day", "23 °C", "$10.05", "12.5%" or "2nd to
+    /// last". Changes of temperature are prefixed with a delta sign, e.g. "Δ+2 °C" or "Δ-2 °C".
+    pub fn from_canonical(kind: SlotValueKind, canonical: &str) -> Result<SlotValue> {
+        let canonical = canonical.trim();
+        let string_value = || StringValue::from(canonical.to_string());
+        Ok(match kind {
+            SlotValueKind::Custom => SlotValue::Custom(string_value()),
+            SlotValueKind::MusicAlbum => SlotValue::MusicAlbum(string_value()),
+            SlotValueKind::MusicArtist => SlotValue::MusicArtist(string_value()),
+            SlotValueKind::MusicTrack => SlotValue::MusicTrack(string_value()),
+            SlotValueKind::City => SlotValue::City(string_value()),
+            SlotValueKind::Country => SlotValue::Country(string_value()),
+            SlotValueKind::Region => SlotValue::Region(string_value()),
+            SlotValueKind::Number => SlotValue::Number(NumberValue {
+                value: parse_number(canonical)?,
+            }),
+            SlotValueKind::Ordinal => SlotValue::Ordinal(parse_ordinal(canonical)?),
+            SlotValueKind::Percentage => SlotValue::Percentage(PercentageValue {
+                value: parse_number(canonical.trim_end_matches('%').trim_end())?,
+            }),
+            SlotValueKind::InstantTime => {
+                SlotValue::InstantTime(InstantTimeValue::from_datetime(canonical)?)
+            }
+            SlotValueKind::TimeInterval => SlotValue::TimeInterval(parse_time_interval(canonical)?),
+            SlotValueKind::AmountOfMoney => {
+                SlotValue::AmountOfMoney(parse_amount_of_money(canonical)?)
+            }
+            SlotValueKind::Temperature => SlotValue::Temperature(parse_temperature(canonical)?),
+            SlotValueKind::Duration => SlotValue::Duration(parse_duration(canonical)?),
+            SlotValueKind::RelativeChange => {
+                SlotValue::RelativeChange(parse_relative_change(canonical)?)
+            }
+            SlotValueKind::AlphanumericCode => SlotValue::AlphanumericCode(string_value()),
+            SlotValueKind::FlightNumber => {
+                SlotValue::FlightNumber(FlightNumberValue::from_code(canonical)?)
+            }
+            SlotValueKind::ProductCode => {
+                SlotValue::ProductCode(ProductCodeValue::from_code(canonical)?)
+            }
+            SlotValueKind::IpAddress => SlotValue::IpAddress(StringValue::from(
+                normalize_ip_address(canonical)
+                    .ok_or_else(|| format_err!("Invalid IP address: '{}'", canonical))?,
+            )),
+            SlotValueKind::Hostname => SlotValue::Hostname(StringValue::from(
+                normalize_hostname(canonical)
+                    .ok_or_else(|| format_err!("Invalid host name: '{}'", canonical))?,
+            )),
+            SlotValueKind::Hashtag => SlotValue::Hashtag(StringValue::from(
+                normalize_hashtag(canonical)
+                    .ok_or_else(|| format_err!("Invalid hashtag: '{}'", canonical))?,
+            )),
+            SlotValueKind::Mention => SlotValue::Mention(StringValue::from(
+                normalize_mention(canonical)
+                    .ok_or_else(|| format_err!("Invalid mention: '{}'", canonical))?,
+            )),
+            SlotValueKind::Version => SlotValue::Version(VersionValue::from_version(canonical)?),
+            SlotValueKind::MediaPosition => {
+                SlotValue::MediaPosition(parse_media_position(canonical)?)
+            }
+            SlotValueKind::Score => SlotValue::Score(parse_score(canonical)?),
+            SlotValueKind::CookingQuantity => {
+                SlotValue::CookingQuantity(parse_cooking_quantity(canonical)?)
+            }
+            SlotValueKind::BloodPressure => {
+                SlotValue::BloodPressure(parse_blood_pressure(canonical)?)
+            }
+            SlotValueKind::Dosage => SlotValue::Dosage(parse_dosage(canonical)?),
+            SlotValueKind::GeoCoordinate => {
+                SlotValue::GeoCoordinate(GeoCoordinateValue::from_coordinates(canonical)?)
+            }
+            SlotValueKind::Reference => SlotValue::Reference(
+                ReferenceValue::from_anaphora(canonical, Language::EN)
+                    .ok_or_else(|| format_err!("Invalid reference: '{}'", canonical))?,
+            ),
+        })
+    }
+}
+
+/// Numbers may contain the digit grouping separators of their English representation, e.g.
+/// "1,234.5"
+fn parse_number(string: &str) -> Result<f64> {
+    string
+        .replace(',', "")
+        .parse::<f64>()
+        .map_err(|_| format_err!("Invalid number: '{}'", string))
+}
+
+fn parse_ordinal(canonical: &str) -> Result<OrdinalValue> {
+    if canonical == "last" {
+        return Ok(OrdinalValue {
+            value: 1,
+            direction: OrdinalDirection::FromEnd,
+        });
+    }
+    let (position, direction) = match canonical.strip_suffix(" to last") {
+        Some(position) => (position, OrdinalDirection::FromEnd),
+        None => (canonical, OrdinalDirection::FromStart),
+    };
+    let digits = position.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let value = digits
+        .parse::<i64>()
+        .map_err(|_| format_err!("Invalid ordinal: '{}'", canonical))?;
+    Ok(OrdinalValue { value, direction })
+}
+
+fn parse_time_interval(canonical: &str) -> Result<TimeIntervalValue> {
+    let bounds = canonical.split('/').map(str::trim).collect::<Vec<_>>();
+    if bounds.len() != 2 {
+        bail!("Invalid time interval: '{}'", canonical)
+    }
+    let parse_bound = |bound: &str| -> Result<Option<String>> {
+        if bound.is_empty() {
+            return Ok(None);
+        }
+        DatetimeComponents::parse(bound)?;
+        Ok(Some(bound.to_string()))
+    };
+    Ok(TimeIntervalValue {
+        from: parse_bound(bounds[0])?,
+        to: parse_bound(bounds[1])?,
+        to_inclusive: false,
+    })
+}
+
+fn parse_amount_of_money(canonical: &str) -> Result<AmountOfMoneyValue> {
+    // The sign of negative amounts comes before the currency symbol, e.g. "-$0.05"
+    let (sign, unsigned) = match canonical.strip_prefix('-') {
+        Some(unsigned) => (-1, unsigned),
+        None => (1, canonical),
+    };
+    let (value, unit) = match unsigned.find(|c: char| c.is_ascii_digit() || c == '-') {
+        Some(0) => match unsigned.find(' ') {
+            Some(index) => (&unsigned[..index], Some(unsigned[index + 1..].trim())),
+            None => (unsigned, None),
+        },
+        Some(index) => (&unsigned[index..], Some(unsigned[..index].trim())),
+        None => bail!("Invalid amount of money: '{}'", canonical),
+    };
+    let minor_units = sign * parse_minor_units(value, minor_unit_exponent(unit))?;
+    let unit = unit.map(|unit| unit.to_string());
+    Ok(AmountOfMoneyValue::from_minor_units(
+        minor_units,
+        Precision::Exact,
+        unit,
+    ))
+}
+
+/// Parses a decimal amount straight into an integer number of minor units, without going through
+/// a floating value, e.g. 123456789 for "1,234,567.89" with an exponent of 2
+///
+/// Digits beyond the minor unit are rounded half away from zero.
+fn parse_minor_units(digits: &str, exponent: u32) -> Result<i64> {
+    let invalid = || format_err!("Invalid amount of money: '{}'", digits);
+    let without_separators = digits.replace(',', "");
+    let (negative, unsigned) = match without_separators.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, without_separators.as_str()),
+    };
+    let (integer_part, fractional_part) = match unsigned.find('.') {
+        Some(index) => (&unsigned[..index], &unsigned[index + 1..]),
+        None => (unsigned, ""),
+    };
+    if integer_part.is_empty() && fractional_part.is_empty()
+        || !integer_part
+            .chars()
+            .chain(fractional_part.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let kept_digits = fractional_part.len().min(exponent as usize);
+    let round_up = fractional_part[kept_digits..].chars().next() >= Some('5');
+    let padding = "0".repeat(exponent as usize - kept_digits);
+    let mut minor_units = integer_part
+        .chars()
+        .chain(fractional_part[..kept_digits].chars())
+        .chain(padding.chars())
+        .try_fold(0_i64, |minor_units, digit| {
+            minor_units
+                .checked_mul(10)?
+                .checked_add(i64::from(digit as u8 - b'0'))
+        })
+        .ok_or_else(invalid)?;
+    if round_up {
+        minor_units = minor_units.checked_add(1).ok_or_else(invalid)?;
+    }
+    Ok(if negative { -minor_units } else { minor_units })
+}
+
+fn parse_temperature(canonical: &str) -> Result<TemperatureValue> {
+    let (value, mode) = match canonical.strip_prefix('Δ') {
+        Some(delta) => (delta.trim_start_matches('+'), TemperatureMode::Delta),
+        // Increases used to be written without the delta sign
+        None => match canonical.strip_prefix('+') {
+            Some(delta) => (delta, TemperatureMode::Delta),
+            None => (canonical, TemperatureMode::Absolute),
+        },
+    };
+    let split_index = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .unwrap_or(value.len());
+    let unit = value[split_index..].trim();
+    let value = parse_number(&value[..split_index])? as f32;
+    let unit = match unit {
+        "" => None,
+        "°C" | "celsius" => Some("celsius"),
+        "°F" | "fahrenheit" => Some("fahrenheit"),
+        "K" | "kelvin" => Some("kelvin"),
+        "°" | "degree" => Some("degree"),
+        _ => bail!("Invalid temperature: '{}'", canonical),
+    };
+    Ok(TemperatureValue {
+        value,
+        unit: unit.map(|unit| unit.to_string()),
+        mode,
+    })
+}
+
+fn parse_relative_change(canonical: &str) -> Result<RelativeChangeValue> {
+    let vague_change = |direction, magnitude| RelativeChangeValue {
+        direction,
+        value: None,
+        unit: None,
+        magnitude,
+    };
+    let direction = match canonical.chars().next() {
+        Some('+') => ChangeDirection::Increase,
+        Some('-') => ChangeDirection::Decrease,
+        _ => {
+            return match canonical {
+                "a bit more" => Ok(vague_change(
+                    ChangeDirection::Increase,
+                    Some(ChangeMagnitude::Slight),
+                )),
+                "more" => Ok(vague_change(ChangeDirection::Increase, None)),
+                "a lot more" => Ok(vague_change(
+                    ChangeDirection::Increase,
+                    Some(ChangeMagnitude::Large),
+                )),
+                "a bit less" => Ok(vague_change(
+                    ChangeDirection::Decrease,
+                    Some(ChangeMagnitude::Slight),
+                )),
+                "less" => Ok(vague_change(ChangeDirection::Decrease, None)),
+                "a lot less" => Ok(vague_change(
+                    ChangeDirection::Decrease,
+                    Some(ChangeMagnitude::Large),
+                )),
+                _ => bail!("Invalid relative change: '{}'", canonical),
+            };
+        }
+    };
+    let (value, unit) = match canonical[1..].find(' ') {
+        Some(index) => (
+            &canonical[1..index + 1],
+            Some(canonical[index + 2..].trim()),
+        ),
+        None => (&canonical[1..], None),
+    };
+    // The sign is the direction, and the size of the change is never negative
+    let invalid_value = || format_err!("Invalid relative change: '{}'", canonical);
+    if value.starts_with(['+', '-']) {
+        return Err(invalid_value());
+    }
+    let value = parse_number(value)?;
+    if !(value.is_finite() && value >= 0.) {
+        return Err(invalid_value());
+    }
+    Ok(RelativeChangeValue {
+        direction,
+        value: Some(value),
+        unit: unit.map(|unit| unit.to_string()),
+        magnitude: None,
+    })
+}
+
+/// Parses cooking quantities written in English, e.g. "2 tablespoons" or "250 g"
+fn parse_cooking_quantity(canonical: &str) -> Result<CookingQuantityValue> {
+    let mut parts = canonical.trim().splitn(2, char::is_whitespace);
+    let value = parse_number(parts.next().unwrap_or_default())?;
+    let unit = parts
+        .next()
+        .and_then(|unit| CookingUnit::from_word(unit, Language::EN))
+        .ok_or_else(|| format_err!("Invalid cooking quantity: '{}'", canonical))?;
+    Ok(CookingQuantityValue::new(value, unit))
+}
+
+/// Parses blood pressures written as "120/80", optionally followed by "mmHg"
+fn parse_blood_pressure(canonical: &str) -> Result<BloodPressureValue> {
+    let invalid_pressure = || format_err!("Invalid blood pressure: '{}'", canonical);
+    let pressure = canonical.trim().trim_end_matches("mmHg");
+    let mut pressures = pressure.split('/').map(|pressure| {
+        pressure
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| invalid_pressure())
+    });
+    match (pressures.next(), pressures.next(), pressures.next()) {
+        (Some(systolic), Some(diastolic), None) => Ok(BloodPressureValue {
+            systolic: systolic?,
+            diastolic: diastolic?,
+        }),
+        _ => Err(invalid_pressure()),
+    }
+}
+
+/// Parses dosages written as on prescriptions, e.g. "2 x 500 mg tablet", "10 ml" or
+/// "2 × tablet"
+fn parse_dosage(canonical: &str) -> Result<DosageValue> {
+    let invalid_dosage = || format_err!("Invalid dosage: '{}'", canonical);
+    let mut tokens = canonical.split_whitespace().collect::<Vec<_>>();
+    let count = if tokens.len() > 2 && (tokens[1] == "x" || tokens[1] == "×") {
+        let count = parse_number(tokens[0])?;
+        tokens.drain(..2);
+        count
+    } else {
+        1.
+    };
+    let (dose, unit) = match tokens.first().map(|token| parse_number(token)) {
+        Some(Ok(dose)) => {
+            let unit = tokens.get(1).ok_or_else(invalid_dosage)?.to_string();
+            tokens.drain(..2);
+            (Some(dose), Some(unit))
+        }
+        _ => (None, None),
+    };
+    let form = if tokens.is_empty() {
+        None
+    } else {
+        Some(tokens.join(" "))
+    };
+    if dose.is_none() && form.is_none() {
+        return Err(invalid_dosage());
+    }
+    Ok(DosageValue {
+        count,
+        dose,
+        unit,
+        form,
+    })
+}
+
+/// Parses scores written as "2-1" or "2:1"
+fn parse_score(canonical: &str) -> Result<ScoreValue> {
+    let invalid_score = || format_err!("Invalid score: '{}'", canonical);
+    let mut scores = canonical
+        .split(['-', ':'])
+        .map(|score| score.trim().parse::<u32>().map_err(|_| invalid_score()));
+    match (scores.next(), scores.next(), scores.next()) {
+        (Some(first), Some(second), None) => Ok(ScoreValue {
+            first: first?,
+            second: second?,
+        }),
+        _ => Err(invalid_score()),
+    }
+}
+
+/// Parses media positions written as on a player, e.g. "1:20:00", signed values being offsets
+/// from the current position, e.g. "-0:30"
+fn parse_media_position(canonical: &str) -> Result<MediaPositionValue> {
+    let invalid_position = || format_err!("Invalid media position: '{}'", canonical);
+    let canonical = canonical.trim();
+    let (mode, sign, clock) = if let Some(clock) = canonical.strip_prefix('-') {
+        (MediaPositionMode::Offset, -1, clock)
+    } else if let Some(clock) = canonical.strip_prefix('+') {
+        (MediaPositionMode::Offset, 1, clock)
+    } else {
+        (MediaPositionMode::Absolute, 1, canonical)
+    };
+    let components = clock
+        .split(':')
+        .map(|component| {
+            if component.is_empty() || !component.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid_position());
+            }
+            component.parse::<i64>().map_err(|_| invalid_position())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if components.len() > 3 || components.iter().skip(1).any(|component| *component >= 60) {
+        return Err(invalid_position());
+    }
+    let seconds = components
+        .iter()
+        .try_fold(0_i64, |seconds, component| {
+            seconds.checked_mul(60)?.checked_add(*component)
+        })
+        .ok_or_else(invalid_position)?;
+    Ok(MediaPositionValue {
+        mode,
+        seconds: sign * seconds,
+    })
+}
+
+fn parse_duration(canonical: &str) -> Result<DurationValue> {
+    let mut duration = DurationValue::from_seconds(0);
+    let tokens = canonical
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty() && *token != "and")
+        .collect::<Vec<_>>();
+    if tokens.is_empty() || tokens.len() % 2 != 0 {
+        bail!("Invalid duration: '{}'", canonical)
+    }
+    for component in tokens.chunks(2) {
+        let count = component[0]
+            .parse::<i64>()
+            .map_err(|_| format_err!("Invalid duration: '{}'", canonical))?;
+        let field = match component[1].trim_end_matches('s') {
+            "year" => &mut duration.years,
+            "quarter" => &mut duration.quarters,
+            "month" => &mut duration.months,
+            "week" => &mut duration.weeks,
+            "day" => &mut duration.days,
+            "hour" => &mut duration.hours,
+            "minute" => &mut duration.minutes,
+            "second" => &mut duration.seconds,
+            _ => bail!(
+                "Invalid duration unit '{}' in '{}'",
+                component[1],
+                canonical
+            ),
+        };
+        *field += count;
+    }
+    Ok(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_canonical() {
+        // When
+        let instant_time =
+            SlotValue::from_canonical(SlotValueKind::InstantTime, "2017-06-13 18:00:00 +02:00");
+        let interval =
+            SlotValue::from_canonical(SlotValueKind::TimeInterval, "/2017-06-08 00:00:00 +02:00");
+        let duration = SlotValue::from_canonical(SlotValueKind::Duration, "3 months and 1 day");
+        let temperature = SlotValue::from_canonical(SlotValueKind::Temperature, "-2.5 °C");
+        let temperature_delta = SlotValue::from_canonical(SlotValueKind::Temperature, "+2°");
+        let amount = SlotValue::from_canonical(SlotValueKind::AmountOfMoney, "$10.05");
+        let ordinal = SlotValue::from_canonical(SlotValueKind::Ordinal, "2nd to last");
+        let relative_change = SlotValue::from_canonical(SlotValueKind::RelativeChange, "-2 degree");
+        let media_position = SlotValue::from_canonical(SlotValueKind::MediaPosition, "1:20:00");
+        let media_offset = SlotValue::from_canonical(SlotValueKind::MediaPosition, "-0:30");
+        let score = SlotValue::from_canonical(SlotValueKind::Score, "3 - 2");
+        let cooking_quantity =
+            SlotValue::from_canonical(SlotValueKind::CookingQuantity, "2 tablespoons");
+        let blood_pressure = SlotValue::from_canonical(SlotValueKind::BloodPressure, "120/80 mmHg");
+        let dosage = SlotValue::from_canonical(SlotValueKind::Dosage, "2 x 500 mg tablet");
+
+        // Then
+        let expected_instant_time = SlotValue::InstantTime(InstantTimeValue {
+            value: "2017-06-13 18:00:00 +02:00".to_string(),
+            grain: Grain::Hour,
+            precision: Precision::Exact,
+        });
+        let expected_interval = SlotValue::TimeInterval(TimeIntervalValue {
+            from: None,
+            to: Some("2017-06-08 00:00:00 +02:00".to_string()),
+            to_inclusive: false,
+        });
+        let expected_duration = SlotValue::Duration(DurationValue {
+            months: 3,
+            ..DurationValue::from_days(1)
+        });
+        let expected_temperature = SlotValue::Temperature(TemperatureValue {
+            value: -2.5,
+            unit: Some("celsius".to_string()),
+            mode: TemperatureMode::Absolute,
+        });
+        let expected_amount = SlotValue::AmountOfMoney(AmountOfMoneyValue::from_minor_units(
+            1005,
+            Precision::Exact,
+            Some("$".to_string()),
+        ));
+        let expected_ordinal = SlotValue::Ordinal(OrdinalValue {
+            value: 2,
+            direction: OrdinalDirection::FromEnd,
+        });
+        assert_eq!(expected_instant_time, instant_time.unwrap());
+        assert_eq!(expected_interval, interval.unwrap());
+        assert_eq!(expected_duration, duration.unwrap());
+        assert_eq!(expected_temperature, temperature.unwrap());
+        assert_eq!(
+            TemperatureMode::Delta,
+            temperature_delta.unwrap().as_temperature().unwrap().mode
+        );
+        assert_eq!(expected_amount, amount.unwrap());
+        assert_eq!(expected_ordinal, ordinal.unwrap());
+        assert_eq!(
+            Some(-2.),
+            relative_change
+                .unwrap()
+                .as_relative_change()
+                .unwrap()
+                .delta()
+        );
+        assert_eq!(
+            SlotValue::MediaPosition(MediaPositionValue {
+                mode: MediaPositionMode::Absolute,
+                seconds: 4800,
+            }),
+            media_position.unwrap()
+        );
+        assert_eq!(
+            SlotValue::MediaPosition(MediaPositionValue {
+                mode: MediaPositionMode::Offset,
+                seconds: -30,
+            }),
+            media_offset.unwrap()
+        );
+        assert_eq!(
+            SlotValue::Score(ScoreValue {
+                first: 3,
+                second: 2,
+            }),
+            score.unwrap()
+        );
+        assert_eq!(
+            SlotValue::CookingQuantity(CookingQuantityValue::new(2., CookingUnit::Tablespoon)),
+            cooking_quantity.unwrap()
+        );
+        assert_eq!(
+            SlotValue::BloodPressure(BloodPressureValue {
+                systolic: 120,
+                diastolic: 80,
+            }),
+            blood_pressure.unwrap()
+        );
+        assert_eq!(
+            SlotValue::Dosage(DosageValue {
+                count: 2.,
+                dose: Some(500.),
+                unit: Some("mg".to_string()),
+                form: Some("tablet".to_string()),
+            }),
+            dosage.unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_invalid_canonical() {
+        assert!(SlotValue::from_canonical(SlotValueKind::Number, "three").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::Duration, "3 fortnights").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::InstantTime, "tomorrow").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::MediaPosition, "1:75").is_err());
+        assert!(SlotValue::from_canonical(
+            SlotValueKind::MediaPosition,
+            "-999999999999999999:00:00"
+        )
+        .is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::Score, "3-2-1").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::Dosage, "2 x 500").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::AmountOfMoney, "$1.2.3").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::RelativeChange, "+-2 degree").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::RelativeChange, "-+2").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::RelativeChange, "+NaN").is_err());
+        assert!(SlotValue::from_canonical(SlotValueKind::AmountOfMoney, "$.").is_err());
+    }
+
+    #[test]
+    fn test_amount_of_money_canonical_minor_units() {
+        // Given
+        let minor_units = |canonical: &str| {
+            SlotValue::from_canonical(SlotValueKind::AmountOfMoney, canonical)
+                .unwrap()
+                .as_amount_of_money()
+                .unwrap()
+                .minor_units
+        };
+
+        // Then
+        assert_eq!(123456789, minor_units("€1234567.89"));
+        assert_eq!(123456789, minor_units("$1,234,567.89"));
+        assert_eq!(-5, minor_units("-0.05 EUR"));
+        assert_eq!(-5, minor_units("-$0.05"));
+        assert_eq!(1001, minor_units("$10.005"));
+        assert_eq!(1234, minor_units("¥1234"));
+        assert_eq!(12345, minor_units("12.345 KWD"));
+    }
+
+    #[test]
+    fn test_temperature_canonical_round_trip() {
+        // Given
+        let temperature = |value: f32, mode: TemperatureMode| {
+            SlotValue::Temperature(TemperatureValue {
+                value,
+                unit: Some("celsius".to_string()),
+                mode,
+            })
+        };
+        let temperatures = vec![
+            temperature(-2., TemperatureMode::Absolute),
+            temperature(-2., TemperatureMode::Delta),
+            temperature(2., TemperatureMode::Delta),
+            temperature(20.5, TemperatureMode::Absolute),
+        ];
+
+        for value in temperatures {
+            // When
+            let canonical = value.to_string();
+            let parsed = SlotValue::from_canonical(SlotValueKind::Temperature, &canonical);
+
+            // Then
+            assert_eq!(value, parsed.unwrap(), "{}", canonical);
+        }
+    }
+}
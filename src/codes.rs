@@ -0,0 +1,532 @@
+//! Normalization of spoken and written codes and identifiers, such as license plates, booking
+//! references or hashtags
+use crate::errors::*;
+use crate::ontology::{FlightNumberValue, ProductCodeFormat, ProductCodeValue, VersionValue};
+use failure::{bail, format_err};
+use std::net::IpAddr;
+
+/// Normalizes an alphanumeric code to its uppercase letters and digits, e.g. "AB-123-CD" and
+/// "alpha bravo one two three charlie delta" are both normalized to "AB123CD"
+///
+/// Spoken letters can use the NATO phonetic alphabet, and spoken digits are expected in English.
+/// `None` is returned when a word is neither a letter, a digit nor a group of digits and
+/// uppercase letters.
+pub fn normalize_alphanumeric_code(code: &str) -> Option<String> {
+    let mut normalized = String::with_capacity(code.len());
+    for word in code.split(|c: char| c.is_whitespace() || c == '-' || c == '.' || c == '/') {
+        if word.is_empty() {
+            continue;
+        }
+        let lowercase_word = word.to_lowercase();
+        if let Some(character) = spoken_character(&lowercase_word) {
+            normalized.push(character);
+        } else if is_written_group(word) {
+            normalized.push_str(&word.to_ascii_uppercase());
+        } else {
+            return None;
+        }
+    }
+    if normalized.is_empty() {
+        None
+    } else {
+        Some(normalized)
+    }
+}
+
+/// Normalizes an IPv4 or IPv6 address, either written or spoken in English, e.g.
+/// "one nine two dot one six eight dot one dot ten" is normalized to "192.168.1.10" and
+/// "2001:DB8:0:0::1" to "2001:db8::1"
+pub fn normalize_ip_address(address: &str) -> Option<String> {
+    let address = join_spoken_words(address, &[("dot", '.'), ("point", '.'), ("colon", ':')])?;
+    address
+        .parse::<IpAddr>()
+        .ok()
+        .map(|address| address.to_string())
+}
+
+/// Normalizes a host name, either written or spoken in English, e.g. "Example dot com" is
+/// normalized to "example.com"
+///
+/// Host names must contain at least two labels, so that plain words are not mistaken for host
+/// names.
+pub fn normalize_hostname(hostname: &str) -> Option<String> {
+    let hostname = join_spoken_words(hostname, &[("dot", '.'), ("dash", '-'), ("hyphen", '-')])?
+        .to_lowercase();
+    let hostname = hostname.strip_suffix('.').unwrap_or(&hostname);
+    let labels = hostname.split('.').collect::<Vec<_>>();
+    let is_valid_label = |label: &&str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if hostname.len() <= 253 && labels.len() >= 2 && labels.iter().all(is_valid_label) {
+        Some(hostname.to_string())
+    } else {
+        None
+    }
+}
+
+/// Normalizes a hashtag, either typed or spoken in English, e.g. "#GoodVibes" is left unchanged
+/// and "hashtag good vibes" is normalized to "#goodvibes"
+pub fn normalize_hashtag(hashtag: &str) -> Option<String> {
+    normalize_tag(hashtag, '#', &["hashtag", "hash tag", "hash"])
+}
+
+/// Normalizes a mention of a user, either typed or spoken in English, e.g. "@Snips" is left
+/// unchanged and "at snips" is normalized to "@snips"
+pub fn normalize_mention(mention: &str) -> Option<String> {
+    normalize_tag(mention, '@', &["at sign", "at"])
+}
+
+fn normalize_tag(tag: &str, sigil: char, spoken_sigils: &[&str]) -> Option<String> {
+    let tag = tag.trim();
+    let body = if let Some(body) = tag.strip_prefix(sigil) {
+        body.to_string()
+    } else {
+        let lowercase_tag = tag.to_lowercase();
+        let spoken_body = spoken_sigils.iter().find_map(|spoken_sigil| {
+            lowercase_tag
+                .strip_prefix(spoken_sigil)
+                .filter(|body| body.starts_with(char::is_whitespace))
+        })?;
+        spoken_body.split_whitespace().collect::<String>()
+    };
+    if !body.is_empty() && body.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        Some(format!("{}{}", sigil, body))
+    } else {
+        None
+    }
+}
+
+/// Concatenates the words of a spoken address, replacing the spoken separators and numbers by
+/// their written form, e.g. "one nine two dot one sixty eight" becomes "192.168" and "twelve
+/// point one" becomes "12.1"
+///
+/// Spoken numbers are read up to the hundreds, and successive numbers are concatenated as digit
+/// groups. Written addresses are returned unchanged.
+fn join_spoken_words(address: &str, separators: &[(&str, char)]) -> Option<String> {
+    let words = address.split_whitespace().collect::<Vec<_>>();
+    if words.is_empty() {
+        return None;
+    }
+    let lowercase_words = words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>();
+    let mut joined = String::with_capacity(address.len());
+    let mut index = 0;
+    while index < words.len() {
+        let lowercase_word = &lowercase_words[index];
+        if let Some((_, separator)) = separators.iter().find(|(w, _)| w == lowercase_word) {
+            joined.push(*separator);
+            index += 1;
+        } else if let Some((number, nb_words)) = spoken_number(&lowercase_words[index..]) {
+            joined.push_str(&number.to_string());
+            index += nb_words;
+        } else {
+            joined.push_str(words[index]);
+            index += 1;
+        }
+    }
+    Some(joined)
+}
+
+/// Reads a spoken number below one thousand at the start of the words, e.g. "one hundred and
+/// twelve", along with the number of words it spans
+fn spoken_number(words: &[String]) -> Option<(u32, usize)> {
+    let (mut number, mut nb_words) = spoken_number_below_hundred(words)?;
+    if (1..10).contains(&number) && words.get(nb_words).map(String::as_str) == Some("hundred") {
+        number *= 100;
+        nb_words += 1;
+        let remainder_start = match words.get(nb_words).map(String::as_str) {
+            Some("and") => nb_words + 1,
+            _ => nb_words,
+        };
+        if let Some((remainder, remainder_nb_words)) =
+            spoken_number_below_hundred(&words[remainder_start.min(words.len())..])
+        {
+            number += remainder;
+            nb_words = remainder_start + remainder_nb_words;
+        }
+    }
+    Some((number, nb_words))
+}
+
+fn spoken_number_below_hundred(words: &[String]) -> Option<(u32, usize)> {
+    let first_word = words.first()?.as_str();
+    let tens = match first_word {
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        _ => {
+            let number = match first_word {
+                "ten" => 10,
+                "eleven" => 11,
+                "twelve" => 12,
+                "thirteen" => 13,
+                "fourteen" => 14,
+                "fifteen" => 15,
+                "sixteen" => 16,
+                "seventeen" => 17,
+                "eighteen" => 18,
+                "nineteen" => 19,
+                _ => spoken_digit(first_word)?,
+            };
+            return Some((number, 1));
+        }
+    };
+    match words.get(1).and_then(|word| spoken_digit(word)) {
+        Some(unit) if unit != 0 => Some((tens + unit, 2)),
+        _ => Some((tens, 1)),
+    }
+}
+
+fn spoken_digit(word: &str) -> Option<u32> {
+    spoken_character(word).and_then(|c| c.to_digit(10))
+}
+
+impl FlightNumberValue {
+    /// Parses a flight number from its written or spoken form, e.g. "AF 1234", "BAW249" or
+    /// "alpha foxtrot one two"
+    ///
+    /// The airline code is made of three letters for ICAO codes, and of two characters otherwise.
+    pub fn from_code(code: &str) -> Result<Self> {
+        let invalid_flight_number = || format_err!("Invalid flight number: '{}'", code);
+        let normalized = normalize_alphanumeric_code(code).ok_or_else(invalid_flight_number)?;
+        let carrier_length = if normalized.chars().take(3).all(|c| c.is_ascii_alphabetic()) {
+            3
+        } else {
+            2
+        };
+        if normalized.len() <= carrier_length || normalized.len() > carrier_length + 4 {
+            return Err(invalid_flight_number());
+        }
+        let (carrier, number) = normalized.split_at(carrier_length);
+        if !carrier.chars().any(|c| c.is_ascii_alphabetic()) {
+            return Err(invalid_flight_number());
+        }
+        Ok(Self {
+            carrier: carrier.to_string(),
+            number: number.parse().map_err(|_| invalid_flight_number())?,
+        })
+    }
+}
+
+impl ProductCodeValue {
+    /// Parses an ISBN-10, ISBN-13 or EAN-13 code, e.g. "978-2-07-036822-8", and validates its
+    /// check digit
+    ///
+    /// EAN-13 codes starting with 978 or 979 are ISBN-13 codes.
+    pub fn from_code(code: &str) -> Result<Self> {
+        let value = code
+            .chars()
+            .filter(|c| !(c.is_whitespace() || *c == '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect::<String>();
+        let digits = value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                'X' if value.len() == 10 && i == 9 => Some(10),
+                _ => c.to_digit(10),
+            })
+            .collect::<Option<Vec<u32>>>()
+            .ok_or_else(|| format_err!("Invalid product code: '{}'", code))?;
+        let (format, is_valid) = match digits.len() {
+            10 => (
+                ProductCodeFormat::Isbn10,
+                digits
+                    .iter()
+                    .enumerate()
+                    .map(|(i, digit)| (10 - i as u32) * digit)
+                    .sum::<u32>()
+                    % 11
+                    == 0,
+            ),
+            13 => (
+                if value.starts_with("978") || value.starts_with("979") {
+                    ProductCodeFormat::Isbn13
+                } else {
+                    ProductCodeFormat::Ean13
+                },
+                digits
+                    .iter()
+                    .enumerate()
+                    .map(|(i, digit)| if i % 2 == 0 { *digit } else { 3 * digit })
+                    .sum::<u32>()
+                    % 10
+                    == 0,
+            ),
+            _ => bail!("Invalid product code length: '{}'", code),
+        };
+        if !is_valid {
+            bail!("Invalid product code check digit: '{}'", code);
+        }
+        Ok(Self { format, value })
+    }
+}
+
+impl VersionValue {
+    /// Parses a version number from its written or spoken form, e.g. "v2.3.1", "version 2.3" or
+    /// "version two point three point one"
+    pub fn from_version(version: &str) -> Result<Self> {
+        let invalid_version = || format_err!("Invalid version: '{}'", version);
+        let lowercase_version = version.trim().to_lowercase();
+        let number = lowercase_version
+            .strip_prefix("version")
+            .or_else(|| lowercase_version.strip_prefix('v'))
+            .unwrap_or(&lowercase_version);
+        let number = join_spoken_words(number, &[("point", '.'), ("dot", '.')])
+            .ok_or_else(invalid_version)?;
+        let components = number
+            .split('.')
+            .map(|component| {
+                if component.is_empty() || !component.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid_version());
+                }
+                component.parse::<u32>().map_err(|_| invalid_version())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if components.len() > 3 {
+            return Err(invalid_version());
+        }
+        Ok(Self {
+            major: components[0],
+            minor: components.get(1).cloned().unwrap_or(0),
+            patch: components.get(2).cloned().unwrap_or(0),
+        })
+    }
+}
+
+/// Written groups are either single characters, or groups of digits and uppercase letters, so
+/// that plain words such as "code" are not mistaken for codes
+fn is_written_group(word: &str) -> bool {
+    word.chars().all(|c| c.is_ascii_alphanumeric())
+        && (word.len() == 1 || !word.chars().any(|c| c.is_ascii_lowercase()))
+}
+
+fn spoken_character(word: &str) -> Option<char> {
+    Some(match word {
+        "zero" | "oh" => '0',
+        "one" => '1',
+        "two" => '2',
+        "three" => '3',
+        "four" => '4',
+        "five" => '5',
+        "six" => '6',
+        "seven" => '7',
+        "eight" => '8',
+        "nine" | "niner" => '9',
+        "alpha" | "alfa" => 'A',
+        "bravo" => 'B',
+        "charlie" => 'C',
+        "delta" => 'D',
+        "echo" => 'E',
+        "foxtrot" => 'F',
+        "golf" => 'G',
+        "hotel" => 'H',
+        "india" => 'I',
+        "juliet" | "juliett" => 'J',
+        "kilo" => 'K',
+        "lima" => 'L',
+        "mike" => 'M',
+        "november" => 'N',
+        "oscar" => 'O',
+        "papa" => 'P',
+        "quebec" => 'Q',
+        "romeo" => 'R',
+        "sierra" => 'S',
+        "tango" => 'T',
+        "uniform" => 'U',
+        "victor" => 'V',
+        "whiskey" | "whisky" => 'W',
+        "xray" => 'X',
+        "yankee" => 'Y',
+        "zulu" => 'Z',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_alphanumeric_code() {
+        assert_eq!(
+            Some("AB123CD".to_string()),
+            normalize_alphanumeric_code("AB-123-CD")
+        );
+        assert_eq!(
+            Some("AB123CD".to_string()),
+            normalize_alphanumeric_code("alpha bravo one two three Charlie delta")
+        );
+        assert_eq!(
+            Some("X9".to_string()),
+            normalize_alphanumeric_code("x niner")
+        );
+        assert_eq!(None, normalize_alphanumeric_code("my code"));
+        assert_eq!(None, normalize_alphanumeric_code(" - "));
+    }
+
+    #[test]
+    fn test_normalize_ip_address() {
+        assert_eq!(
+            Some("192.168.1.10".to_string()),
+            normalize_ip_address("one nine two dot one six eight dot one dot ten")
+        );
+        assert_eq!(
+            Some("2001:db8::1".to_string()),
+            normalize_ip_address("2001:DB8:0:0::1")
+        );
+        assert_eq!(
+            Some("192.168.1.112".to_string()),
+            normalize_ip_address(
+                "one ninety two dot one sixty eight dot one dot one hundred and twelve"
+            )
+        );
+        assert_eq!(None, normalize_ip_address("192.168.1"));
+        assert_eq!(None, normalize_ip_address("one two three"));
+    }
+
+    #[test]
+    fn test_normalize_hostname() {
+        assert_eq!(
+            Some("example.com".to_string()),
+            normalize_hostname("Example dot com")
+        );
+        assert_eq!(
+            Some("my-server.local".to_string()),
+            normalize_hostname("my-server.local.")
+        );
+        assert_eq!(None, normalize_hostname("localhost"));
+        assert_eq!(None, normalize_hostname("-bad.example.com"));
+    }
+
+    #[test]
+    fn test_normalize_hashtag() {
+        assert_eq!(
+            Some("#GoodVibes".to_string()),
+            normalize_hashtag("#GoodVibes")
+        );
+        assert_eq!(
+            Some("#goodvibes".to_string()),
+            normalize_hashtag("Hashtag good vibes")
+        );
+        assert_eq!(None, normalize_hashtag("#good vibes"));
+        assert_eq!(None, normalize_hashtag("hashtags"));
+        assert_eq!(None, normalize_hashtag("good vibes"));
+    }
+
+    #[test]
+    fn test_normalize_mention() {
+        assert_eq!(
+            Some("@snips_ai".to_string()),
+            normalize_mention("@snips_ai")
+        );
+        assert_eq!(
+            Some("@snips".to_string()),
+            normalize_mention("at sign snips")
+        );
+        assert_eq!(
+            Some("@johndoe".to_string()),
+            normalize_mention("at John Doe")
+        );
+        assert_eq!(None, normalize_mention("@"));
+        assert_eq!(None, normalize_mention("attic"));
+    }
+
+    #[test]
+    fn test_flight_number_from_code() {
+        // Given
+        let flight_number = |carrier: &str, number| FlightNumberValue {
+            carrier: carrier.to_string(),
+            number,
+        };
+
+        // When/Then
+        assert_eq!(
+            flight_number("AF", 1234),
+            FlightNumberValue::from_code("AF 1234").unwrap()
+        );
+        assert_eq!(
+            flight_number("BAW", 249),
+            FlightNumberValue::from_code("BAW249").unwrap()
+        );
+        assert_eq!(
+            flight_number("U2", 12),
+            FlightNumberValue::from_code("uniform two one two").unwrap()
+        );
+        assert!(FlightNumberValue::from_code("AF").is_err());
+        assert!(FlightNumberValue::from_code("AF12345").is_err());
+        assert!(FlightNumberValue::from_code("1234").is_err());
+    }
+
+    #[test]
+    fn test_version_from_version() {
+        // Given
+        let version = |major, minor, patch| VersionValue {
+            major,
+            minor,
+            patch,
+        };
+
+        // When/Then
+        assert_eq!(
+            version(2, 3, 1),
+            VersionValue::from_version("v2.3.1").unwrap()
+        );
+        assert_eq!(
+            version(2, 3, 1),
+            VersionValue::from_version("version two point three point one").unwrap()
+        );
+        assert_eq!(
+            version(10, 0, 0),
+            VersionValue::from_version("Version 10").unwrap()
+        );
+        assert_eq!(
+            version(2, 0, 0),
+            VersionValue::from_version("version two").unwrap()
+        );
+        assert_eq!(
+            version(12, 1, 0),
+            VersionValue::from_version("version twelve point one").unwrap()
+        );
+        assert_eq!(
+            version(1, 21, 0),
+            VersionValue::from_version("version one point twenty one").unwrap()
+        );
+        assert!(VersionValue::from_version("v2..1").is_err());
+        assert!(VersionValue::from_version("1.2.3.4").is_err());
+        assert!(VersionValue::from_version("version").is_err());
+    }
+
+    #[test]
+    fn test_product_code_from_code() {
+        // When
+        let isbn_10 = ProductCodeValue::from_code("2-07-036822-X");
+        let isbn_13 = ProductCodeValue::from_code("978-2-07-036822-8");
+        let ean_13 = ProductCodeValue::from_code("4006381333931");
+
+        // Then
+        assert_eq!(
+            ProductCodeValue {
+                format: ProductCodeFormat::Isbn10,
+                value: "207036822X".to_string(),
+            },
+            isbn_10.unwrap()
+        );
+        assert_eq!(ProductCodeFormat::Isbn13, isbn_13.unwrap().format);
+        assert_eq!(ProductCodeFormat::Ean13, ean_13.unwrap().format);
+        assert!(ProductCodeValue::from_code("4006381333932").is_err());
+        assert!(ProductCodeValue::from_code("40063813").is_err());
+        assert!(ProductCodeValue::from_code("400638133393X").is_err());
+    }
+}
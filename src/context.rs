@@ -0,0 +1,614 @@
+//! Interpretation of builtin entities from the words surrounding them
+//!
+//! Entity ranges are expressed in characters, not bytes.
+use crate::annotation::char_range_to_byte_range;
+use crate::errors::*;
+use crate::language::Language;
+use crate::ontology::{
+    ChangeDirection, DurationValue, MediaPositionMode, MediaPositionValue, TemperatureMode,
+    TemperatureValue,
+};
+use failure::format_err;
+use std::ops::Range;
+
+/// Infers whether the temperature found at the given range of the text is a temperature, as in
+/// "set it to 20 degrees", or a change of temperature, as in "raise it by 2 degrees" or
+/// "2 degrees cooler", in which case the direction of the change is returned
+///
+/// Prepositions such as "by" only mark a change when they follow a verb such as "raise" or
+/// "lower", which gives the direction of the change, so that "pon la calefacción en 20 grados"
+/// remains a temperature. Likewise, Japanese and Korean set points are followed by a particle
+/// such as "に" in "温度を20度に上げて", which takes precedence over the verb.
+pub fn temperature_change(
+    text: &str,
+    range: &Range<usize>,
+    language: Language,
+) -> Result<Option<ChangeDirection>> {
+    let byte_range = char_range_to_byte_range(text, range)?;
+    let before = text[..byte_range.start].trim_end().to_lowercase();
+    let after = text[byte_range.end..].trim_start().to_lowercase();
+    let cues = temperature_change_cues(language);
+    let following_direction = match language {
+        // Japanese and Korean cues are matched within words, and may come after an adverb
+        Language::JA | Language::KO => {
+            let has_cue = |cues: &[&str]| cues.iter().any(|cue| after.contains(cue));
+            let is_set_point = cues
+                .set_point_particles
+                .iter()
+                .any(|particle| after.starts_with(particle));
+            if is_set_point {
+                None
+            } else if has_cue(cues.following_decrease) {
+                Some(ChangeDirection::Decrease)
+            } else if has_cue(cues.following_increase) {
+                Some(ChangeDirection::Increase)
+            } else {
+                None
+            }
+        }
+        _ => {
+            let has_cue = |cues: &[&str]| cues.iter().any(|cue| starts_with_words(&after, cue));
+            if has_cue(cues.following_decrease) {
+                Some(ChangeDirection::Decrease)
+            } else if has_cue(cues.following_increase) {
+                Some(ChangeDirection::Increase)
+            } else {
+                None
+            }
+        }
+    };
+    if following_direction.is_some() {
+        return Ok(following_direction);
+    }
+    let follows_preposition = cues
+        .prepositions
+        .iter()
+        .any(|preposition| ends_with_words(&before, preposition));
+    if !follows_preposition {
+        return Ok(None);
+    }
+    let has_verb = |verbs: &[&str]| verbs.iter().any(|verb| contains_words(&before, verb));
+    Ok(if has_verb(cues.decrease_verbs) {
+        Some(ChangeDirection::Decrease)
+    } else if has_verb(cues.increase_verbs) {
+        Some(ChangeDirection::Increase)
+    } else {
+        None
+    })
+}
+
+/// Interprets the temperature found at the given range of the text, the value of a change of
+/// temperature being negated when it is a decrease, e.g. "2 degrees cooler" gives a change of -2
+/// degrees
+pub fn temperature(
+    text: &str,
+    range: &Range<usize>,
+    temperature: &TemperatureValue,
+    language: Language,
+) -> Result<TemperatureValue> {
+    let value = temperature.value.abs();
+    let (value, mode) = match temperature_change(text, range, language)? {
+        Some(ChangeDirection::Increase) => (value, TemperatureMode::Delta),
+        Some(ChangeDirection::Decrease) => (-value, TemperatureMode::Delta),
+        None => (temperature.value, TemperatureMode::Absolute),
+    };
+    Ok(TemperatureValue {
+        value,
+        unit: temperature.unit.clone(),
+        mode,
+    })
+}
+
+struct TemperatureChangeCues {
+    /// Prepositions introducing the size of a change, e.g. "by"
+    prepositions: &'static [&'static str],
+    increase_verbs: &'static [&'static str],
+    decrease_verbs: &'static [&'static str],
+    /// Words following the size of a change, e.g. "warmer"
+    following_increase: &'static [&'static str],
+    following_decrease: &'static [&'static str],
+    /// Particles directly following a set point, e.g. "に" in "20度に上げて", which is not a change
+    /// despite its verb, as "to" in "raise it to 20 degrees"
+    set_point_particles: &'static [&'static str],
+}
+
+fn temperature_change_cues(language: Language) -> TemperatureChangeCues {
+    match language {
+        Language::DE => TemperatureChangeCues {
+            prepositions: &["um"],
+            increase_verbs: &["erhöhe", "erhöhen", "steigere", "steigern", "hebe", "heben"],
+            decrease_verbs: &[
+                "senke",
+                "senken",
+                "verringere",
+                "verringern",
+                "reduziere",
+                "reduzieren",
+            ],
+            following_increase: &["mehr", "wärmer", "höher"],
+            following_decrease: &["weniger", "kälter", "kühler", "niedriger"],
+            set_point_particles: &[],
+        },
+        Language::EN => TemperatureChangeCues {
+            prepositions: &["by"],
+            increase_verbs: &["raise", "increase", "up", "warm"],
+            decrease_verbs: &["lower", "decrease", "reduce", "down", "drop", "cool"],
+            following_increase: &["more", "warmer", "higher"],
+            following_decrease: &["less", "cooler", "colder", "lower"],
+            set_point_particles: &[],
+        },
+        Language::ES => TemperatureChangeCues {
+            prepositions: &["en"],
+            increase_verbs: &["sube", "subir", "aumenta", "aumentar", "incrementa"],
+            decrease_verbs: &[
+                "baja",
+                "bajar",
+                "disminuye",
+                "disminuir",
+                "reduce",
+                "reducir",
+            ],
+            following_increase: &["más"],
+            following_decrease: &["menos"],
+            set_point_particles: &[],
+        },
+        Language::FR => TemperatureChangeCues {
+            prepositions: &["de"],
+            increase_verbs: &["augmente", "augmenter", "monte", "monter", "élève"],
+            decrease_verbs: &[
+                "baisse", "baisser", "diminue", "diminuer", "réduis", "réduire",
+            ],
+            following_increase: &["de plus"],
+            following_decrease: &["de moins"],
+            set_point_particles: &[],
+        },
+        Language::IT => TemperatureChangeCues {
+            prepositions: &["di"],
+            increase_verbs: &["alza", "alzare", "aumenta", "aumentare"],
+            decrease_verbs: &["abbassa", "abbassare", "diminuisci", "diminuire", "riduci"],
+            following_increase: &["in più"],
+            following_decrease: &["in meno"],
+            set_point_particles: &[],
+        },
+        Language::PT_PT | Language::PT_BR => TemperatureChangeCues {
+            prepositions: &["em"],
+            increase_verbs: &["aumenta", "aumentar", "aumente", "sobe", "subir", "suba"],
+            decrease_verbs: &[
+                "diminui", "diminuir", "diminua", "baixa", "baixar", "baixe", "reduz", "reduzir",
+            ],
+            following_increase: &["a mais"],
+            following_decrease: &["a menos"],
+            set_point_particles: &[],
+        },
+        Language::JA => TemperatureChangeCues {
+            prepositions: &[],
+            increase_verbs: &[],
+            decrease_verbs: &[],
+            following_increase: &["上げ", "高く"],
+            following_decrease: &["下げ", "低く"],
+            set_point_particles: &["に", "まで"],
+        },
+        Language::KO => TemperatureChangeCues {
+            prepositions: &[],
+            increase_verbs: &[],
+            decrease_verbs: &[],
+            following_increase: &["올려", "높여", "더"],
+            following_decrease: &["내려", "낮춰"],
+            set_point_particles: &["로", "으로", "까지"],
+        },
+    }
+}
+
+/// Infers whether the duration found at the given range of the text is a position within a
+/// media, as in "skip to one hour twenty", or an offset from the current position, as in
+/// "go back 30 seconds" or "fast forward 2 minutes"
+///
+/// Years, quarters and months are ignored, as media are not that long, and durations whose
+/// number of seconds overflows are rejected.
+pub fn media_position(
+    text: &str,
+    range: &Range<usize>,
+    duration: &DurationValue,
+    language: Language,
+) -> Result<MediaPositionValue> {
+    let byte_range = char_range_to_byte_range(text, range)?;
+    let before = text[..byte_range.start].trim_end().to_lowercase();
+    let after = text[byte_range.end..].trim_start().to_lowercase();
+    let (position_cues, backward_cues, forward_cues): (&[&str], &[&str], &[&str]) = match language {
+        Language::DE => (
+            &["zu", "auf", "bei"],
+            &["zurück", "zurückspulen"],
+            &["vor", "vorwärts", "vorspulen", "überspringe"],
+        ),
+        Language::EN => (
+            &["to", "at"],
+            &["back", "backward", "backwards", "rewind"],
+            &["forward", "forwards", "ahead", "skip"],
+        ),
+        Language::ES => (
+            &["a", "al"],
+            &["atrás", "retrocede", "retroceder"],
+            &["adelante", "adelanta", "adelantar", "avanza", "avanzar"],
+        ),
+        Language::FR => (
+            &["à"],
+            &["en arrière", "recule", "reculer", "reviens", "revenir"],
+            &["en avant", "avance", "avancer", "saute", "sauter"],
+        ),
+        Language::IT => (
+            &["a", "al"],
+            &["indietro", "torna", "tornare"],
+            &["avanti", "avanza", "salta", "saltare"],
+        ),
+        Language::PT_PT | Language::PT_BR => (
+            &["para", "a", "ao"],
+            &["atrás", "volta", "voltar", "retrocede"],
+            &["avança", "avançar", "adianta", "pula", "salta"],
+        ),
+        Language::JA => (&[], &["戻", "巻き戻"], &["進", "早送り", "飛ばし"]),
+        Language::KO => (&[], &["뒤로", "되감"], &["앞으로", "빨리 감"]),
+    };
+    let has_cue = |cues: &[&str]| match language {
+        // Japanese and Korean cues are matched within words
+        Language::JA | Language::KO => cues
+            .iter()
+            .any(|cue| before.contains(cue) || after.contains(cue)),
+        _ => cues
+            .iter()
+            .any(|cue| contains_words(&before, cue) || contains_words(&after, cue)),
+    };
+    let too_long = || format_err!("Duration too long to be a media position: {:?}", duration);
+    let seconds = [
+        (duration.weeks, 604_800),
+        (duration.days, 86_400),
+        (duration.hours, 3_600),
+        (duration.minutes, 60),
+        (duration.seconds, 1),
+    ]
+    .iter()
+    .try_fold(0_i64, |seconds, (count, unit_seconds)| {
+        seconds.checked_add(count.checked_mul(*unit_seconds)?)
+    })
+    .ok_or_else(too_long)?;
+    let is_position = position_cues
+        .iter()
+        .any(|cue| ends_with_words(&before, cue));
+    Ok(if is_position {
+        MediaPositionValue {
+            mode: MediaPositionMode::Absolute,
+            seconds,
+        }
+    } else if has_cue(backward_cues) {
+        MediaPositionValue {
+            mode: MediaPositionMode::Offset,
+            seconds: seconds.checked_neg().ok_or_else(too_long)?,
+        }
+    } else if has_cue(forward_cues) {
+        MediaPositionValue {
+            mode: MediaPositionMode::Offset,
+            seconds,
+        }
+    } else {
+        MediaPositionValue {
+            mode: MediaPositionMode::Absolute,
+            seconds,
+        }
+    })
+}
+
+/// Infers whether the entity found at the given range of the text is explicitly negated, as in
+/// "not at 5 pm" or "sans sucre"
+///
+/// Negation cues are looked for within the five words preceding the entity in the same clause,
+/// or right after the entity in Japanese and Korean, where negation follows the negated words.
+pub fn is_negated(text: &str, range: &Range<usize>, language: Language) -> Result<bool> {
+    let byte_range = char_range_to_byte_range(text, range)?;
+    let before = text[..byte_range.start].to_lowercase();
+    let after = text[byte_range.end..].trim_start().to_lowercase();
+    let clause = before
+        .rsplit([',', ';', '.', '!', '?'])
+        .next()
+        .unwrap_or_default();
+    let window = clause.split_whitespace().rev().take(5).collect::<Vec<_>>();
+    let has_preceding_cue = |cues: &[&str]| window.iter().any(|word| cues.contains(word));
+    Ok(match language {
+        Language::DE => has_preceding_cue(&[
+            "nicht", "kein", "keine", "keinen", "keinem", "ohne", "nie", "außer",
+        ]),
+        Language::EN => {
+            has_preceding_cue(&["not", "no", "never", "without", "except"])
+                || window
+                    .iter()
+                    .any(|word| word.ends_with("n't") || word.ends_with("n’t"))
+        }
+        Language::ES => has_preceding_cue(&["no", "sin", "nunca", "excepto", "salvo"]),
+        Language::FR => {
+            has_preceding_cue(&["ne", "pas", "sans", "jamais", "sauf", "aucun", "aucune"])
+                || window
+                    .iter()
+                    .any(|word| word.starts_with("n'") || word.starts_with("n’"))
+        }
+        Language::IT => has_preceding_cue(&["non", "senza", "mai", "tranne"]),
+        Language::PT_PT | Language::PT_BR => has_preceding_cue(&["não", "sem", "nunca", "exceto"]),
+        Language::JA => ["じゃなく", "ではなく", "なし", "抜き", "以外"]
+            .iter()
+            .any(|cue| after.starts_with(cue)),
+        Language::KO => ["없이", "빼고", "말고", "아니라", "제외"]
+            .iter()
+            .any(|cue| after.starts_with(cue)),
+    })
+}
+
+/// Infers whether the entity found at the given range of the text is the object of a question,
+/// as in "how many liters?", rather than a provided value, as in "three liters"
+///
+/// Question words are looked for within the entity and the two words preceding it in the same
+/// clause, or within the entity and right around it in Japanese and Korean.
+pub fn is_interrogative(text: &str, range: &Range<usize>, language: Language) -> Result<bool> {
+    let byte_range = char_range_to_byte_range(text, range)?;
+    let before = text[..byte_range.start].to_lowercase();
+    let entity = text[byte_range.clone()].to_lowercase();
+    let after = text[byte_range.end..].trim_start().to_lowercase();
+    let question_words: &[&str] = match language {
+        Language::DE => &[
+            "wie", "wieviel", "wieviele", "welche", "welcher", "welches", "welchen", "wann", "wo",
+            "wer", "warum", "was",
+        ],
+        Language::EN => &[
+            "how", "what", "which", "when", "where", "who", "whom", "whose", "why",
+        ],
+        Language::ES => &[
+            "cuánto", "cuánta", "cuántos", "cuántas", "cuál", "cuáles", "cuándo", "dónde", "qué",
+            "quién",
+        ],
+        Language::FR => &[
+            "combien", "quel", "quelle", "quels", "quelles", "quand", "où", "qui", "pourquoi",
+            "comment", "quoi",
+        ],
+        Language::IT => &[
+            "quanto", "quanta", "quanti", "quante", "quale", "quali", "quando", "dove", "chi",
+            "perché", "cosa",
+        ],
+        Language::PT_PT | Language::PT_BR => &[
+            "quanto", "quanta", "quantos", "quantas", "qual", "quais", "quando", "onde", "quem",
+            "porque",
+        ],
+        Language::JA => &[
+            "何",
+            "いくつ",
+            "いくら",
+            "どのくらい",
+            "どれくらい",
+            "いつ",
+            "どこ",
+        ],
+        Language::KO => &["몇", "얼마", "언제", "어디", "무엇", "뭐"],
+    };
+    Ok(match language {
+        // Japanese and Korean words are not always separated by spaces
+        Language::JA | Language::KO => question_words.iter().any(|word| {
+            entity.contains(word) || after.starts_with(word) || before.trim_end().ends_with(word)
+        }),
+        _ => {
+            let clause = before
+                .rsplit([',', ';', '.', '!', '?'])
+                .next()
+                .unwrap_or_default();
+            let is_question_word = |word: &str| {
+                let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+                question_words.contains(&word)
+            };
+            clause
+                .split_whitespace()
+                .rev()
+                .take(2)
+                .any(is_question_word)
+                || entity.split_whitespace().any(is_question_word)
+        }
+    })
+}
+
+fn contains_words(text: &str, words: &str) -> bool {
+    text.match_indices(words).any(|(index, _)| {
+        let is_word_start = text[..index]
+            .chars()
+            .next_back()
+            .map(|c| !c.is_alphanumeric())
+            .unwrap_or(true);
+        is_word_start && starts_with_words(&text[index..], words)
+    })
+}
+
+fn starts_with_words(text: &str, words: &str) -> bool {
+    text.strip_prefix(words)
+        .map(|suffix| !suffix.starts_with(char::is_alphanumeric))
+        .unwrap_or(false)
+}
+
+fn ends_with_words(text: &str, words: &str) -> bool {
+    text.strip_suffix(words)
+        .map(|prefix| prefix.is_empty() || prefix.ends_with(char::is_whitespace))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temperature_change() {
+        // Given
+        let set_point = "set the heating to 20 degrees";
+        let delta = "raise the heating by 2 degrees";
+        let decrease = "make it 2 degrees cooler";
+        let lowered = "lower the heating by 2 degrees";
+        let french_delta = "fais-le 2 degrés de plus";
+        let spanish_set_point = "pon la calefacción en 20 grados";
+        let spanish_delta = "baja la calefacción en 2 grados";
+        let japanese_delta = "2度上げて";
+        let japanese_set_point = "温度を20度に上げて";
+        let korean_delta = "온도를 2도 올려";
+        let korean_set_point = "온도를 20도로 올려";
+        let lesser = "set the heating to 20 degrees lesser";
+
+        // When/Then
+        let change = |text: &str, range: Range<usize>, language: Language| {
+            temperature_change(text, &range, language).unwrap()
+        };
+        assert_eq!(None, change(set_point, 19..29, Language::EN));
+        assert_eq!(
+            Some(ChangeDirection::Increase),
+            change(delta, 21..30, Language::EN)
+        );
+        assert_eq!(
+            Some(ChangeDirection::Decrease),
+            change(decrease, 8..17, Language::EN)
+        );
+        assert_eq!(
+            Some(ChangeDirection::Decrease),
+            change(lowered, 21..30, Language::EN)
+        );
+        assert_eq!(
+            Some(ChangeDirection::Increase),
+            change(french_delta, 8..16, Language::FR)
+        );
+        assert_eq!(None, change(spanish_set_point, 22..31, Language::ES));
+        assert_eq!(
+            Some(ChangeDirection::Decrease),
+            change(spanish_delta, 23..31, Language::ES)
+        );
+        assert_eq!(
+            Some(ChangeDirection::Increase),
+            change(japanese_delta, 0..2, Language::JA)
+        );
+        assert_eq!(None, change(japanese_set_point, 3..6, Language::JA));
+        assert_eq!(
+            Some(ChangeDirection::Increase),
+            change(korean_delta, 4..6, Language::KO)
+        );
+        assert_eq!(None, change(korean_set_point, 4..7, Language::KO));
+        assert_eq!(None, change(lesser, 19..29, Language::EN));
+    }
+
+    #[test]
+    fn test_temperature() {
+        // Given
+        let text = "make it 2 degrees cooler";
+        let parsed = TemperatureValue {
+            value: 2.,
+            unit: Some("degree".to_string()),
+            mode: TemperatureMode::Absolute,
+        };
+
+        // When
+        let temperature = temperature(text, &(8..17), &parsed, Language::EN).unwrap();
+
+        // Then
+        let expected = TemperatureValue {
+            value: -2.,
+            unit: Some("degree".to_string()),
+            mode: TemperatureMode::Delta,
+        };
+        assert_eq!(expected, temperature);
+    }
+
+    #[test]
+    fn test_is_negated() {
+        // Given
+        let text = "remind me at 5 pm, not at 6 pm";
+        let contraction = "don't wake me up at 7";
+        let french = "un café sans sucre";
+        let japanese = "砂糖なしのコーヒー";
+
+        // When/Then
+        assert!(!is_negated(text, &(13..17), Language::EN).unwrap());
+        assert!(is_negated(text, &(26..30), Language::EN).unwrap());
+        assert!(is_negated(contraction, &(20..21), Language::EN).unwrap());
+        assert!(is_negated(french, &(13..18), Language::FR).unwrap());
+        assert!(is_negated(japanese, &(0..2), Language::JA).unwrap());
+    }
+
+    #[test]
+    fn test_is_interrogative() {
+        // Given
+        let question = "how many liters are left?";
+        let answer = "three liters are left";
+        let entity_question = "what time is it";
+        let french_question = "combien de litres reste-t-il ?";
+
+        // When/Then
+        assert!(is_interrogative(question, &(9..15), Language::EN).unwrap());
+        assert!(!is_interrogative(answer, &(0..12), Language::EN).unwrap());
+        assert!(is_interrogative(entity_question, &(0..9), Language::EN).unwrap());
+        assert!(is_interrogative(french_question, &(11..17), Language::FR).unwrap());
+    }
+
+    #[test]
+    fn test_media_position() {
+        // Given
+        let position = "skip to one hour twenty";
+        let backward_offset = "go back 30 seconds";
+        let forward_offset = "fast forward 2 minutes please";
+        let french_offset = "recule de 30 secondes";
+        let position_duration = DurationValue {
+            hours: 1,
+            ..DurationValue::from_minutes(20)
+        };
+        let offset = |seconds| MediaPositionValue {
+            mode: MediaPositionMode::Offset,
+            seconds,
+        };
+
+        // When/Then
+        assert_eq!(
+            MediaPositionValue {
+                mode: MediaPositionMode::Absolute,
+                seconds: 4800,
+            },
+            media_position(position, &(8..23), &position_duration, Language::EN).unwrap()
+        );
+        assert_eq!(
+            offset(-30),
+            media_position(
+                backward_offset,
+                &(8..18),
+                &DurationValue::from_seconds(30),
+                Language::EN
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            offset(120),
+            media_position(
+                forward_offset,
+                &(13..22),
+                &DurationValue::from_minutes(2),
+                Language::EN
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            offset(-30),
+            media_position(
+                french_offset,
+                &(10..21),
+                &DurationValue::from_seconds(30),
+                Language::FR
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_media_position_of_too_long_duration() {
+        // Given
+        let text = "go back 30 seconds";
+        let duration = DurationValue::from_days(i64::MAX / 2);
+
+        // When
+        let position = media_position(text, &(8..18), &duration, Language::EN);
+
+        // Then
+        assert!(position.is_err());
+    }
+}
@@ -0,0 +1,257 @@
+//! Culinary units, as found in recipes, and their metric equivalents
+use crate::language::Language;
+use crate::ontology::{CookingMetricUnit, CookingQuantityValue, CookingUnit};
+
+impl CookingQuantityValue {
+    /// Builds a cooking quantity, computing its metric equivalent rounded to the hundredth
+    pub fn new(value: f64, unit: CookingUnit) -> Self {
+        let (factor, metric_unit) = unit.metric_equivalent();
+        Self {
+            value,
+            unit,
+            metric_value: (value * factor * 100.).round() / 100.,
+            metric_unit,
+        }
+    }
+}
+
+impl CookingUnit {
+    /// Finds the unit named by the given word or abbreviation, e.g. "tablespoons", "c. à s." or
+    /// "EL", in the given language
+    ///
+    /// Only the English, French, German and Spanish vocabularies are available, metric
+    /// abbreviations such as "ml" being recognized in every language.
+    pub fn from_word(word: &str, language: Language) -> Option<Self> {
+        let word = word.trim().to_lowercase();
+        let vocabulary: &[(CookingUnit, &[&str])] = match language {
+            Language::DE => DE_UNITS,
+            Language::EN => EN_UNITS,
+            Language::ES => ES_UNITS,
+            Language::FR => FR_UNITS,
+            _ => &[],
+        };
+        vocabulary
+            .iter()
+            .chain(METRIC_ABBREVIATIONS)
+            .find(|(_, words)| words.contains(&word.as_str()))
+            .map(|(unit, _)| *unit)
+    }
+
+    /// Metric equivalent of one unit, in milliliters for volumes and in grams for weights
+    ///
+    /// Cups are US cups of 240 milliliters, and pinches and dashes are the usual approximations
+    /// of a sixteenth and of an eighth of a teaspoon.
+    pub fn metric_equivalent(&self) -> (f64, CookingMetricUnit) {
+        match self {
+            CookingUnit::Cup => (240., CookingMetricUnit::Milliliter),
+            CookingUnit::Tablespoon => (15., CookingMetricUnit::Milliliter),
+            CookingUnit::Teaspoon => (5., CookingMetricUnit::Milliliter),
+            CookingUnit::Pinch => (0.3, CookingMetricUnit::Milliliter),
+            CookingUnit::Dash => (0.6, CookingMetricUnit::Milliliter),
+            CookingUnit::FluidOunce => (29.57, CookingMetricUnit::Milliliter),
+            CookingUnit::Milliliter => (1., CookingMetricUnit::Milliliter),
+            CookingUnit::Centiliter => (10., CookingMetricUnit::Milliliter),
+            CookingUnit::Deciliter => (100., CookingMetricUnit::Milliliter),
+            CookingUnit::Liter => (1000., CookingMetricUnit::Milliliter),
+            CookingUnit::Ounce => (28.35, CookingMetricUnit::Gram),
+            CookingUnit::Pound => (453.59, CookingMetricUnit::Gram),
+            CookingUnit::Gram => (1., CookingMetricUnit::Gram),
+            CookingUnit::Kilogram => (1000., CookingMetricUnit::Gram),
+        }
+    }
+
+    /// Name of the unit in the given language, e.g. "tablespoons", English being used for the
+    /// languages whose vocabulary lacks plain singular and plural names
+    pub(crate) fn name(&self, language: Language, plural: bool) -> &'static str {
+        let vocabulary = match language {
+            Language::ES => ES_UNITS,
+            Language::FR => FR_UNITS,
+            _ => EN_UNITS,
+        };
+        vocabulary
+            .iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, words)| if plural { words[1] } else { words[0] })
+            .unwrap_or_else(|| self.abbreviation())
+    }
+
+    /// Abbreviation of the unit, e.g. "tbsp"
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            CookingUnit::Cup => "cup",
+            CookingUnit::Tablespoon => "tbsp",
+            CookingUnit::Teaspoon => "tsp",
+            CookingUnit::Pinch => "pinch",
+            CookingUnit::Dash => "dash",
+            CookingUnit::FluidOunce => "fl oz",
+            CookingUnit::Milliliter => "ml",
+            CookingUnit::Centiliter => "cl",
+            CookingUnit::Deciliter => "dl",
+            CookingUnit::Liter => "l",
+            CookingUnit::Ounce => "oz",
+            CookingUnit::Pound => "lb",
+            CookingUnit::Gram => "g",
+            CookingUnit::Kilogram => "kg",
+        }
+    }
+}
+
+const METRIC_ABBREVIATIONS: &[(CookingUnit, &[&str])] = &[
+    (CookingUnit::Milliliter, &["ml"]),
+    (CookingUnit::Centiliter, &["cl"]),
+    (CookingUnit::Deciliter, &["dl"]),
+    (CookingUnit::Liter, &["l"]),
+    (CookingUnit::Gram, &["g"]),
+    (CookingUnit::Kilogram, &["kg"]),
+];
+
+const EN_UNITS: &[(CookingUnit, &[&str])] = &[
+    (CookingUnit::Cup, &["cup", "cups"]),
+    (
+        CookingUnit::Tablespoon,
+        &["tablespoon", "tablespoons", "tbsp", "tbs"],
+    ),
+    (CookingUnit::Teaspoon, &["teaspoon", "teaspoons", "tsp"]),
+    (CookingUnit::Pinch, &["pinch", "pinches"]),
+    (CookingUnit::Dash, &["dash", "dashes"]),
+    (
+        CookingUnit::FluidOunce,
+        &["fluid ounce", "fluid ounces", "fl oz"],
+    ),
+    (
+        CookingUnit::Milliliter,
+        &["milliliter", "milliliters", "millilitre", "millilitres"],
+    ),
+    (
+        CookingUnit::Centiliter,
+        &["centiliter", "centiliters", "centilitre", "centilitres"],
+    ),
+    (
+        CookingUnit::Deciliter,
+        &["deciliter", "deciliters", "decilitre", "decilitres"],
+    ),
+    (CookingUnit::Liter, &["liter", "liters", "litre", "litres"]),
+    (CookingUnit::Ounce, &["ounce", "ounces", "oz"]),
+    (CookingUnit::Pound, &["pound", "pounds", "lb", "lbs"]),
+    (CookingUnit::Gram, &["gram", "grams", "gramme", "grammes"]),
+    (
+        CookingUnit::Kilogram,
+        &["kilogram", "kilograms", "kilo", "kilos"],
+    ),
+];
+
+const FR_UNITS: &[(CookingUnit, &[&str])] = &[
+    (CookingUnit::Cup, &["tasse", "tasses"]),
+    (
+        CookingUnit::Tablespoon,
+        &[
+            "cuillère à soupe",
+            "cuillères à soupe",
+            "cuillerée à soupe",
+            "cuillerées à soupe",
+            "c. à s.",
+            "càs",
+        ],
+    ),
+    (
+        CookingUnit::Teaspoon,
+        &[
+            "cuillère à café",
+            "cuillères à café",
+            "cuillerée à café",
+            "cuillerées à café",
+            "c. à c.",
+            "càc",
+        ],
+    ),
+    (CookingUnit::Pinch, &["pincée", "pincées"]),
+    (CookingUnit::Dash, &["trait", "traits"]),
+    (CookingUnit::FluidOunce, &["once liquide", "onces liquides"]),
+    (CookingUnit::Milliliter, &["millilitre", "millilitres"]),
+    (CookingUnit::Centiliter, &["centilitre", "centilitres"]),
+    (CookingUnit::Deciliter, &["décilitre", "décilitres"]),
+    (CookingUnit::Liter, &["litre", "litres"]),
+    (CookingUnit::Ounce, &["once", "onces"]),
+    (CookingUnit::Pound, &["livre", "livres"]),
+    (CookingUnit::Gram, &["gramme", "grammes"]),
+    (
+        CookingUnit::Kilogram,
+        &["kilogramme", "kilogrammes", "kilo", "kilos"],
+    ),
+];
+
+const DE_UNITS: &[(CookingUnit, &[&str])] = &[
+    (CookingUnit::Cup, &["tasse", "tassen", "becher"]),
+    (CookingUnit::Tablespoon, &["esslöffel", "el"]),
+    (CookingUnit::Teaspoon, &["teelöffel", "tl"]),
+    (CookingUnit::Pinch, &["prise", "prisen"]),
+    (CookingUnit::Dash, &["spritzer", "schuss"]),
+    (CookingUnit::FluidOunce, &["flüssigunze", "flüssigunzen"]),
+    (CookingUnit::Milliliter, &["milliliter"]),
+    (CookingUnit::Centiliter, &["zentiliter"]),
+    (CookingUnit::Deciliter, &["deziliter"]),
+    (CookingUnit::Liter, &["liter"]),
+    (CookingUnit::Ounce, &["unze", "unzen"]),
+    (CookingUnit::Pound, &["pfund"]),
+    (CookingUnit::Gram, &["gramm"]),
+    (CookingUnit::Kilogram, &["kilogramm", "kilo"]),
+];
+
+const ES_UNITS: &[(CookingUnit, &[&str])] = &[
+    (CookingUnit::Cup, &["taza", "tazas"]),
+    (CookingUnit::Tablespoon, &["cucharada", "cucharadas"]),
+    (CookingUnit::Teaspoon, &["cucharadita", "cucharaditas"]),
+    (CookingUnit::Pinch, &["pizca", "pizcas"]),
+    (CookingUnit::Dash, &["chorrito", "chorritos"]),
+    (CookingUnit::FluidOunce, &["onza líquida", "onzas líquidas"]),
+    (CookingUnit::Milliliter, &["mililitro", "mililitros"]),
+    (CookingUnit::Centiliter, &["centilitro", "centilitros"]),
+    (CookingUnit::Deciliter, &["decilitro", "decilitros"]),
+    (CookingUnit::Liter, &["litro", "litros"]),
+    (CookingUnit::Ounce, &["onza", "onzas"]),
+    (CookingUnit::Pound, &["libra", "libras"]),
+    (CookingUnit::Gram, &["gramo", "gramos"]),
+    (
+        CookingUnit::Kilogram,
+        &["kilogramo", "kilogramos", "kilo", "kilos"],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooking_unit_from_word() {
+        assert_eq!(
+            Some(CookingUnit::Tablespoon),
+            CookingUnit::from_word("Tablespoons", Language::EN)
+        );
+        assert_eq!(
+            Some(CookingUnit::Teaspoon),
+            CookingUnit::from_word("c. à c.", Language::FR)
+        );
+        assert_eq!(
+            Some(CookingUnit::Pinch),
+            CookingUnit::from_word("Prise", Language::DE)
+        );
+        assert_eq!(
+            Some(CookingUnit::Gram),
+            CookingUnit::from_word("g", Language::ES)
+        );
+        assert_eq!(None, CookingUnit::from_word("cup", Language::FR));
+    }
+
+    #[test]
+    fn test_cooking_quantity_metric_equivalent() {
+        // When
+        let cups = CookingQuantityValue::new(1.5, CookingUnit::Cup);
+        let pounds = CookingQuantityValue::new(2., CookingUnit::Pound);
+
+        // Then
+        assert_eq!(360., cups.metric_value);
+        assert_eq!(CookingMetricUnit::Milliliter, cups.metric_unit);
+        assert_eq!(907.18, pounds.metric_value);
+        assert_eq!(CookingMetricUnit::Gram, pounds.metric_unit);
+    }
+}
@@ -0,0 +1,660 @@
+//! Calendar manipulations of the datetimes contained in slot values
+use crate::errors::*;
+use crate::ontology::*;
+use failure::{bail, format_err};
+use std::fmt;
+
+impl InstantTimeValue {
+    /// Builds an exact instant time from a resolved datetime, e.g. "2017-06-13 18:00:00 +02:00"
+    ///
+    /// The grain is the finest non-zero time component of the datetime, hour in the example
+    /// above, and defaults to the day at midnight: "2018-01-01 00:00:00 +01:00" is a date rather
+    /// than a year. Values of a coarser grain are built with `truncate_to`.
+    pub fn from_datetime(datetime: &str) -> Result<InstantTimeValue> {
+        let components = DatetimeComponents::parse(datetime)?;
+        let grain = if components.second != 0 {
+            Grain::Second
+        } else if components.minute != 0 {
+            Grain::Minute
+        } else if components.hour != 0 {
+            Grain::Hour
+        } else {
+            Grain::Day
+        };
+        Ok(InstantTimeValue {
+            value: datetime.to_string(),
+            grain,
+            precision: Precision::Exact,
+        })
+    }
+
+    /// Truncates the value to the start of the given grain, e.g. "2017-06-13 18:25:00 +02:00"
+    /// truncated to `Grain::Hour` becomes "2017-06-13 18:00:00 +02:00"
+    ///
+    /// Weeks start on Monday. The grain of the value is updated when the truncation grain is
+    /// coarser.
+    pub fn truncate_to(&self, grain: Grain) -> Result<InstantTimeValue> {
+        let truncated = DatetimeComponents::parse(&self.value)?.truncate(grain);
+        Ok(InstantTimeValue {
+            value: truncated.to_string(),
+            grain: coarsest_grain(self.grain, grain),
+            precision: self.precision,
+        })
+    }
+}
+
+impl TimeIntervalValue {
+    /// Expands the interval so that its bounds are aligned on the given grain, e.g. the interval
+    /// from 2:30 pm to 3:30 pm expanded to `Grain::Hour` goes from 2 pm to 4 pm
+    ///
+    /// The upper bound of the expanded interval is always exclusive.
+    pub fn expand_to(&self, grain: Grain) -> Result<TimeIntervalValue> {
+        let from = match self.from {
+            Some(ref from) => Some(DatetimeComponents::parse(from)?.truncate(grain).to_string()),
+            None => None,
+        };
+        let to = match self.to {
+            Some(ref to) => {
+                let to = DatetimeComponents::parse(to)?;
+                let truncated = to.truncate(grain);
+                if truncated == to && !self.to_inclusive {
+                    Some(to.to_string())
+                } else {
+                    Some(truncated.add(grain, 1).to_string())
+                }
+            }
+            None => None,
+        };
+        Ok(TimeIntervalValue {
+            from,
+            to,
+            to_inclusive: false,
+        })
+    }
+
+    /// Upper bound of a date interval as displayed to users, e.g. the interval from June 7 to
+    /// June 9 exclusive is displayed as going from June 7 to June 8
+    ///
+    /// The upper bound is only shifted to the previous day when it is an exclusive midnight and
+    /// the interval does not start during a day.
+    pub(crate) fn displayed_to(&self) -> Option<String> {
+        let to = self.to.as_ref()?;
+        match DatetimeComponents::parse(to) {
+            Ok(components)
+                if !self.to_inclusive && !self.starts_during_day() && components.is_midnight() =>
+            {
+                Some(components.add(Grain::Day, -1).to_string())
+            }
+            _ => Some(to.clone()),
+        }
+    }
+
+    /// Grain with which the upper bound is displayed, when it cannot be inferred from the bound
+    /// itself: the interval from 6 pm to midnight is displayed as going to midnight rather than
+    /// to the next day
+    pub(crate) fn displayed_to_grain(&self) -> Option<Grain> {
+        if self.starts_during_day() {
+            Some(Grain::Minute)
+        } else {
+            None
+        }
+    }
+
+    fn starts_during_day(&self) -> bool {
+        match self.from {
+            Some(ref from) => DatetimeComponents::parse(from)
+                .map(|from| !from.is_midnight())
+                .unwrap_or(true),
+            None => false,
+        }
+    }
+}
+
+/// Fiscal calendar, whose years start on the first day of a configurable month
+///
+/// Fiscal years are named after the calendar year in which they end, e.g. with a start in
+/// October, the fiscal year 2018 goes from October 2017 to September 2018.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalCalendar {
+    start_month: u32,
+}
+
+/// A quarter of a fiscal year, from 1 to 4
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalQuarter {
+    pub fiscal_year: i32,
+    pub quarter: u32,
+}
+
+impl Default for FiscalCalendar {
+    /// Fiscal years matching calendar years
+    fn default() -> Self {
+        Self { start_month: 1 }
+    }
+}
+
+impl FiscalCalendar {
+    pub fn new(start_month: u32) -> Result<Self> {
+        if start_month == 0 || start_month > 12 {
+            bail!("Invalid fiscal year start month: {}", start_month)
+        }
+        Ok(Self { start_month })
+    }
+
+    pub fn start_month(&self) -> u32 {
+        self.start_month
+    }
+
+    /// Fiscal quarter containing the instant time
+    pub fn quarter_of(&self, instant_time: &InstantTimeValue) -> Result<FiscalQuarter> {
+        let datetime = DatetimeComponents::parse(&instant_time.value)?;
+        let months_since_start = (datetime.month + 12 - self.start_month) % 12;
+        let fiscal_year = if self.start_month == 1 || datetime.month < self.start_month {
+            datetime.year
+        } else {
+            datetime.year + 1
+        };
+        Ok(FiscalQuarter {
+            fiscal_year,
+            quarter: months_since_start / 3 + 1,
+        })
+    }
+
+    /// Interval covering the fiscal quarter, whose bounds use the provided UTC offset, such as
+    /// "+02:00"
+    pub fn quarter_interval(
+        &self,
+        quarter: FiscalQuarter,
+        utc_offset: &str,
+    ) -> Result<TimeIntervalValue> {
+        if quarter.quarter == 0 || quarter.quarter > 4 {
+            bail!("Invalid fiscal quarter: {}", quarter.quarter)
+        }
+        let start_year = if self.start_month == 1 {
+            quarter.fiscal_year
+        } else {
+            quarter.fiscal_year - 1
+        };
+        let year_start = DatetimeComponents {
+            year: start_year,
+            month: self.start_month,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            utc_offset: utc_offset.to_string(),
+        };
+        let from = year_start.add(Grain::Quarter, quarter.quarter as i64 - 1);
+        let to = from.add(Grain::Quarter, 1);
+        Ok(TimeIntervalValue {
+            from: Some(from.to_string()),
+            to: Some(to.to_string()),
+            to_inclusive: false,
+        })
+    }
+}
+
+/// School calendar, whose years start with the "rentrée" on the first day of a configurable month
+///
+/// School years are named after the calendar year in which they start, e.g. with a start in
+/// September, the school year 2017 goes from September 2017 to August 2018.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchoolCalendar {
+    start_month: u32,
+}
+
+impl Default for SchoolCalendar {
+    /// School years starting in September
+    fn default() -> Self {
+        Self { start_month: 9 }
+    }
+}
+
+impl SchoolCalendar {
+    pub fn new(start_month: u32) -> Result<Self> {
+        if start_month == 0 || start_month > 12 {
+            bail!("Invalid school year start month: {}", start_month)
+        }
+        Ok(Self { start_month })
+    }
+
+    pub fn start_month(&self) -> u32 {
+        self.start_month
+    }
+
+    /// School year containing the instant time
+    pub fn school_year_of(&self, instant_time: &InstantTimeValue) -> Result<i32> {
+        let datetime = DatetimeComponents::parse(&instant_time.value)?;
+        Ok(if datetime.month < self.start_month {
+            datetime.year - 1
+        } else {
+            datetime.year
+        })
+    }
+
+    /// Interval covering the school year, whose bounds use the provided UTC offset, such as
+    /// "+02:00"
+    pub fn school_year_interval(&self, school_year: i32, utc_offset: &str) -> TimeIntervalValue {
+        let from = self.year_start(school_year, utc_offset);
+        let to = from.add(Grain::Year, 1);
+        TimeIntervalValue {
+            from: Some(from.to_string()),
+            to: Some(to.to_string()),
+            to_inclusive: false,
+        }
+    }
+
+    /// Next start of a school year, e.g. "la rentrée" or "back to school", relative to the
+    /// instant time
+    ///
+    /// The current start is returned during the month in which the school year starts. As the
+    /// exact day depends on the school, the result is an approximate month.
+    pub fn rentree(&self, reference: &InstantTimeValue) -> Result<InstantTimeValue> {
+        let datetime = DatetimeComponents::parse(&reference.value)?;
+        let year = if datetime.month <= self.start_month {
+            datetime.year
+        } else {
+            datetime.year + 1
+        };
+        Ok(InstantTimeValue {
+            value: self.year_start(year, &datetime.utc_offset).to_string(),
+            grain: Grain::Month,
+            precision: Precision::Approximate,
+        })
+    }
+
+    fn year_start(&self, year: i32, utc_offset: &str) -> DatetimeComponents {
+        DatetimeComponents {
+            year,
+            month: self.start_month,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            utc_offset: utc_offset.to_string(),
+        }
+    }
+}
+
+fn coarsest_grain(grain: Grain, other: Grain) -> Grain {
+    if (grain as u8) < (other as u8) {
+        grain
+    } else {
+        other
+    }
+}
+
+/// Date and time components of a datetime string such as "2017-06-13 18:00:00 +02:00"
+///
+/// The UTC offset is kept as is, as values are expressed in the local time of the user.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DatetimeComponents {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub utc_offset: String,
+}
+
+impl DatetimeComponents {
+    pub fn parse(datetime: &str) -> Result<Self> {
+        let error = || format_err!("Invalid datetime: '{}'", datetime);
+        let parts = datetime.split(' ').collect::<Vec<_>>();
+        if parts.len() != 3 {
+            return Err(error());
+        }
+        let date = parse_numbers(parts[0], '-').ok_or_else(error)?;
+        let time = parse_numbers(parts[1], ':').ok_or_else(error)?;
+        if date.len() != 3 || time.len() != 3 {
+            return Err(error());
+        }
+        let components = Self {
+            year: date[0] as i32,
+            month: date[1],
+            day: date[2],
+            hour: time[0],
+            minute: time[1],
+            second: time[2],
+            utc_offset: parts[2].to_string(),
+        };
+        if components.month == 0
+            || components.month > 12
+            || components.day == 0
+            || components.day > days_in_month(components.year, components.month)
+            || components.hour > 23
+            || components.minute > 59
+            || components.second > 60
+        {
+            return Err(error());
+        }
+        Ok(components)
+    }
+
+    pub fn is_midnight(&self) -> bool {
+        self.hour == 0 && self.minute == 0 && self.second == 0
+    }
+
+    pub fn truncate(&self, grain: Grain) -> Self {
+        let mut truncated = self.clone();
+        match grain {
+            Grain::Second => {}
+            Grain::Minute => truncated.second = 0,
+            Grain::Hour => {
+                truncated.minute = 0;
+                truncated.second = 0;
+            }
+            Grain::Day | Grain::Week | Grain::Month | Grain::Quarter | Grain::Year => {
+                truncated.hour = 0;
+                truncated.minute = 0;
+                truncated.second = 0;
+            }
+        }
+        match grain {
+            Grain::Week => truncated.add(Grain::Day, -(self.weekday() as i64)),
+            Grain::Month => Self {
+                day: 1,
+                ..truncated
+            },
+            Grain::Quarter => Self {
+                month: (self.month - 1) / 3 * 3 + 1,
+                day: 1,
+                ..truncated
+            },
+            Grain::Year => Self {
+                month: 1,
+                day: 1,
+                ..truncated
+            },
+            _ => truncated,
+        }
+    }
+
+    /// Adds `count` units of `grain`, the day of month being clamped when adding months to a
+    /// date such as January 31st
+    pub fn add(&self, grain: Grain, count: i64) -> Self {
+        match grain {
+            Grain::Year => self.add_months(12 * count),
+            Grain::Quarter => self.add_months(3 * count),
+            Grain::Month => self.add_months(count),
+            Grain::Week => self.add_seconds(7 * 86400 * count),
+            Grain::Day => self.add_seconds(86400 * count),
+            Grain::Hour => self.add_seconds(3600 * count),
+            Grain::Minute => self.add_seconds(60 * count),
+            Grain::Second => self.add_seconds(count),
+        }
+    }
+
+    /// Day of the week, from 0 for Monday to 6 for Sunday
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (days_from_civil(self.year as i64, self.month, self.day) + 3).rem_euclid(7) as u32
+    }
+
+    fn add_months(&self, count: i64) -> Self {
+        let months = self.year as i64 * 12 + (self.month as i64 - 1) + count;
+        let year = months.div_euclid(12) as i32;
+        let month = months.rem_euclid(12) as u32 + 1;
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+            ..self.clone()
+        }
+    }
+
+    fn add_seconds(&self, count: i64) -> Self {
+        let seconds = days_from_civil(self.year as i64, self.month, self.day) * 86400
+            + self.hour as i64 * 3600
+            + self.minute as i64 * 60
+            + self.second as i64
+            + count;
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+        let seconds_of_day = seconds.rem_euclid(86400);
+        Self {
+            year: year as i32,
+            month,
+            day,
+            hour: (seconds_of_day / 3600) as u32,
+            minute: (seconds_of_day % 3600 / 60) as u32,
+            second: (seconds_of_day % 60) as u32,
+            utc_offset: self.utc_offset.clone(),
+        }
+    }
+}
+
+impl fmt::Display for DatetimeComponents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02} {}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second, self.utc_offset
+        )
+    }
+}
+
+fn parse_numbers(string: &str, separator: char) -> Option<Vec<u32>> {
+    string
+        .split(separator)
+        .map(|n| n.parse::<u32>().ok())
+        .collect()
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days since 1970-01-01 in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of `days_from_civil`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instant_time_from_datetime() {
+        // When
+        let instant_time = InstantTimeValue::from_datetime("2017-06-13 18:30:00 +02:00");
+        let first_of_year = InstantTimeValue::from_datetime("2018-01-01 00:00:00 +01:00");
+        let first_of_month = InstantTimeValue::from_datetime("2018-06-01 00:00:00 +02:00");
+        let invalid = InstantTimeValue::from_datetime("2017-02-30 00:00:00 +02:00");
+
+        // Then
+        let expected_instant_time = InstantTimeValue {
+            value: "2017-06-13 18:30:00 +02:00".to_string(),
+            grain: Grain::Minute,
+            precision: Precision::Exact,
+        };
+        assert_eq!(expected_instant_time, instant_time.unwrap());
+        assert_eq!(Grain::Day, first_of_year.unwrap().grain);
+        assert_eq!(Grain::Day, first_of_month.unwrap().grain);
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_truncate_instant_time() {
+        // Given
+        let instant_time = InstantTimeValue {
+            value: "2017-06-15 18:25:12 +02:00".to_string(),
+            grain: Grain::Second,
+            precision: Precision::Approximate,
+        };
+
+        // When
+        let by_hour = instant_time.truncate_to(Grain::Hour).unwrap();
+        let by_week = instant_time.truncate_to(Grain::Week).unwrap();
+        let by_quarter = instant_time.truncate_to(Grain::Quarter).unwrap();
+
+        // Then
+        assert_eq!("2017-06-15 18:00:00 +02:00", by_hour.value);
+        assert_eq!(Grain::Hour, by_hour.grain);
+        assert_eq!("2017-06-12 00:00:00 +02:00", by_week.value);
+        assert_eq!("2017-04-01 00:00:00 +02:00", by_quarter.value);
+    }
+
+    #[test]
+    fn test_expand_time_interval() {
+        // Given
+        let around_three_pm = TimeIntervalValue {
+            from: Some("2017-12-31 14:30:00 +01:00".to_string()),
+            to: Some("2017-12-31 15:30:00 +01:00".to_string()),
+            to_inclusive: false,
+        };
+        let until_midnight = TimeIntervalValue {
+            from: None,
+            to: Some("2017-12-31 00:00:00 +01:00".to_string()),
+            to_inclusive: true,
+        };
+
+        // When
+        let by_hour = around_three_pm.expand_to(Grain::Hour).unwrap();
+        let by_day = around_three_pm.expand_to(Grain::Day).unwrap();
+        let inclusive_by_day = until_midnight.expand_to(Grain::Day).unwrap();
+
+        // Then
+        let expected_by_hour = TimeIntervalValue {
+            from: Some("2017-12-31 14:00:00 +01:00".to_string()),
+            to: Some("2017-12-31 16:00:00 +01:00".to_string()),
+            to_inclusive: false,
+        };
+        let expected_by_day = TimeIntervalValue {
+            from: Some("2017-12-31 00:00:00 +01:00".to_string()),
+            to: Some("2018-01-01 00:00:00 +01:00".to_string()),
+            to_inclusive: false,
+        };
+        let expected_inclusive_by_day = TimeIntervalValue {
+            from: None,
+            to: Some("2018-01-01 00:00:00 +01:00".to_string()),
+            to_inclusive: false,
+        };
+        assert_eq!(expected_by_hour, by_hour);
+        assert_eq!(expected_by_day, by_day);
+        assert_eq!(expected_inclusive_by_day, inclusive_by_day);
+    }
+
+    #[test]
+    fn test_fiscal_quarters() {
+        // Given
+        let calendar = FiscalCalendar::new(10).unwrap();
+        let instant_time = InstantTimeValue {
+            value: "2017-11-15 00:00:00 -05:00".to_string(),
+            grain: Grain::Day,
+            precision: Precision::Exact,
+        };
+
+        // When
+        let quarter = calendar.quarter_of(&instant_time).unwrap();
+        let third_quarter = FiscalQuarter {
+            fiscal_year: 2018,
+            quarter: 3,
+        };
+        let interval = calendar.quarter_interval(third_quarter, "-05:00").unwrap();
+
+        // Then
+        let expected_quarter = FiscalQuarter {
+            fiscal_year: 2018,
+            quarter: 1,
+        };
+        let expected_interval = TimeIntervalValue {
+            from: Some("2018-04-01 00:00:00 -05:00".to_string()),
+            to: Some("2018-07-01 00:00:00 -05:00".to_string()),
+            to_inclusive: false,
+        };
+        assert_eq!(expected_quarter, quarter);
+        assert_eq!(expected_interval, interval);
+        assert!(FiscalCalendar::new(13).is_err());
+    }
+
+    #[test]
+    fn test_school_calendar() {
+        // Given
+        let calendar = SchoolCalendar::default();
+        let instant_time = |value: &str| InstantTimeValue {
+            value: value.to_string(),
+            grain: Grain::Day,
+            precision: Precision::Exact,
+        };
+
+        // When
+        let rentree_in_june = calendar.rentree(&instant_time("2017-06-13 00:00:00 +02:00"));
+        let rentree_in_october = calendar.rentree(&instant_time("2017-10-02 00:00:00 +02:00"));
+        let school_year = calendar.school_year_of(&instant_time("2018-02-10 00:00:00 +01:00"));
+        let interval = calendar.school_year_interval(2017, "+02:00");
+
+        // Then
+        let expected_rentree = InstantTimeValue {
+            value: "2017-09-01 00:00:00 +02:00".to_string(),
+            grain: Grain::Month,
+            precision: Precision::Approximate,
+        };
+        let expected_interval = TimeIntervalValue {
+            from: Some("2017-09-01 00:00:00 +02:00".to_string()),
+            to: Some("2018-09-01 00:00:00 +02:00".to_string()),
+            to_inclusive: false,
+        };
+        assert_eq!(expected_rentree, rentree_in_june.unwrap());
+        assert_eq!(
+            "2018-09-01 00:00:00 +02:00",
+            rentree_in_october.unwrap().value
+        );
+        assert_eq!(2017, school_year.unwrap());
+        assert_eq!(expected_interval, interval);
+        assert!(SchoolCalendar::new(0).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_datetime() {
+        assert!(DatetimeComponents::parse("2017-13-01 00:00:00 +02:00").is_err());
+        assert!(DatetimeComponents::parse("2017-02-29 00:00:00 +02:00").is_err());
+        assert!(DatetimeComponents::parse("tomorrow").is_err());
+    }
+
+    #[test]
+    fn test_add_months_clamps_day() {
+        // Given
+        let datetime = DatetimeComponents::parse("2020-01-31 10:00:00 +00:00").unwrap();
+
+        // When
+        let next_month = datetime.add(Grain::Month, 1);
+
+        // Then
+        assert_eq!("2020-02-29 10:00:00 +00:00", next_month.to_string());
+    }
+}
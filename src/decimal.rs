@@ -0,0 +1,157 @@
+//! Arbitrary precision representation of numeric values, enabled with the `bigdecimal` feature
+use crate::errors::*;
+use crate::format::NumberSeparators;
+use crate::language::Language;
+use crate::ontology::*;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use failure::{bail, format_err};
+use std::str::FromStr;
+
+/// Parses a number written with the separators of the language into an exact decimal, without
+/// going through a floating value, e.g. 1234567.89 for "1234567,89" in French or "1,234,567.89"
+/// in English
+pub fn parse_decimal(digits: &str, language: Language) -> Result<BigDecimal> {
+    let separators = NumberSeparators::of(language);
+    let normalized = digits
+        .trim()
+        .replace(separators.grouping, "")
+        .replace([' ', '\u{a0}', '\u{202f}'], "")
+        .replace(separators.decimal, ".");
+    BigDecimal::from_str(&normalized)
+        .map_err(|_| format_err!("Invalid {:?} decimal: '{}'", language, digits))
+}
+
+impl NumberValue {
+    /// Converts the number to a decimal, using the shortest decimal representation of the
+    /// floating value, e.g. 0.1 rather than 0.1000000000000000055511151231257827
+    pub fn to_big_decimal(&self) -> Result<BigDecimal> {
+        BigDecimal::from_str(&format!("{}", self.value))
+            .map_err(|_| format_err!("Cannot convert {} to a decimal", self.value))
+    }
+}
+
+impl AmountOfMoneyValue {
+    /// Converts the amount to an exact decimal, computed from its minor units, e.g. 1234567.89
+    /// for 123456789 cents
+    pub fn to_big_decimal(&self) -> BigDecimal {
+        BigDecimal::new(self.minor_units.into(), self.minor_unit_exponent() as i64)
+    }
+
+    /// Builds an amount of money from its digits written with the separators of the language, e.g.
+    /// "1234567,89" euros in French, keeping all of them
+    pub fn from_decimal_digits(
+        digits: &str,
+        language: Language,
+        precision: Precision,
+        unit: Option<String>,
+    ) -> Result<Self> {
+        Self::from_big_decimal(&parse_decimal(digits, language)?, precision, unit)
+    }
+
+    /// Builds an amount of money from a decimal, which must not have more digits than the minor
+    /// unit of the currency
+    pub fn from_big_decimal(
+        value: &BigDecimal,
+        precision: Precision,
+        unit: Option<String>,
+    ) -> Result<Self> {
+        let exponent = minor_unit_exponent(unit.as_deref());
+        let minor_units = value * BigDecimal::new(1.into(), -(exponent as i64));
+        if !minor_units.is_integer() {
+            bail!(
+                "{} has more decimals than the minor unit of {:?}",
+                value,
+                unit
+            )
+        }
+        let minor_units = minor_units
+            .to_i64()
+            .ok_or_else(|| format_err!("{} is too large to be an amount of money", value))?;
+        Ok(AmountOfMoneyValue::from_minor_units(
+            minor_units,
+            precision,
+            unit,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_of_money_to_big_decimal() {
+        // Given
+        let amount = AmountOfMoneyValue::from_minor_units(123456789, Precision::Exact, None);
+
+        // When
+        let decimal = amount.to_big_decimal();
+
+        // Then
+        assert_eq!(BigDecimal::from_str("1234567.89").unwrap(), decimal);
+        assert_eq!(
+            amount,
+            AmountOfMoneyValue::from_big_decimal(&decimal, Precision::Exact, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_amount_of_money_from_decimal_digits() {
+        // When
+        let amount = AmountOfMoneyValue::from_decimal_digits(
+            "1234567,89",
+            Language::FR,
+            Precision::Exact,
+            Some("€".to_string()),
+        )
+        .unwrap();
+
+        // Then
+        assert_eq!(123456789, amount.minor_units);
+        assert_eq!(
+            BigDecimal::from_str("1234567.89").unwrap(),
+            amount.to_big_decimal()
+        );
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(
+            BigDecimal::from_str("1234567.89").unwrap(),
+            parse_decimal("1.234.567,89", Language::DE).unwrap()
+        );
+        assert_eq!(
+            BigDecimal::from_str("1234567.89").unwrap(),
+            parse_decimal("1,234,567.89", Language::EN).unwrap()
+        );
+        assert_eq!(
+            BigDecimal::from_str("1234567.89").unwrap(),
+            parse_decimal("1 234 567,89", Language::FR).unwrap()
+        );
+        assert!(parse_decimal("douze", Language::FR).is_err());
+    }
+
+    #[test]
+    fn test_amount_of_money_from_big_decimal_with_too_many_decimals() {
+        // Given
+        let decimal = BigDecimal::from_str("12.345").unwrap();
+
+        // When
+        let amount = AmountOfMoneyValue::from_big_decimal(&decimal, Precision::Exact, None);
+
+        // Then
+        assert!(amount.is_err());
+    }
+
+    #[test]
+    fn test_number_to_big_decimal() {
+        // Given
+        let number = NumberValue { value: 0.1 };
+
+        // When
+        let decimal = number.to_big_decimal().unwrap();
+
+        // Then
+        assert_eq!(BigDecimal::from_str("0.1").unwrap(), decimal);
+    }
+}
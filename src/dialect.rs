@@ -0,0 +1,172 @@
+//! Serialization of the ontology types with a uniform key naming convention
+//!
+//! The ontology types use a mix of camelCase and snake_case keys. This module allows to
+//! serialize them using a single convention. Deserialization accepts both conventions
+//! directly, so `serde_json::from_str` can be used on payloads produced in any dialect.
+use crate::errors::*;
+use serde::Serialize;
+use serde_json::{self, Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonDialect {
+    /// Keys such as `slotName` or `entityKind`
+    CamelCase,
+    /// Keys such as `slot_name` or `entity_kind`
+    SnakeCase,
+}
+
+impl JsonDialect {
+    pub fn convert_key(&self, key: &str) -> String {
+        match *self {
+            JsonDialect::CamelCase => to_camel_case(key),
+            JsonDialect::SnakeCase => to_snake_case(key),
+        }
+    }
+
+    /// Recursively renames the object keys of a JSON value according to the dialect
+    pub fn convert_value(&self, value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(k, v)| (self.convert_key(&k), self.convert_value(v)))
+                    .collect::<Map<String, Value>>(),
+            ),
+            Value::Array(values) => {
+                Value::Array(values.into_iter().map(|v| self.convert_value(v)).collect())
+            }
+            other => other,
+        }
+    }
+}
+
+pub fn to_value_with_dialect<T: Serialize>(value: &T, dialect: JsonDialect) -> Result<Value> {
+    Ok(dialect.convert_value(serde_json::to_value(value)?))
+}
+
+pub fn to_string_with_dialect<T: Serialize>(value: &T, dialect: JsonDialect) -> Result<String> {
+    Ok(serde_json::to_string(&to_value_with_dialect(
+        value, dialect,
+    )?)?)
+}
+
+pub(crate) fn to_camel_case(key: &str) -> String {
+    let mut converted = String::with_capacity(key.len());
+    let mut uppercase_next = false;
+    for c in key.chars() {
+        if c == '_' && !converted.is_empty() {
+            uppercase_next = true;
+        } else if uppercase_next {
+            converted.extend(c.to_uppercase());
+            uppercase_next = false;
+        } else {
+            converted.push(c);
+        }
+    }
+    converted
+}
+
+pub(crate) fn to_snake_case(key: &str) -> String {
+    let mut converted = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_uppercase() {
+            if !converted.is_empty() {
+                converted.push('_');
+            }
+            converted.extend(c.to_lowercase());
+        } else {
+            converted.push(c);
+        }
+    }
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::builtin_entity::{BuiltinEntity, BuiltinEntityKind};
+    use crate::ontology::*;
+
+    #[test]
+    fn test_intent_parser_result_snake_case_round_trip() {
+        // Given
+        let result = IntentParserResult {
+            input: "book a table until 8pm".to_string(),
+            intent: IntentClassifierResult {
+                intent_name: Some("BookRestaurant".to_string()),
+                confidence_score: 0.5,
+            },
+            slots: vec![Slot {
+                raw_value: "until 8pm".to_string(),
+                value: SlotValue::TimeInterval(TimeIntervalValue {
+                    from: None,
+                    to: Some("2019-09-06 20:00:00 +02:00".to_string()),
+                    to_inclusive: true,
+                }),
+                alternatives: vec![],
+                range: 13..22,
+                entity: "snips/datetime".to_string(),
+                slot_name: "time".to_string(),
+                confidence_score: Some(0.9),
+            }],
+            alternatives: vec![],
+        };
+
+        // When
+        let json = to_value_with_dialect(&result, JsonDialect::SnakeCase).unwrap();
+        let deserialized: IntentParserResult = serde_json::from_value(json.clone()).unwrap();
+
+        // Then
+        assert_eq!("BookRestaurant", json["intent"]["intent_name"]);
+        assert_eq!("until 8pm", json["slots"][0]["raw_value"]);
+        assert_eq!(true, json["slots"][0]["value"]["to_inclusive"]);
+        assert_eq!(result, deserialized);
+    }
+
+    #[test]
+    fn test_builtin_entity_camel_case_round_trip() {
+        // Given
+        let entity = BuiltinEntity {
+            value: "ten dollars".to_string(),
+            range: 0..11,
+            entity: SlotValue::AmountOfMoney(AmountOfMoneyValue::from_minor_units(
+                1000,
+                Precision::Exact,
+                Some("$".to_string()),
+            )),
+            alternatives: vec![],
+            entity_kind: BuiltinEntityKind::AmountOfMoney,
+            negated: false,
+        };
+
+        // When
+        let json = to_value_with_dialect(&entity, JsonDialect::CamelCase).unwrap();
+        let deserialized: BuiltinEntity = serde_json::from_value(json.clone()).unwrap();
+
+        // Then
+        assert_eq!("snips/amountOfMoney", json["entityKind"]);
+        assert_eq!("AmountOfMoney", json["entity"]["kind"]);
+        assert_eq!(1000, json["entity"]["minorUnits"]);
+        assert_eq!(entity, deserialized);
+    }
+
+    #[test]
+    fn test_cooking_quantity_snake_case_round_trip() {
+        // Given
+        let value = SlotValue::CookingQuantity(CookingQuantityValue {
+            value: 2.,
+            unit: CookingUnit::Tablespoon,
+            metric_value: 30.,
+            metric_unit: CookingMetricUnit::Milliliter,
+        });
+
+        // When
+        let camel_case = to_value_with_dialect(&value, JsonDialect::CamelCase).unwrap();
+        let snake_case = to_value_with_dialect(&value, JsonDialect::SnakeCase).unwrap();
+
+        // Then
+        assert_eq!(30., camel_case["metricValue"]);
+        assert_eq!(30., snake_case["metric_value"]);
+        assert_eq!(value, serde_json::from_value(camel_case).unwrap());
+        assert_eq!(value, serde_json::from_value(snake_case).unwrap());
+    }
+}
@@ -0,0 +1,89 @@
+//! Rendering of the entity catalog into Markdown documentation
+//!
+//! The documentation is generated from `complete_entity_ontology`, so that it always describes
+//! the entities of the current version of the ontology.
+use crate::entity::catalog::{EntityDescription, EntityOntology};
+use serde_json;
+
+/// Renders the entity catalog as a Markdown document, with a summary table followed by one
+/// section per entity
+///
+/// Entities are sorted by identifier, which makes the output deterministic.
+pub fn render_markdown(ontology: &EntityOntology) -> String {
+    let mut entities = ontology.entities.iter().collect::<Vec<_>>();
+    entities.sort_by(|a, b| a.identifier.cmp(&b.identifier));
+
+    let mut markdown = String::new();
+    markdown.push_str("# Builtin entities\n\n");
+    markdown.push_str(&format!(
+        "Builtin entities of the Snips NLU ontology, version {}.\n\n",
+        ontology.ontology_version
+    ));
+    markdown.push_str("| Entity | Identifier | Group | Parser |\n");
+    markdown.push_str("| --- | --- | --- | --- |\n");
+    for entity in entities.iter() {
+        markdown.push_str(&format!(
+            "| [{}](#{}) | `{}` | {:?} | {} |\n",
+            entity.name,
+            entity.name.to_lowercase(),
+            entity.identifier,
+            entity.group,
+            parser_name(entity)
+        ));
+    }
+    for entity in entities {
+        markdown.push('\n');
+        render_entity_section(&mut markdown, entity);
+    }
+    markdown
+}
+
+fn render_entity_section(markdown: &mut String, entity: &EntityDescription) {
+    markdown.push_str(&format!("## {}\n\n", entity.name));
+    markdown.push_str(&format!("Identifier: `{}`\n\n", entity.identifier));
+    markdown.push_str(&format!("{}\n\n", entity.description));
+    markdown.push_str("Results examples:\n\n");
+    markdown.push_str("```json\n");
+    markdown.push_str(
+        &serde_json::to_string_pretty(&entity.result_examples)
+            .expect("Result examples must be serializable"),
+    );
+    markdown.push_str("\n```\n");
+}
+
+fn parser_name(entity: &EntityDescription) -> &'static str {
+    if entity.gazetteer {
+        "Gazetteer"
+    } else if entity.grammar {
+        "Grammar"
+    } else {
+        "None (value type only)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::catalog::complete_entity_ontology;
+
+    #[test]
+    fn test_render_markdown() {
+        // Given
+        let ontology = complete_entity_ontology();
+
+        // When
+        let markdown = render_markdown(&ontology);
+
+        // Then
+        assert!(markdown.starts_with("# Builtin entities\n"));
+        assert!(markdown.contains(
+            "| [AmountOfMoney](#amountofmoney) | `snips/amountOfMoney` | Quantity | Grammar |\n"
+        ));
+        assert!(markdown.contains("| [City](#city) | `snips/city` | Geographical | Gazetteer |\n"));
+        assert!(markdown.contains(
+            "| [Version](#version) | `snips/version` | Code | None (value type only) |\n"
+        ));
+        assert!(markdown.contains("## Temperature\n\nIdentifier: `snips/temperature`\n"));
+        assert_eq!(ontology.entities.len(), markdown.matches("\n## ").count());
+    }
+}
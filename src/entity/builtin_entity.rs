@@ -1,12 +1,13 @@
 use crate::enum_kind;
 use crate::errors::*;
 use crate::ontology::*;
-use failure::format_err;
+use crate::prelude::*;
+use core::cmp::Ordering;
+use core::ops::Range;
 use serde::Deserialize;
-use serde_json;
-use std::ops::Range;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct BuiltinEntity {
     pub value: String,
     pub range: Range<usize>,
@@ -14,15 +15,102 @@ pub struct BuiltinEntity {
     pub alternatives: Vec<SlotValue>,
     #[serde(
         serialize_with = "serialize_builtin_entity_kind",
-        deserialize_with = "deserialize_builtin_entity_kind"
+        deserialize_with = "deserialize_builtin_entity_kind",
+        alias = "entityKind"
     )]
     pub entity_kind: BuiltinEntityKind,
+    /// Whether the entity is explicitly negated, as in "not at 5 pm", see `context::is_negated`
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    pub negated: bool,
+}
+
+impl BuiltinEntity {
+    /// Builds a builtin entity without alternatives, which can be added using `with_alternatives`
+    pub fn new(
+        value: String,
+        range: Range<usize>,
+        entity: SlotValue,
+        entity_kind: BuiltinEntityKind,
+    ) -> Self {
+        Self {
+            value,
+            range,
+            entity,
+            alternatives: vec![],
+            entity_kind,
+            negated: false,
+        }
+    }
+
+    pub fn with_alternatives(self, alternatives: Vec<SlotValue>) -> Self {
+        Self {
+            alternatives,
+            ..self
+        }
+    }
+
+    pub fn with_negated(self, negated: bool) -> Self {
+        Self { negated, ..self }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    pub fn entity(&self) -> &SlotValue {
+        &self.entity
+    }
+
+    pub fn alternatives(&self) -> &[SlotValue] {
+        &self.alternatives
+    }
+
+    pub fn entity_kind(&self) -> BuiltinEntityKind {
+        self.entity_kind
+    }
+
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Canonical ordering of builtin entities, which parsers must use to sort their output
+    ///
+    /// Entities are sorted by range start, then by range length, then by entity kind identifier.
+    /// Remaining ties, such as two readings of the same datetime, are broken on the JSON
+    /// serialization of the entities so that the order never depends on the parser output order.
+    pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+        self.range
+            .start
+            .cmp(&other.range.start)
+            .then_with(|| self.range.len().cmp(&other.range.len()))
+            .then_with(|| {
+                self.entity_kind
+                    .identifier()
+                    .cmp(other.entity_kind.identifier())
+            })
+            .then_with(|| {
+                serde_json::to_string(self)
+                    .ok()
+                    .cmp(&serde_json::to_string(other).ok())
+            })
+    }
+}
+
+/// Sorts builtin entities according to `BuiltinEntity::canonical_cmp`
+///
+/// The sort is stable, so entities which compare equal keep their relative order.
+pub fn sort_builtin_entities(entities: &mut [BuiltinEntity]) {
+    entities.sort_by(|a, b| a.canonical_cmp(b))
 }
 
 fn serialize_builtin_entity_kind<S>(
     value: &BuiltinEntityKind,
     serializer: S,
-) -> ::std::result::Result<S::Ok, S::Error>
+) -> ::core::result::Result<S::Ok, S::Error>
 where
     S: ::serde::Serializer,
 {
@@ -31,7 +119,7 @@ where
 
 fn deserialize_builtin_entity_kind<'de, D>(
     deserializer: D,
-) -> ::std::result::Result<BuiltinEntityKind, D::Error>
+) -> ::core::result::Result<BuiltinEntityKind, D::Error>
 where
     D: ::serde::Deserializer<'de>,
 {
@@ -58,7 +146,24 @@ enum_kind!(
         MusicTrack,
         City,
         Country,
-        Region
+        Region,
+        VolumeLevel,
+        Brightness,
+        RelativeChange,
+        AlphanumericCode,
+        FlightNumber,
+        ProductCode,
+        IpAddress,
+        Hostname,
+        Hashtag,
+        Mention,
+        Version,
+        MediaPosition,
+        Score,
+        CookingQuantity,
+        BloodPressure,
+        Dosage,
+        GeoCoordinate
     ]
 );
 
@@ -73,9 +178,13 @@ pub trait IntoBuiltinEntityKind: Copy {
         self.into_builtin_kind().description()
     }
 
-    fn result_description(&self) -> String {
+    fn result_description(&self) -> Vec<SlotValue> {
         self.into_builtin_kind().result_description()
     }
+
+    fn result_description_json(&self) -> String {
+        self.into_builtin_kind().result_description_json()
+    }
 }
 
 impl BuiltinEntityKind {
@@ -98,6 +207,23 @@ impl BuiltinEntityKind {
             BuiltinEntityKind::City => "snips/city",
             BuiltinEntityKind::Country => "snips/country",
             BuiltinEntityKind::Region => "snips/region",
+            BuiltinEntityKind::VolumeLevel => "snips/volumeLevel",
+            BuiltinEntityKind::Brightness => "snips/brightness",
+            BuiltinEntityKind::RelativeChange => "snips/relativeChange",
+            BuiltinEntityKind::AlphanumericCode => "snips/alphanumericCode",
+            BuiltinEntityKind::FlightNumber => "snips/flightNumber",
+            BuiltinEntityKind::ProductCode => "snips/productCode",
+            BuiltinEntityKind::IpAddress => "snips/ipAddress",
+            BuiltinEntityKind::Hostname => "snips/hostname",
+            BuiltinEntityKind::Hashtag => "snips/hashtag",
+            BuiltinEntityKind::Mention => "snips/mention",
+            BuiltinEntityKind::Version => "snips/version",
+            BuiltinEntityKind::MediaPosition => "snips/mediaPosition",
+            BuiltinEntityKind::Score => "snips/score",
+            BuiltinEntityKind::CookingQuantity => "snips/cookingQuantity",
+            BuiltinEntityKind::BloodPressure => "snips/bloodPressure",
+            BuiltinEntityKind::Dosage => "snips/dosage",
+            BuiltinEntityKind::GeoCoordinate => "snips/geoCoordinate",
         }
     }
 
@@ -110,6 +236,59 @@ impl BuiltinEntityKind {
     }
 }
 
+impl BuiltinEntityKind {
+    /// Stable numeric code of the entity kind, to be used in binary protocols
+    ///
+    /// Codes are never reassigned: new kinds get new codes, regardless of their position in the
+    /// enum.
+    pub fn to_code(&self) -> u32 {
+        match *self {
+            BuiltinEntityKind::AmountOfMoney => 0,
+            BuiltinEntityKind::Duration => 1,
+            BuiltinEntityKind::Number => 2,
+            BuiltinEntityKind::Ordinal => 3,
+            BuiltinEntityKind::Temperature => 4,
+            BuiltinEntityKind::Datetime => 5,
+            BuiltinEntityKind::Date => 6,
+            BuiltinEntityKind::Time => 7,
+            BuiltinEntityKind::DatePeriod => 8,
+            BuiltinEntityKind::TimePeriod => 9,
+            BuiltinEntityKind::Percentage => 10,
+            BuiltinEntityKind::MusicAlbum => 11,
+            BuiltinEntityKind::MusicArtist => 12,
+            BuiltinEntityKind::MusicTrack => 13,
+            BuiltinEntityKind::City => 14,
+            BuiltinEntityKind::Country => 15,
+            BuiltinEntityKind::Region => 16,
+            BuiltinEntityKind::VolumeLevel => 17,
+            BuiltinEntityKind::Brightness => 18,
+            BuiltinEntityKind::RelativeChange => 19,
+            BuiltinEntityKind::AlphanumericCode => 20,
+            BuiltinEntityKind::FlightNumber => 21,
+            BuiltinEntityKind::ProductCode => 22,
+            BuiltinEntityKind::IpAddress => 23,
+            BuiltinEntityKind::Hostname => 24,
+            BuiltinEntityKind::Hashtag => 25,
+            BuiltinEntityKind::Mention => 26,
+            BuiltinEntityKind::Version => 27,
+            BuiltinEntityKind::MediaPosition => 28,
+            BuiltinEntityKind::Score => 29,
+            BuiltinEntityKind::CookingQuantity => 30,
+            BuiltinEntityKind::BloodPressure => 31,
+            BuiltinEntityKind::Dosage => 32,
+            BuiltinEntityKind::GeoCoordinate => 33,
+        }
+    }
+
+    pub fn from_code(code: u32) -> Result<Self> {
+        BuiltinEntityKind::all()
+            .iter()
+            .find(|kind| kind.to_code() == code)
+            .cloned()
+            .ok_or_else(|| format_err!("Unknown EntityKind code: {}", code))
+    }
+}
+
 impl BuiltinEntityKind {
     pub fn description(&self) -> &'static str {
         match *self {
@@ -136,22 +315,184 @@ impl BuiltinEntityKind {
             BuiltinEntityKind::City => "Matches main local and world cities",
             BuiltinEntityKind::Country => "Matches countries around the world",
             BuiltinEntityKind::Region => "Matches local administrative regions",
+            BuiltinEntityKind::VolumeLevel => {
+                "Matches a volume level such as \"max\" or \"halfway\", resolved as a percentage \
+                 between 0 and 100"
+            }
+            BuiltinEntityKind::Brightness => {
+                "Matches a brightness level such as \"max\" or \"halfway\", resolved as a \
+                 percentage between 0 and 100"
+            }
+            BuiltinEntityKind::RelativeChange => {
+                "Matches a change relative to the current value of a quantity, such as \
+                 \"increase by 3\" or \"a bit warmer\""
+            }
+            BuiltinEntityKind::AlphanumericCode => {
+                "Matches a code made of letters and digits, such as a license plate, and resolves \
+                 it to its uppercase letters and digits"
+            }
+            BuiltinEntityKind::FlightNumber => {
+                "Matches a flight number, such as \"flight BA249\", and resolves the airline code \
+                 and the flight number"
+            }
+            BuiltinEntityKind::ProductCode => {
+                "Matches an ISBN or EAN product code, whose check digit must be valid"
+            }
+            BuiltinEntityKind::IpAddress => {
+                "Matches an IPv4 or IPv6 address, and resolves it to its normalized written form"
+            }
+            BuiltinEntityKind::Hostname => {
+                "Matches a host name, such as \"example dot com\", and resolves it to its \
+                 lowercase written form"
+            }
+            BuiltinEntityKind::Hashtag => {
+                "Matches a typed or spoken hashtag, such as \"hashtag good vibes\", and resolves \
+                 it to its written form"
+            }
+            BuiltinEntityKind::Mention => {
+                "Matches a typed or spoken mention of a user, such as \"at snips\", and resolves \
+                 it to its written form"
+            }
+            BuiltinEntityKind::Version => {
+                "Matches a version number, such as \"version two point three point one\" or \
+                 \"v2.3.1\", and resolves it to its major, minor and patch components"
+            }
+            BuiltinEntityKind::MediaPosition => {
+                "Matches a position within a media, such as \"skip to one hour twenty\", or an \
+                 offset from the current position, such as \"go back 30 seconds\""
+            }
+            BuiltinEntityKind::Score => {
+                "Matches the score of a match, such as \"three two\" or \"2-1\", and resolves it \
+                 to the pair of scores in the order in which they were said"
+            }
+            BuiltinEntityKind::CookingQuantity => {
+                "Matches a quantity of an ingredient, such as \"two tablespoons\" or \"a pinch\", \
+                 and resolves it along with its metric equivalent"
+            }
+            BuiltinEntityKind::BloodPressure => {
+                "Matches a blood pressure, such as \"one twenty over eighty\", and resolves it to \
+                 its systolic and diastolic pressures"
+            }
+            BuiltinEntityKind::Dosage => {
+                "Matches a dose of a medication, such as \"two 500 milligram tablets\", and \
+                 resolves it to the number of units taken, the dose per unit and the form of the \
+                 medication"
+            }
+            BuiltinEntityKind::GeoCoordinate => {
+                "Matches geographic coordinates, such as \"forty eight point eight north, two \
+                 point three east\" or \"48°51'24\"N 2°21'8\"E\", and resolves them to a latitude \
+                 and a longitude in decimal degrees"
+            }
+        }
+    }
+}
+
+/// Semantic family of builtin entity kinds
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Hash, Eq)]
+pub enum EntityKindGroup {
+    /// Plain numbers: cardinals, ordinals and percentages
+    Numeric,
+    /// Dates, times, periods and durations
+    Temporal,
+    /// Numbers associated with a unit, such as amounts of money or temperatures
+    Quantity,
+    /// Music albums, artists and tracks
+    Music,
+    /// Cities, countries and regions
+    Geographical,
+    /// Codes and identifiers, such as license plates or IP addresses
+    Code,
+}
+
+impl EntityKindGroup {
+    pub fn all() -> &'static [EntityKindGroup] {
+        static ALL: &[EntityKindGroup] = &[
+            EntityKindGroup::Numeric,
+            EntityKindGroup::Temporal,
+            EntityKindGroup::Quantity,
+            EntityKindGroup::Music,
+            EntityKindGroup::Geographical,
+            EntityKindGroup::Code,
+        ];
+        ALL
+    }
+
+    pub fn kinds(&self) -> Vec<BuiltinEntityKind> {
+        BuiltinEntityKind::all()
+            .iter()
+            .filter(|kind| kind.group() == *self)
+            .cloned()
+            .collect()
+    }
+}
+
+impl BuiltinEntityKind {
+    pub fn group(&self) -> EntityKindGroup {
+        match *self {
+            BuiltinEntityKind::Number => EntityKindGroup::Numeric,
+            BuiltinEntityKind::Ordinal => EntityKindGroup::Numeric,
+            BuiltinEntityKind::Percentage => EntityKindGroup::Numeric,
+            BuiltinEntityKind::Duration => EntityKindGroup::Temporal,
+            BuiltinEntityKind::Datetime => EntityKindGroup::Temporal,
+            BuiltinEntityKind::Date => EntityKindGroup::Temporal,
+            BuiltinEntityKind::Time => EntityKindGroup::Temporal,
+            BuiltinEntityKind::DatePeriod => EntityKindGroup::Temporal,
+            BuiltinEntityKind::TimePeriod => EntityKindGroup::Temporal,
+            BuiltinEntityKind::AmountOfMoney => EntityKindGroup::Quantity,
+            BuiltinEntityKind::Temperature => EntityKindGroup::Quantity,
+            BuiltinEntityKind::MusicAlbum => EntityKindGroup::Music,
+            BuiltinEntityKind::MusicArtist => EntityKindGroup::Music,
+            BuiltinEntityKind::MusicTrack => EntityKindGroup::Music,
+            BuiltinEntityKind::City => EntityKindGroup::Geographical,
+            BuiltinEntityKind::Country => EntityKindGroup::Geographical,
+            BuiltinEntityKind::Region => EntityKindGroup::Geographical,
+            BuiltinEntityKind::VolumeLevel => EntityKindGroup::Numeric,
+            BuiltinEntityKind::Brightness => EntityKindGroup::Numeric,
+            BuiltinEntityKind::RelativeChange => EntityKindGroup::Quantity,
+            BuiltinEntityKind::AlphanumericCode => EntityKindGroup::Code,
+            BuiltinEntityKind::FlightNumber => EntityKindGroup::Code,
+            BuiltinEntityKind::ProductCode => EntityKindGroup::Code,
+            BuiltinEntityKind::IpAddress => EntityKindGroup::Code,
+            BuiltinEntityKind::Hostname => EntityKindGroup::Code,
+            BuiltinEntityKind::Hashtag => EntityKindGroup::Code,
+            BuiltinEntityKind::Mention => EntityKindGroup::Code,
+            BuiltinEntityKind::Version => EntityKindGroup::Code,
+            BuiltinEntityKind::MediaPosition => EntityKindGroup::Temporal,
+            BuiltinEntityKind::Score => EntityKindGroup::Numeric,
+            BuiltinEntityKind::CookingQuantity => EntityKindGroup::Quantity,
+            BuiltinEntityKind::BloodPressure => EntityKindGroup::Quantity,
+            BuiltinEntityKind::Dosage => EntityKindGroup::Quantity,
+            BuiltinEntityKind::GeoCoordinate => EntityKindGroup::Geographical,
         }
     }
+
+    pub fn is_numeric(&self) -> bool {
+        self.group() == EntityKindGroup::Numeric
+    }
+
+    pub fn is_temporal(&self) -> bool {
+        self.group() == EntityKindGroup::Temporal
+    }
+
+    pub fn is_quantity(&self) -> bool {
+        self.group() == EntityKindGroup::Quantity
+    }
 }
 
 impl BuiltinEntityKind {
-    pub fn result_description(&self) -> String {
+    /// Examples of values to which the entity resolves, describing the schema of the results
+    pub fn result_description(&self) -> Vec<SlotValue> {
         match *self {
             BuiltinEntityKind::AmountOfMoney => {
-                serde_json::to_string_pretty(&vec![SlotValue::AmountOfMoney(AmountOfMoneyValue {
+                vec![SlotValue::AmountOfMoney(AmountOfMoneyValue {
                     value: 10.05,
                     precision: Precision::Approximate,
                     unit: Some("€".to_string()),
-                })])
+                    minor_units: 1005,
+                })]
             }
             BuiltinEntityKind::Duration => {
-                serde_json::to_string_pretty(&vec![SlotValue::Duration(DurationValue {
+                vec![SlotValue::Duration(DurationValue {
                     years: 0,
                     quarters: 0,
                     months: 3,
@@ -161,25 +502,34 @@ impl BuiltinEntityKind {
                     minutes: 0,
                     seconds: 0,
                     precision: Precision::Exact,
-                })])
+                })]
             }
             BuiltinEntityKind::Number => {
-                serde_json::to_string_pretty(&vec![SlotValue::Number(NumberValue { value: 42. })])
+                vec![SlotValue::Number(NumberValue { value: 42. })]
             }
-            BuiltinEntityKind::Ordinal => {
-                serde_json::to_string_pretty(&vec![SlotValue::Ordinal(OrdinalValue { value: 2 })])
-            }
-            BuiltinEntityKind::Temperature => serde_json::to_string_pretty(&vec![
+            BuiltinEntityKind::Ordinal => vec![
+                SlotValue::Ordinal(OrdinalValue {
+                    value: 2,
+                    direction: OrdinalDirection::FromStart,
+                }),
+                SlotValue::Ordinal(OrdinalValue {
+                    value: 1,
+                    direction: OrdinalDirection::FromEnd,
+                }),
+            ],
+            BuiltinEntityKind::Temperature => vec![
                 SlotValue::Temperature(TemperatureValue {
                     value: 23.0,
                     unit: Some("celsius".to_string()),
+                    mode: TemperatureMode::Absolute,
                 }),
                 SlotValue::Temperature(TemperatureValue {
                     value: 60.0,
                     unit: Some("fahrenheit".to_string()),
+                    mode: TemperatureMode::Absolute,
                 }),
-            ]),
-            BuiltinEntityKind::Datetime => serde_json::to_string_pretty(&vec![
+            ],
+            BuiltinEntityKind::Datetime => vec![
                 SlotValue::InstantTime(InstantTimeValue {
                     value: "2017-06-13 18:00:00 +02:00".to_string(),
                     grain: Grain::Hour,
@@ -188,71 +538,162 @@ impl BuiltinEntityKind {
                 SlotValue::TimeInterval(TimeIntervalValue {
                     from: Some("2017-06-07 18:00:00 +02:00".to_string()),
                     to: Some("2017-06-08 00:00:00 +02:00".to_string()),
+                    to_inclusive: false,
                 }),
-            ]),
+            ],
             BuiltinEntityKind::Date => {
-                serde_json::to_string_pretty(&vec![SlotValue::InstantTime(InstantTimeValue {
+                vec![SlotValue::InstantTime(InstantTimeValue {
                     value: "2017-06-13 00:00:00 +02:00".to_string(),
                     grain: Grain::Day,
                     precision: Precision::Exact,
-                })])
+                })]
             }
             BuiltinEntityKind::Time => {
-                serde_json::to_string_pretty(&vec![SlotValue::InstantTime(InstantTimeValue {
+                vec![SlotValue::InstantTime(InstantTimeValue {
                     value: "2017-06-13 18:00:00 +02:00".to_string(),
                     grain: Grain::Hour,
                     precision: Precision::Exact,
-                })])
+                })]
             }
             BuiltinEntityKind::DatePeriod => {
-                serde_json::to_string_pretty(&vec![SlotValue::TimeInterval(TimeIntervalValue {
+                vec![SlotValue::TimeInterval(TimeIntervalValue {
                     from: Some("2017-06-07 00:00:00 +02:00".to_string()),
                     to: Some("2017-06-09 00:00:00 +02:00".to_string()),
-                })])
+                    to_inclusive: false,
+                })]
             }
             BuiltinEntityKind::TimePeriod => {
-                serde_json::to_string_pretty(&vec![SlotValue::TimeInterval(TimeIntervalValue {
+                vec![SlotValue::TimeInterval(TimeIntervalValue {
                     from: Some("2017-06-07 18:00:00 +02:00".to_string()),
                     to: Some("2017-06-07 20:00:00 +02:00".to_string()),
-                })])
+                    to_inclusive: false,
+                })]
             }
             BuiltinEntityKind::Percentage => {
-                serde_json::to_string_pretty(&vec![SlotValue::Percentage(PercentageValue {
-                    value: 20.,
-                })])
+                vec![SlotValue::Percentage(PercentageValue { value: 20. })]
             }
             BuiltinEntityKind::MusicAlbum => {
-                serde_json::to_string_pretty(&vec![SlotValue::MusicAlbum(StringValue {
-                    value: "Discovery".to_string(),
-                })])
+                vec![SlotValue::MusicAlbum(StringValue::from("Discovery"))]
             }
             BuiltinEntityKind::MusicArtist => {
-                serde_json::to_string_pretty(&vec![SlotValue::MusicArtist(StringValue {
-                    value: "Daft Punk".to_string(),
-                })])
+                vec![SlotValue::MusicArtist(StringValue::from("Daft Punk"))]
             }
             BuiltinEntityKind::MusicTrack => {
-                serde_json::to_string_pretty(&vec![SlotValue::MusicTrack(StringValue {
-                    value: "Harder Better Faster Stronger".to_string(),
-                })])
+                vec![SlotValue::MusicTrack(StringValue::from(
+                    "Harder Better Faster Stronger",
+                ))]
             }
             BuiltinEntityKind::City => {
-                serde_json::to_string_pretty(&vec![SlotValue::City(StringValue {
-                    value: "Paris".to_string(),
-                })])
+                vec![SlotValue::City(StringValue::from("Paris"))]
             }
             BuiltinEntityKind::Country => {
-                serde_json::to_string_pretty(&vec![SlotValue::Country(StringValue {
-                    value: "France".to_string(),
-                })])
+                vec![SlotValue::Country(StringValue::from("France"))]
+            }
+            BuiltinEntityKind::Region => vec![SlotValue::Region(StringValue::from("California"))],
+            BuiltinEntityKind::VolumeLevel => vec![
+                SlotValue::Percentage(PercentageValue { value: 50. }),
+                SlotValue::Percentage(PercentageValue { value: 100. }),
+            ],
+            BuiltinEntityKind::Brightness => vec![
+                SlotValue::Percentage(PercentageValue { value: 50. }),
+                SlotValue::Percentage(PercentageValue { value: 100. }),
+            ],
+            BuiltinEntityKind::RelativeChange => vec![
+                SlotValue::RelativeChange(RelativeChangeValue {
+                    direction: ChangeDirection::Increase,
+                    value: Some(3.),
+                    unit: None,
+                    magnitude: None,
+                }),
+                SlotValue::RelativeChange(RelativeChangeValue {
+                    direction: ChangeDirection::Decrease,
+                    value: Some(2.),
+                    unit: Some("degree".to_string()),
+                    magnitude: None,
+                }),
+                SlotValue::RelativeChange(RelativeChangeValue {
+                    direction: ChangeDirection::Increase,
+                    value: None,
+                    unit: None,
+                    magnitude: Some(ChangeMagnitude::Slight),
+                }),
+            ],
+            BuiltinEntityKind::AlphanumericCode => vec![
+                SlotValue::AlphanumericCode(StringValue::from("AB123CD")),
+                SlotValue::AlphanumericCode(StringValue::from("X7K2")),
+            ],
+            BuiltinEntityKind::FlightNumber => vec![SlotValue::FlightNumber(FlightNumberValue {
+                carrier: "BA".to_string(),
+                number: 249,
+            })],
+            BuiltinEntityKind::ProductCode => vec![
+                SlotValue::ProductCode(ProductCodeValue {
+                    format: ProductCodeFormat::Isbn13,
+                    value: "9782070368228".to_string(),
+                }),
+                SlotValue::ProductCode(ProductCodeValue {
+                    format: ProductCodeFormat::Ean13,
+                    value: "4006381333931".to_string(),
+                }),
+            ],
+            BuiltinEntityKind::IpAddress => vec![
+                SlotValue::IpAddress(StringValue::from("192.168.1.10")),
+                SlotValue::IpAddress(StringValue::from("2001:db8::1")),
+            ],
+            BuiltinEntityKind::Hostname => vec![SlotValue::Hostname(StringValue::from("snips.ai"))],
+            BuiltinEntityKind::Hashtag => vec![SlotValue::Hashtag(StringValue::from("#goodvibes"))],
+            BuiltinEntityKind::Mention => vec![SlotValue::Mention(StringValue::from("@snips"))],
+            BuiltinEntityKind::Version => vec![SlotValue::Version(VersionValue {
+                major: 2,
+                minor: 3,
+                patch: 1,
+            })],
+            BuiltinEntityKind::MediaPosition => vec![
+                SlotValue::MediaPosition(MediaPositionValue {
+                    mode: MediaPositionMode::Absolute,
+                    seconds: 4800,
+                }),
+                SlotValue::MediaPosition(MediaPositionValue {
+                    mode: MediaPositionMode::Offset,
+                    seconds: -30,
+                }),
+            ],
+            BuiltinEntityKind::Score => vec![SlotValue::Score(ScoreValue {
+                first: 2,
+                second: 1,
+            })],
+            BuiltinEntityKind::CookingQuantity => {
+                vec![SlotValue::CookingQuantity(CookingQuantityValue {
+                    value: 2.,
+                    unit: CookingUnit::Tablespoon,
+                    metric_value: 30.,
+                    metric_unit: CookingMetricUnit::Milliliter,
+                })]
             }
-            BuiltinEntityKind::Region => {
-                serde_json::to_string_pretty(&vec![SlotValue::Region(StringValue {
-                    value: "California".to_string(),
-                })])
+            BuiltinEntityKind::BloodPressure => {
+                vec![SlotValue::BloodPressure(BloodPressureValue {
+                    systolic: 120,
+                    diastolic: 80,
+                })]
+            }
+            BuiltinEntityKind::Dosage => vec![SlotValue::Dosage(DosageValue {
+                count: 2.,
+                dose: Some(500.),
+                unit: Some("mg".to_string()),
+                form: Some("tablet".to_string()),
+            })],
+            BuiltinEntityKind::GeoCoordinate => {
+                vec![SlotValue::GeoCoordinate(GeoCoordinateValue {
+                    latitude: 48.8566,
+                    longitude: 2.3522,
+                })]
             }
         }
-        .unwrap()
+    }
+
+    /// JSON array of the values returned by `result_description`
+    pub fn result_description_json(&self) -> String {
+        serde_json::to_string_pretty(&self.result_description()).unwrap()
     }
 }
 
@@ -265,11 +706,113 @@ mod tests {
     fn test_result_descriptions() {
         // Given
         let description = BuiltinEntityKind::Percentage.result_description();
+        let json_description = BuiltinEntityKind::Percentage.result_description_json();
 
         // When/Then
-        let expected_description =
+        assert_eq!(
+            vec![SlotValue::Percentage(PercentageValue { value: 20. })],
+            description
+        );
+        let expected_json_description =
             "[\n  {\n    \"kind\": \"Percentage\",\n    \"value\": 20.0\n  }\n]";
-        assert_eq!(expected_description, description);
+        assert_eq!(expected_json_description, json_description);
+    }
+
+    #[test]
+    fn test_entity_kind_codes_round_trip() {
+        for kind in BuiltinEntityKind::all() {
+            assert_eq!(*kind, BuiltinEntityKind::from_code(kind.to_code()).unwrap());
+        }
+        assert!(BuiltinEntityKind::from_code(1000).is_err());
+    }
+
+    #[test]
+    fn test_entity_kind_groups() {
+        assert!(BuiltinEntityKind::Ordinal.is_numeric());
+        assert!(BuiltinEntityKind::DatePeriod.is_temporal());
+        assert!(BuiltinEntityKind::Temperature.is_quantity());
+        assert!(!BuiltinEntityKind::City.is_numeric());
+        assert_eq!(
+            vec![
+                BuiltinEntityKind::AmountOfMoney,
+                BuiltinEntityKind::Temperature,
+                BuiltinEntityKind::RelativeChange,
+                BuiltinEntityKind::CookingQuantity,
+                BuiltinEntityKind::BloodPressure,
+                BuiltinEntityKind::Dosage,
+            ],
+            EntityKindGroup::Quantity.kinds()
+        );
+        let nb_grouped_kinds: usize = EntityKindGroup::all()
+            .iter()
+            .map(|group| group.kinds().len())
+            .sum();
+        assert_eq!(BuiltinEntityKind::all().len(), nb_grouped_kinds);
+    }
+
+    #[test]
+    fn test_sort_builtin_entities() {
+        // Given
+        let entity = |range: Range<usize>, entity_kind: BuiltinEntityKind| BuiltinEntity {
+            value: "".to_string(),
+            range,
+            entity: SlotValue::Number(NumberValue { value: 1.0 }),
+            alternatives: vec![],
+            entity_kind,
+            negated: false,
+        };
+        let mut entities = vec![
+            entity(5..9, BuiltinEntityKind::Number),
+            entity(0..4, BuiltinEntityKind::Number),
+            entity(0..4, BuiltinEntityKind::Duration),
+            entity(0..2, BuiltinEntityKind::Ordinal),
+        ];
+
+        // When
+        sort_builtin_entities(&mut entities);
+
+        // Then
+        let expected_entities = vec![
+            entity(0..2, BuiltinEntityKind::Ordinal),
+            entity(0..4, BuiltinEntityKind::Duration),
+            entity(0..4, BuiltinEntityKind::Number),
+            entity(5..9, BuiltinEntityKind::Number),
+        ];
+        assert_eq!(expected_entities, entities);
+    }
+
+    #[test]
+    fn test_sort_builtin_entities_with_same_range_and_kind() {
+        // Given
+        let entity = |value: &str| {
+            BuiltinEntity::new(
+                "tomorrow".to_string(),
+                0..8,
+                SlotValue::InstantTime(InstantTimeValue {
+                    value: value.to_string(),
+                    grain: Grain::Day,
+                    precision: Precision::Exact,
+                }),
+                BuiltinEntityKind::Datetime,
+            )
+        };
+        let mut entities = vec![
+            entity("2017-06-14 00:00:00 +02:00"),
+            entity("2017-06-13 00:00:00 +02:00"),
+        ];
+        let mut reversed_entities = entities.iter().cloned().rev().collect::<Vec<_>>();
+
+        // When
+        sort_builtin_entities(&mut entities);
+        sort_builtin_entities(&mut reversed_entities);
+
+        // Then
+        let expected_entities = vec![
+            entity("2017-06-13 00:00:00 +02:00"),
+            entity("2017-06-14 00:00:00 +02:00"),
+        ];
+        assert_eq!(expected_entities, entities);
+        assert_eq!(expected_entities, reversed_entities);
     }
 
     #[test]
@@ -288,6 +831,7 @@ mod tests {
                 precision: Precision::Exact,
             })],
             entity_kind: BuiltinEntityKind::Datetime,
+            negated: false,
         };
 
         assert_tokens(
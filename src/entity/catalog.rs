@@ -0,0 +1,84 @@
+use crate::entity::builtin_entity::{BuiltinEntityKind, EntityKindGroup};
+use crate::entity::gazetteer_entity::TryIntoBuiltinGazetteerEntityKind;
+use crate::entity::grammar_entity::TryIntoGrammarEntityKind;
+use crate::ontology::SlotValue;
+
+/// Machine-readable description of all the builtin entities, serializable as a single JSON
+/// document
+///
+/// The languages supported by each entity depend on the parser, and are therefore not part of
+/// the catalog.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityOntology {
+    pub ontology_version: String,
+    pub entities: Vec<EntityDescription>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityDescription {
+    /// Identifier of the entity, e.g. "snips/amountOfMoney"
+    pub identifier: String,
+    /// Name of the entity kind, e.g. "AmountOfMoney"
+    pub name: String,
+    pub description: String,
+    pub group: EntityKindGroup,
+    /// Whether the entity is resolved using a grammar
+    pub grammar: bool,
+    /// Whether the entity is resolved using a gazetteer
+    ///
+    /// Entities which are neither resolved by a grammar nor by a gazetteer are value types only,
+    /// whose values are part of the ontology but which no parser extracts yet.
+    pub gazetteer: bool,
+    /// Examples of resolved values, describing the schema of the results
+    pub result_examples: Vec<SlotValue>,
+}
+
+impl EntityDescription {
+    pub fn from_kind(kind: BuiltinEntityKind) -> Self {
+        Self {
+            identifier: kind.identifier().to_string(),
+            name: kind.to_string(),
+            description: kind.description().to_string(),
+            group: kind.group(),
+            grammar: kind.try_into_grammar_kind().is_ok(),
+            gazetteer: kind.try_into_gazetteer_kind().is_ok(),
+            result_examples: kind.result_description(),
+        }
+    }
+}
+
+pub fn complete_entity_ontology() -> EntityOntology {
+    EntityOntology {
+        ontology_version: env!("CARGO_PKG_VERSION").to_string(),
+        entities: BuiltinEntityKind::all()
+            .iter()
+            .map(|kind| EntityDescription::from_kind(*kind))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_complete_entity_ontology() {
+        // When
+        let ontology = complete_entity_ontology();
+        let json = serde_json::to_value(&ontology).unwrap();
+
+        // Then
+        assert_eq!(BuiltinEntityKind::all().len(), ontology.entities.len());
+        assert_eq!("snips/amountOfMoney", json["entities"][0]["identifier"]);
+        assert_eq!("Quantity", json["entities"][0]["group"]);
+        assert_eq!(true, json["entities"][0]["grammar"]);
+        assert_eq!(false, json["entities"][0]["gazetteer"]);
+        assert_eq!(
+            "AmountOfMoney",
+            json["entities"][0]["resultExamples"][0]["kind"]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Linking of builtin entities which together form a compound expression
+//!
+//! Entity ranges are expressed in characters, not bytes.
+use crate::annotation::char_range_to_byte_range;
+use crate::entity::builtin_entity::BuiltinEntity;
+use crate::errors::*;
+use crate::language::Language;
+use crate::ontology::*;
+use std::ops::Range;
+
+/// Percentage applied to a quantity, e.g. "20% of 50 euros"
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentageOfQuantity<'a> {
+    pub percentage: &'a BuiltinEntity,
+    pub quantity: &'a BuiltinEntity,
+    /// Range covering both entities and the words linking them
+    pub range: Range<usize>,
+}
+
+impl<'a> PercentageOfQuantity<'a> {
+    /// Resolves the compound expression to the resulting quantity, e.g. 10 euros for
+    /// "20% of 50 euros"
+    ///
+    /// `None` is returned when the quantity is neither a number nor an amount of money.
+    pub fn resolve(&self) -> Option<SlotValue> {
+        let ratio = self.percentage.entity.as_percentage()?.value / 100.;
+        match &self.quantity.entity {
+            SlotValue::Number(number) => Some(SlotValue::Number(NumberValue {
+                value: number.value * ratio,
+            })),
+            SlotValue::AmountOfMoney(amount) => Some(SlotValue::AmountOfMoney(
+                AmountOfMoneyValue::from_minor_units(
+                    (amount.minor_units as f64 * ratio).round() as i64,
+                    amount.precision,
+                    amount.unit.clone(),
+                ),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Finds the percentages applied to a number or an amount of money within the text
+///
+/// A percentage and a quantity are linked when they are only separated by a word such as "of",
+/// or by the genitive particle in Japanese and Korean, in which case the quantity comes first.
+pub fn percentages_of_quantities<'a>(
+    text: &str,
+    entities: &'a [BuiltinEntity],
+    language: Language,
+) -> Result<Vec<PercentageOfQuantity<'a>>> {
+    let percentages = entities
+        .iter()
+        .filter(|e| e.entity.as_percentage().is_some());
+    let quantities = entities
+        .iter()
+        .filter(|e| matches!(e.entity, SlotValue::Number(_) | SlotValue::AmountOfMoney(_)));
+    let quantity_first = matches!(language, Language::JA | Language::KO);
+    let mut compounds = vec![];
+    for percentage in percentages {
+        for quantity in quantities.clone() {
+            let (first, second) = if quantity_first {
+                (quantity, percentage)
+            } else {
+                (percentage, quantity)
+            };
+            if first.range.end > second.range.start {
+                continue;
+            }
+            let byte_range =
+                char_range_to_byte_range(text, &(first.range.end..second.range.start))?;
+            if is_connector(text[byte_range].trim(), language) {
+                compounds.push(PercentageOfQuantity {
+                    percentage,
+                    quantity,
+                    range: first.range.start..second.range.end,
+                });
+            }
+        }
+    }
+    compounds.sort_by_key(|compound| compound.range.start);
+    Ok(compounds)
+}
+
+fn is_connector(words: &str, language: Language) -> bool {
+    let connectors: &[&str] = match language {
+        Language::DE => &["von", "vom", "der", "des"],
+        Language::EN => &["of"],
+        Language::ES => &["de", "del"],
+        Language::FR => &["de", "des", "du", "d'"],
+        Language::IT => &["di", "del", "dei", "della"],
+        Language::PT_PT | Language::PT_BR => &["de", "do", "dos", "da"],
+        Language::JA => &["の"],
+        Language::KO => &["의"],
+    };
+    connectors.contains(&words.to_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::builtin_entity::BuiltinEntityKind;
+
+    fn entity(
+        text: &str,
+        range: Range<usize>,
+        entity: SlotValue,
+        entity_kind: BuiltinEntityKind,
+    ) -> BuiltinEntity {
+        BuiltinEntity::new(
+            text.chars().skip(range.start).take(range.len()).collect(),
+            range,
+            entity,
+            entity_kind,
+        )
+    }
+
+    #[test]
+    fn test_percentages_of_quantities() {
+        // Given
+        let text = "add a tip of 15% of 42 euros";
+        let entities = vec![
+            entity(
+                text,
+                13..16,
+                SlotValue::Percentage(PercentageValue { value: 15. }),
+                BuiltinEntityKind::Percentage,
+            ),
+            entity(
+                text,
+                13..15,
+                SlotValue::Number(NumberValue { value: 15. }),
+                BuiltinEntityKind::Number,
+            ),
+            entity(
+                text,
+                20..28,
+                SlotValue::AmountOfMoney(AmountOfMoneyValue::new(42., "€")),
+                BuiltinEntityKind::AmountOfMoney,
+            ),
+        ];
+
+        // When
+        let compounds = percentages_of_quantities(text, &entities, Language::EN).unwrap();
+
+        // Then
+        assert_eq!(1, compounds.len());
+        assert_eq!(13..28, compounds[0].range);
+        assert_eq!(
+            Some(SlotValue::AmountOfMoney(AmountOfMoneyValue::new(6.3, "€"))),
+            compounds[0].resolve()
+        );
+    }
+
+    #[test]
+    fn test_percentages_of_quantities_in_japanese() {
+        // Given
+        let text = "50の20%";
+        let entities = vec![
+            entity(
+                text,
+                0..2,
+                SlotValue::Number(NumberValue { value: 50. }),
+                BuiltinEntityKind::Number,
+            ),
+            entity(
+                text,
+                3..6,
+                SlotValue::Percentage(PercentageValue { value: 20. }),
+                BuiltinEntityKind::Percentage,
+            ),
+        ];
+
+        // When
+        let compounds = percentages_of_quantities(text, &entities, Language::JA).unwrap();
+
+        // Then
+        assert_eq!(1, compounds.len());
+        assert_eq!(
+            Some(SlotValue::Number(NumberValue { value: 10. })),
+            compounds[0].resolve()
+        );
+    }
+}
@@ -1,7 +1,6 @@
 use crate::entity::builtin_entity::{BuiltinEntityKind, IntoBuiltinEntityKind};
 use crate::errors::*;
 use crate::sub_entity_kind;
-use failure::format_err;
 
 sub_entity_kind!(
     BuiltinGazetteerEntityKind,
@@ -1,8 +1,9 @@
 use crate::entity::builtin_entity::{BuiltinEntityKind, IntoBuiltinEntityKind};
 use crate::errors::*;
 use crate::sub_entity_kind;
-use failure::format_err;
 
+// Entity kinds which are part of the ontology but are not parsed by any grammar yet, such as
+// `VolumeLevel` or `GeoCoordinate`, are left out until their grammar ships
 sub_entity_kind!(
     GrammarEntityKind,
     [
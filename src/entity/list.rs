@@ -0,0 +1,192 @@
+//! Extraction of the items of enumerations, such as "milk, eggs and two apples"
+//!
+//! Entity ranges are expressed in characters, not bytes.
+use crate::entity::builtin_entity::BuiltinEntity;
+use crate::language::Language;
+use crate::ontology::SlotValue;
+use std::ops::Range;
+
+/// Item of an enumeration, e.g. "apples" in "milk, eggs and two apples"
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListItem<'a> {
+    pub value: String,
+    /// Range of the item, excluding its quantity
+    pub range: Range<usize>,
+    /// Number preceding the item, e.g. "two" in "two apples"
+    pub quantity: Option<&'a BuiltinEntity>,
+}
+
+/// Splits an enumeration into its items, which are separated by commas or by conjunctions such
+/// as "and", and attaches to each item the number entity it starts with
+///
+/// In Japanese and Korean, the conjunctive particles are matched within words, so this is only an
+/// approximation.
+pub fn parse_list<'a>(
+    text: &str,
+    entities: &'a [BuiltinEntity],
+    language: Language,
+) -> Vec<ListItem<'a>> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut separators = separator_ranges(&chars, language);
+    separators.push(chars.len()..chars.len());
+    let mut items = vec![];
+    let mut start = 0;
+    for separator in separators {
+        let range = trim_range(&chars, start..separator.start);
+        start = separator.end;
+        if range.is_empty() {
+            continue;
+        }
+        let quantity = entities.iter().find(|e| {
+            matches!(e.entity, SlotValue::Number(_))
+                && e.range.start == range.start
+                && e.range.end < range.end
+        });
+        let range = match quantity {
+            Some(quantity) => trim_range(&chars, quantity.range.end..range.end),
+            None => range,
+        };
+        items.push(ListItem {
+            value: chars[range.clone()].iter().collect(),
+            range,
+            quantity,
+        });
+    }
+    items
+}
+
+fn separator_ranges(chars: &[char], language: Language) -> Vec<Range<usize>> {
+    let conjunctions: &[&str] = match language {
+        Language::DE => &["und", "oder", "sowie"],
+        Language::EN => &["and", "or", "&"],
+        Language::ES => &["y", "e", "o"],
+        Language::FR => &["et", "ou"],
+        Language::IT => &["e", "ed", "o"],
+        Language::PT_PT | Language::PT_BR => &["e", "ou"],
+        Language::JA => &["と", "や"],
+        Language::KO => &["이랑", "하고", "랑"],
+    };
+    let mut separators = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        if is_punctuation_separator(chars[i]) {
+            separators.push(i..i + 1);
+            i += 1;
+            continue;
+        }
+        let separator = match language {
+            Language::JA | Language::KO => conjunctions
+                .iter()
+                .map(|conjunction| conjunction.chars().collect::<Vec<_>>())
+                .find(|conjunction| chars[i..].starts_with(conjunction))
+                .map(|conjunction| i..i + conjunction.len()),
+            _ => {
+                let word_end = (i..chars.len())
+                    .find(|j| is_word_boundary(chars[*j]))
+                    .unwrap_or(chars.len());
+                let is_word_start = i == 0 || is_word_boundary(chars[i - 1]);
+                let word = chars[i..word_end].iter().collect::<String>().to_lowercase();
+                if is_word_start && conjunctions.contains(&word.as_str()) {
+                    Some(i..word_end)
+                } else {
+                    None
+                }
+            }
+        };
+        match separator {
+            Some(separator) => {
+                i = separator.end;
+                separators.push(separator);
+            }
+            None => i += 1,
+        }
+    }
+    separators
+}
+
+fn is_punctuation_separator(c: char) -> bool {
+    c == ',' || c == ';' || c == '、' || c == '，'
+}
+
+fn is_word_boundary(c: char) -> bool {
+    c.is_whitespace() || is_punctuation_separator(c)
+}
+
+/// Shrinks the range so that it excludes the surrounding whitespaces and final punctuation
+fn trim_range(chars: &[char], range: Range<usize>) -> Range<usize> {
+    let is_trimmed = |c: char| c.is_whitespace() || c == '.' || c == '!' || c == '?' || c == '。';
+    let start = (range.start..range.end)
+        .find(|i| !is_trimmed(chars[*i]))
+        .unwrap_or(range.end);
+    let end = (start..range.end)
+        .rev()
+        .find(|i| !is_trimmed(chars[*i]))
+        .map(|i| i + 1)
+        .unwrap_or(start);
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::builtin_entity::BuiltinEntityKind;
+    use crate::ontology::NumberValue;
+
+    fn number(text: &str, range: Range<usize>, value: f64) -> BuiltinEntity {
+        BuiltinEntity::new(
+            text.chars().skip(range.start).take(range.len()).collect(),
+            range,
+            SlotValue::Number(NumberValue { value }),
+            BuiltinEntityKind::Number,
+        )
+    }
+
+    #[test]
+    fn test_parse_list() {
+        // Given
+        let text = "milk, eggs, and two apples.";
+        let entities = vec![number(text, 16..19, 2.)];
+
+        // When
+        let items = parse_list(text, &entities, Language::EN);
+
+        // Then
+        let values = items
+            .iter()
+            .map(|item| item.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["milk", "eggs", "apples"], values);
+        assert_eq!(20..26, items[2].range);
+        assert_eq!(Some(&entities[0]), items[2].quantity);
+        assert_eq!(None, items[0].quantity);
+    }
+
+    #[test]
+    fn test_parse_list_keeps_words_containing_conjunctions() {
+        // When
+        let items = parse_list("sandwiches or bread et cetera", &[], Language::EN);
+
+        // Then
+        let values = items
+            .iter()
+            .map(|item| item.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["sandwiches", "bread et cetera"], values);
+    }
+
+    #[test]
+    fn test_parse_list_in_japanese() {
+        // Given
+        let text = "牛乳と卵2個";
+
+        // When
+        let items = parse_list(text, &[], Language::JA);
+
+        // Then
+        let values = items
+            .iter()
+            .map(|item| item.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["牛乳", "卵2個"], values);
+    }
+}
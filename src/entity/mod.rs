@@ -1,3 +1,11 @@
 pub mod builtin_entity;
+#[cfg(feature = "std")]
+pub mod catalog;
+#[cfg(feature = "std")]
+pub mod compound;
 pub mod gazetteer_entity;
 pub mod grammar_entity;
+#[cfg(feature = "std")]
+pub mod list;
+#[cfg(feature = "std")]
+pub mod overlap;
@@ -0,0 +1,279 @@
+//! Resolution of overlapping builtin and custom entity matches
+//!
+//! Entity ranges are expressed in characters, not bytes.
+use crate::entity::builtin_entity::BuiltinEntity;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Match of a custom entity, as extracted by a gazetteer or a custom entity parser
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomEntityMatch {
+    /// Matched text
+    pub value: String,
+    /// Value to which the matched text resolves
+    pub resolved_value: String,
+    pub range: Range<usize>,
+    /// Name of the custom entity
+    pub entity: String,
+}
+
+/// Policy used to decide which of two overlapping matches is kept
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlapPolicy {
+    /// The longest match is kept, custom matches win when both have the same length
+    #[default]
+    Longest,
+    /// Custom matches are always kept over builtin ones, then the longest match is kept
+    CustomFirst,
+    /// Builtin matches are always kept over custom ones, then the longest match is kept
+    BuiltinFirst,
+}
+
+/// Either a builtin or a custom entity match
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntityMatch<'a> {
+    Builtin(&'a BuiltinEntity),
+    Custom(&'a CustomEntityMatch),
+}
+
+impl<'a> EntityMatch<'a> {
+    pub fn range(&self) -> &'a Range<usize> {
+        match self {
+            EntityMatch::Builtin(entity) => &entity.range,
+            EntityMatch::Custom(entity) => &entity.range,
+        }
+    }
+
+    /// Identifier of the builtin entity kind, or name of the custom entity
+    pub fn entity(&self) -> &'a str {
+        match self {
+            EntityMatch::Builtin(entity) => entity.entity_kind.identifier(),
+            EntityMatch::Custom(entity) => &entity.entity,
+        }
+    }
+
+    pub fn is_builtin(&self) -> bool {
+        match self {
+            EntityMatch::Builtin(_) => true,
+            EntityMatch::Custom(_) => false,
+        }
+    }
+
+    fn overlaps(&self, other: &EntityMatch) -> bool {
+        self.range().start < other.range().end && other.range().start < self.range().end
+    }
+
+    /// Orders the matches from the most to the least preferred one according to the policy
+    fn priority_cmp(&self, other: &EntityMatch, policy: OverlapPolicy) -> Ordering {
+        let source_cmp = match policy {
+            OverlapPolicy::Longest => Ordering::Equal,
+            OverlapPolicy::CustomFirst => self.is_builtin().cmp(&other.is_builtin()),
+            OverlapPolicy::BuiltinFirst => other.is_builtin().cmp(&self.is_builtin()),
+        };
+        source_cmp
+            .then_with(|| other.range().len().cmp(&self.range().len()))
+            .then_with(|| self.is_builtin().cmp(&other.is_builtin()))
+            .then_with(|| self.position_cmp(other))
+    }
+
+    /// Orders the matches by range start, then by range length, then by entity
+    fn position_cmp(&self, other: &EntityMatch) -> Ordering {
+        self.range()
+            .start
+            .cmp(&other.range().start)
+            .then_with(|| self.range().len().cmp(&other.range().len()))
+            .then_with(|| self.entity().cmp(other.entity()))
+    }
+}
+
+/// Candidate match which was discarded because it overlaps a preferred match
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DroppedMatch<'a> {
+    pub candidate: EntityMatch<'a>,
+    /// Selected match overlapping the candidate
+    pub kept: EntityMatch<'a>,
+}
+
+/// Outcome of the resolution of overlapping matches, along with the discarded candidates
+///
+/// This helps understanding why an entity which was extracted does not appear in a parsing
+/// result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlapResolution<'a> {
+    /// Selected matches, in the order in which they appear in the text
+    pub selected: Vec<EntityMatch<'a>>,
+    /// Discarded candidates, in the order in which they appear in the text
+    pub dropped: Vec<DroppedMatch<'a>>,
+}
+
+/// Selects a set of non overlapping matches among builtin and custom entity matches
+///
+/// Matches are considered from the most to the least preferred one according to the policy, and
+/// a match is kept when it does not overlap any match kept before. The selected matches are
+/// returned in the order in which they appear in the text.
+pub fn resolve_overlapping_matches<'a>(
+    builtin_entities: &'a [BuiltinEntity],
+    custom_entities: &'a [CustomEntityMatch],
+    policy: OverlapPolicy,
+) -> Vec<EntityMatch<'a>> {
+    resolve_overlapping_matches_with_dropped(builtin_entities, custom_entities, policy).selected
+}
+
+/// Same as `resolve_overlapping_matches`, but also returns the discarded candidates along with
+/// the selected match which prevailed over each of them
+pub fn resolve_overlapping_matches_with_dropped<'a>(
+    builtin_entities: &'a [BuiltinEntity],
+    custom_entities: &'a [CustomEntityMatch],
+    policy: OverlapPolicy,
+) -> OverlapResolution<'a> {
+    let mut candidates = builtin_entities
+        .iter()
+        .map(EntityMatch::Builtin)
+        .chain(custom_entities.iter().map(EntityMatch::Custom))
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| a.priority_cmp(b, policy));
+    let mut selected: Vec<EntityMatch> = vec![];
+    let mut dropped: Vec<DroppedMatch> = vec![];
+    for candidate in candidates {
+        match selected.iter().find(|m| m.overlaps(&candidate)) {
+            Some(kept) => dropped.push(DroppedMatch {
+                candidate,
+                kept: *kept,
+            }),
+            None => selected.push(candidate),
+        }
+    }
+    selected.sort_by(|a, b| a.position_cmp(b));
+    dropped.sort_by(|a, b| a.candidate.position_cmp(&b.candidate));
+    OverlapResolution { selected, dropped }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::builtin_entity::BuiltinEntityKind;
+    use crate::ontology::*;
+
+    fn builtin(range: Range<usize>, entity_kind: BuiltinEntityKind) -> BuiltinEntity {
+        BuiltinEntity::new(
+            "".to_string(),
+            range,
+            SlotValue::Number(NumberValue { value: 3.0 }),
+            entity_kind,
+        )
+    }
+
+    fn custom(range: Range<usize>, entity: &str) -> CustomEntityMatch {
+        CustomEntityMatch {
+            value: "".to_string(),
+            resolved_value: "".to_string(),
+            range,
+            entity: entity.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_overlapping_matches() {
+        // Given
+        // "play the 7 rings song in 3 minutes"
+        let builtin_entities = vec![
+            builtin(9..10, BuiltinEntityKind::Number),
+            builtin(20..34, BuiltinEntityKind::Duration),
+            builtin(25..26, BuiltinEntityKind::Number),
+        ];
+        let custom_entities = vec![custom(9..16, "song"), custom(25..34, "timer")];
+
+        // When
+        let longest = resolve_overlapping_matches(
+            &builtin_entities,
+            &custom_entities,
+            OverlapPolicy::Longest,
+        );
+        let custom_first = resolve_overlapping_matches(
+            &builtin_entities,
+            &custom_entities,
+            OverlapPolicy::CustomFirst,
+        );
+        let builtin_first = resolve_overlapping_matches(
+            &builtin_entities,
+            &custom_entities,
+            OverlapPolicy::BuiltinFirst,
+        );
+
+        // Then
+        let entities = |matches: Vec<EntityMatch>| {
+            matches
+                .iter()
+                .map(|m| m.entity().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["song", "snips/duration"], entities(longest));
+        assert_eq!(vec!["song", "timer"], entities(custom_first));
+        assert_eq!(
+            vec!["snips/number", "snips/duration"],
+            entities(builtin_first)
+        );
+    }
+
+    #[test]
+    fn test_resolve_overlapping_matches_with_equal_lengths() {
+        // Given
+        let builtin_entities = vec![builtin(0..5, BuiltinEntityKind::City)];
+        let custom_entities = vec![custom(0..5, "destination")];
+
+        // When
+        let selected = resolve_overlapping_matches(
+            &builtin_entities,
+            &custom_entities,
+            OverlapPolicy::Longest,
+        );
+
+        // Then
+        assert_eq!(vec![EntityMatch::Custom(&custom_entities[0])], selected);
+    }
+
+    #[test]
+    fn test_resolve_overlapping_matches_with_dropped() {
+        // Given
+        // "play the 7 rings song in 3 minutes"
+        let builtin_entities = vec![
+            builtin(9..10, BuiltinEntityKind::Number),
+            builtin(20..34, BuiltinEntityKind::Duration),
+            builtin(25..26, BuiltinEntityKind::Number),
+        ];
+        let custom_entities = vec![custom(9..16, "song"), custom(25..34, "timer")];
+
+        // When
+        let resolution = resolve_overlapping_matches_with_dropped(
+            &builtin_entities,
+            &custom_entities,
+            OverlapPolicy::Longest,
+        );
+
+        // Then
+        let expected_dropped = vec![
+            DroppedMatch {
+                candidate: EntityMatch::Builtin(&builtin_entities[0]),
+                kept: EntityMatch::Custom(&custom_entities[0]),
+            },
+            DroppedMatch {
+                candidate: EntityMatch::Builtin(&builtin_entities[2]),
+                kept: EntityMatch::Builtin(&builtin_entities[1]),
+            },
+            DroppedMatch {
+                candidate: EntityMatch::Custom(&custom_entities[1]),
+                kept: EntityMatch::Builtin(&builtin_entities[1]),
+            },
+        ];
+        assert_eq!(
+            resolve_overlapping_matches(
+                &builtin_entities,
+                &custom_entities,
+                OverlapPolicy::Longest
+            ),
+            resolution.selected
+        );
+        assert_eq!(expected_dropped, resolution.dropped);
+    }
+}
@@ -1 +1,48 @@
+#[cfg(feature = "std")]
+pub use failure::Error;
+#[cfg(feature = "std")]
+pub(crate) use failure::{bail, format_err};
+
+#[cfg(feature = "std")]
 pub type Result<T> = ::std::result::Result<T, ::failure::Error>;
+
+#[cfg(not(feature = "std"))]
+pub type Result<T> = ::core::result::Result<T, Error>;
+
+/// Error used when the `std` feature is disabled, which only holds a message
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    message: alloc::string::String,
+}
+
+#[cfg(not(feature = "std"))]
+impl Error {
+    pub(crate) fn new(message: alloc::string::String) -> Self {
+        Self { message }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ::core::fmt::Display for Error {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! format_err {
+    ($($arg:tt)*) => {
+        $crate::errors::Error::new(::alloc::format!($($arg)*))
+    };
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! bail {
+    ($($arg:tt)*) => {
+        return Err($crate::errors::format_err!($($arg)*))
+    };
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) use {bail, format_err};
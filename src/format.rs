@@ -0,0 +1,889 @@
+//! Human readable formatting of slot values, for confirmations and logs
+use crate::datetime::DatetimeComponents;
+use crate::errors::*;
+use crate::language::Language;
+use crate::ontology::*;
+use crate::verbalization::VerbalizationOptions;
+use std::fmt;
+
+impl SlotValue {
+    /// Formats the value in a human readable way, e.g. "3 months" or "13 June 2017 at 6 pm"
+    pub fn format_human(&self, language: Language) -> String {
+        match self {
+            SlotValue::Custom(value)
+            | SlotValue::MusicAlbum(value)
+            | SlotValue::MusicArtist(value)
+            | SlotValue::MusicTrack(value)
+            | SlotValue::City(value)
+            | SlotValue::Country(value)
+            | SlotValue::Region(value)
+            | SlotValue::IpAddress(value)
+            | SlotValue::Hostname(value)
+            | SlotValue::Hashtag(value)
+            | SlotValue::Mention(value)
+            | SlotValue::AlphanumericCode(value) => value.value.clone(),
+            SlotValue::Number(number) => number.format(language),
+            SlotValue::Ordinal(ordinal) => format_ordinal(ordinal, language),
+            SlotValue::Percentage(percentage) => percentage.format(language),
+            SlotValue::InstantTime(instant_time) => format_instant_time(instant_time, language),
+            SlotValue::TimeInterval(interval) => format_time_interval(interval, language),
+            SlotValue::AmountOfMoney(amount) => amount.format(language),
+            SlotValue::Temperature(temperature) => format_temperature(temperature, language),
+            SlotValue::Duration(duration) => format_duration(duration, language),
+            SlotValue::RelativeChange(change) => format_relative_change(change, language),
+            SlotValue::MediaPosition(position) => format_media_position(position),
+            SlotValue::BloodPressure(pressure) => {
+                format!("{}/{} mmHg", pressure.systolic, pressure.diastolic)
+            }
+            SlotValue::Dosage(dosage) => format_dosage(dosage, language),
+            SlotValue::Reference(reference) => reference.anaphora.phrase(language).to_string(),
+            SlotValue::GeoCoordinate(coordinates) => {
+                let latitude_hemisphere = if coordinates.latitude < 0. { "S" } else { "N" };
+                let longitude_hemisphere = if coordinates.longitude < 0. { "W" } else { "E" };
+                format!(
+                    "{}° {}, {}° {}",
+                    format_number(coordinates.latitude.abs(), language),
+                    latitude_hemisphere,
+                    format_number(coordinates.longitude.abs(), language),
+                    longitude_hemisphere
+                )
+            }
+            SlotValue::CookingQuantity(quantity) => format!(
+                "{} {}",
+                format_number(quantity.value, language),
+                quantity.unit.abbreviation()
+            ),
+            SlotValue::Score(score) => match language {
+                Language::JA => format!("{}対{}", score.first, score.second),
+                Language::KO => format!("{}대{}", score.first, score.second),
+                _ => format!("{}-{}", score.first, score.second),
+            },
+            SlotValue::Version(version) => {
+                format!("{}.{}.{}", version.major, version.minor, version.patch)
+            }
+            SlotValue::FlightNumber(flight) => format!("{}{}", flight.carrier, flight.number),
+            SlotValue::ProductCode(product_code) => product_code.value.clone(),
+        }
+    }
+}
+
+/// Style of the surface form produced by `SlotValue::to_surface`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SurfaceStyle {
+    /// Text meant to be displayed, as produced by `SlotValue::format_human`
+    Written,
+    /// Text meant to be spoken, as produced by `SlotValue::verbalize`
+    Spoken(VerbalizationOptions),
+}
+
+impl SlotValue {
+    /// Renders the value as a natural phrase, which can be used to paraphrase it back to the
+    /// user, e.g. in confirmation prompts
+    pub fn to_surface(&self, language: Language, style: SurfaceStyle) -> Result<String> {
+        match style {
+            SurfaceStyle::Written => Ok(self.format_human(language)),
+            SurfaceStyle::Spoken(options) => self.verbalize(language, options),
+        }
+    }
+}
+
+/// Slot values are displayed in English, use `SlotValue::format_human` for other languages
+impl fmt::Display for SlotValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format_human(Language::EN))
+    }
+}
+
+impl NumberValue {
+    /// Formats the number with the digit grouping and decimal separators of the language, e.g.
+    /// "1,234.5" in English and "1.234,5" in German
+    pub fn format(&self, language: Language) -> String {
+        format_number(self.value, language)
+    }
+}
+
+impl PercentageValue {
+    /// Formats the percentage with the separators of the language, e.g. "12.5%" in English and
+    /// "12,5 %" in French
+    pub fn format(&self, language: Language) -> String {
+        let value = format_number(self.value, language);
+        match language {
+            Language::DE | Language::FR => format!("{} %", value),
+            _ => format!("{}%", value),
+        }
+    }
+}
+
+impl AmountOfMoneyValue {
+    /// Formats the amount with the separators of the language and all the digits of its minor
+    /// unit, e.g. "$1,234.50" in English and "1.234,50 €" in German
+    pub fn format(&self, language: Language) -> String {
+        let separators = NumberSeparators::of(language);
+        let exponent = self.minor_unit_exponent();
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let major_units = self.minor_units.unsigned_abs() / 10_u64.pow(exponent);
+        let remaining_minor_units = self.minor_units.unsigned_abs() % 10_u64.pow(exponent);
+        let major_units = separators.group_digits(&major_units.to_string());
+        let value = if remaining_minor_units == 0 {
+            major_units
+        } else {
+            format!(
+                "{}{}{:0width$}",
+                major_units,
+                separators.decimal,
+                remaining_minor_units,
+                width = exponent as usize
+            )
+        };
+        match self.unit.as_deref() {
+            // The sign comes before the currency symbol, e.g. "-$0.05"
+            Some(unit @ "$") | Some(unit @ "£") if language == Language::EN => {
+                format!("{}{}{}", sign, unit, value)
+            }
+            Some(unit) => format!("{}{} {}", sign, value, unit),
+            None => format!("{}{}", sign, value),
+        }
+    }
+}
+
+pub(crate) struct NumberSeparators {
+    pub(crate) grouping: &'static str,
+    pub(crate) decimal: &'static str,
+    /// Minimum number of digits in the leading group, below which digits are not grouped, e.g.
+    /// "1234" but "12.345" in Spanish
+    min_grouping_digits: usize,
+}
+
+impl NumberSeparators {
+    pub(crate) fn of(language: Language) -> Self {
+        let (grouping, decimal, min_grouping_digits) = match language {
+            Language::EN | Language::JA | Language::KO => (",", ".", 1),
+            Language::DE | Language::IT | Language::PT_BR => (".", ",", 1),
+            Language::ES => (".", ",", 2),
+            Language::FR => ("\u{202f}", ",", 1),
+            Language::PT_PT => ("\u{a0}", ",", 2),
+        };
+        Self {
+            grouping,
+            decimal,
+            min_grouping_digits,
+        }
+    }
+
+    fn group_digits(&self, digits: &str) -> String {
+        if digits.len() < 3 + self.min_grouping_digits {
+            return digits.to_string();
+        }
+        let (leading_group, other_groups) = digits.split_at((digits.len() - 1) % 3 + 1);
+        let mut grouped = leading_group.to_string();
+        for start in (0..other_groups.len()).step_by(3) {
+            grouped.push_str(self.grouping);
+            grouped.push_str(&other_groups[start..start + 3]);
+        }
+        grouped
+    }
+}
+
+/// Widens a single precision value to the double precision value having the same shortest decimal
+/// representation, e.g. 20.3 rather than 20.299999237060547
+pub(crate) fn widen(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(value as f64)
+}
+
+fn format_number(value: f64, language: Language) -> String {
+    let separators = NumberSeparators::of(language);
+    let sign = if value < 0.0 { "-" } else { "" };
+    let digits = if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value.abs() as i64)
+    } else {
+        format!("{}", value.abs())
+    };
+    match digits.find('.') {
+        Some(index) => format!(
+            "{}{}{}{}",
+            sign,
+            separators.group_digits(&digits[..index]),
+            separators.decimal,
+            &digits[index + 1..]
+        ),
+        None => format!("{}{}", sign, separators.group_digits(&digits)),
+    }
+}
+
+fn format_ordinal(ordinal: &OrdinalValue, language: Language) -> String {
+    let value = ordinal.value;
+    let position = match language {
+        Language::EN => {
+            let suffix = match (value % 10, value % 100) {
+                (_, 11) | (_, 12) | (_, 13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{}{}", value, suffix)
+        }
+        Language::FR if value == 1 => "1er".to_string(),
+        Language::FR => format!("{}e", value),
+        Language::DE => format!("{}.", value),
+        Language::ES => format!("{}.º", value),
+        Language::IT | Language::PT_PT | Language::PT_BR => format!("{}º", value),
+        Language::JA => format!("{}番目", value),
+        Language::KO => format!("{}번째", value),
+    };
+    match ordinal.direction {
+        OrdinalDirection::FromStart => position,
+        OrdinalDirection::FromEnd => match language {
+            Language::EN if value == 1 => "last".to_string(),
+            Language::EN => format!("{} to last", position),
+            Language::FR => format!("{} en partant de la fin", position),
+            Language::DE => format!("{} von hinten", position),
+            Language::ES => format!("{} desde el final", position),
+            Language::IT => format!("{} dalla fine", position),
+            Language::PT_PT | Language::PT_BR => format!("{} a contar do fim", position),
+            Language::JA => format!("後ろから{}", position),
+            Language::KO => format!("뒤에서 {}", position),
+        },
+    }
+}
+
+fn month_name(month: u32, language: Language) -> &'static str {
+    let names = match language {
+        Language::DE => [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        Language::EN => [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        Language::ES => [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        Language::FR => [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        Language::IT => [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        Language::PT_PT | Language::PT_BR => [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        Language::JA | Language::KO => [""; 12],
+    };
+    names[(month - 1) as usize]
+}
+
+fn format_year(datetime: &DatetimeComponents, language: Language) -> String {
+    match language {
+        Language::JA => format!("{}年", datetime.year),
+        Language::KO => format!("{}년", datetime.year),
+        _ => format!("{}", datetime.year),
+    }
+}
+
+fn format_month(datetime: &DatetimeComponents, language: Language) -> String {
+    let (year, month) = (datetime.year, datetime.month);
+    match language {
+        Language::ES | Language::PT_PT | Language::PT_BR => {
+            format!("{} de {}", month_name(month, language), year)
+        }
+        Language::JA => format!("{}年{}月", year, month),
+        Language::KO => format!("{}년 {}월", year, month),
+        _ => format!("{} {}", month_name(month, language), year),
+    }
+}
+
+fn format_date(datetime: &DatetimeComponents, language: Language) -> String {
+    let (year, month, day) = (datetime.year, datetime.month, datetime.day);
+    match language {
+        Language::DE => format!("{}. {} {}", day, month_name(month, language), year),
+        Language::ES | Language::PT_PT | Language::PT_BR => {
+            format!("{} de {} de {}", day, month_name(month, language), year)
+        }
+        Language::JA => format!("{}年{}月{}日", year, month, day),
+        Language::KO => format!("{}년 {}월 {}일", year, month, day),
+        _ => format!("{} {} {}", day, month_name(month, language), year),
+    }
+}
+
+fn format_time_of_day(datetime: &DatetimeComponents, language: Language) -> String {
+    let (hour, minute) = (datetime.hour, datetime.minute);
+    match language {
+        Language::EN => {
+            let period = if hour < 12 { "am" } else { "pm" };
+            let hour = match hour % 12 {
+                0 => 12,
+                h => h,
+            };
+            if minute == 0 {
+                format!("{} {}", hour, period)
+            } else {
+                format!("{}:{:02} {}", hour, minute, period)
+            }
+        }
+        _ => format!("{:02}:{:02}", hour, minute),
+    }
+}
+
+fn format_date_and_time(datetime: &DatetimeComponents, language: Language) -> String {
+    let connector = match language {
+        Language::DE => "um",
+        Language::EN => "at",
+        Language::ES => "a las",
+        Language::FR => "à",
+        Language::IT => "alle",
+        Language::PT_PT | Language::PT_BR => "às",
+        Language::JA | Language::KO => {
+            return format!(
+                "{} {}",
+                format_date(datetime, language),
+                format_time_of_day(datetime, language)
+            )
+        }
+    };
+    format!(
+        "{} {} {}",
+        format_date(datetime, language),
+        connector,
+        format_time_of_day(datetime, language)
+    )
+}
+
+fn format_datetime_with_grain(datetime: &str, grain: Option<Grain>, language: Language) -> String {
+    let components = match DatetimeComponents::parse(datetime) {
+        Ok(components) => components,
+        Err(_) => return datetime.to_string(),
+    };
+    let grain = grain.unwrap_or(if components.is_midnight() {
+        Grain::Day
+    } else {
+        Grain::Minute
+    });
+    match grain {
+        Grain::Year => format_year(&components, language),
+        Grain::Quarter | Grain::Month => format_month(&components, language),
+        Grain::Week | Grain::Day => format_date(&components, language),
+        Grain::Hour | Grain::Minute | Grain::Second => format_date_and_time(&components, language),
+    }
+}
+
+fn format_instant_time(instant_time: &InstantTimeValue, language: Language) -> String {
+    format_datetime_with_grain(&instant_time.value, Some(instant_time.grain), language)
+}
+
+fn format_time_interval(interval: &TimeIntervalValue, language: Language) -> String {
+    let from = interval
+        .from
+        .as_ref()
+        .map(|from| format_datetime_with_grain(from, None, language));
+    let to = interval
+        .displayed_to()
+        .map(|to| format_datetime_with_grain(&to, interval.displayed_to_grain(), language));
+    match (from, to) {
+        (Some(from), Some(to)) => match language {
+            Language::DE => format!("von {} bis {}", from, to),
+            Language::EN => format!("from {} to {}", from, to),
+            Language::ES => format!("desde {} hasta {}", from, to),
+            Language::FR => format!("de {} à {}", from, to),
+            Language::IT => format!("da {} a {}", from, to),
+            Language::PT_PT | Language::PT_BR => format!("de {} a {}", from, to),
+            Language::JA => format!("{}から{}まで", from, to),
+            Language::KO => format!("{}부터 {}까지", from, to),
+        },
+        (Some(from), None) => match language {
+            Language::DE => format!("ab {}", from),
+            Language::EN => format!("from {}", from),
+            Language::ES => format!("desde {}", from),
+            Language::FR => format!("à partir de {}", from),
+            Language::IT => format!("da {}", from),
+            Language::PT_PT | Language::PT_BR => format!("a partir de {}", from),
+            Language::JA => format!("{}から", from),
+            Language::KO => format!("{}부터", from),
+        },
+        (None, Some(to)) => match language {
+            Language::DE => format!("bis {}", to),
+            Language::EN => format!("until {}", to),
+            Language::ES => format!("hasta {}", to),
+            Language::FR => format!("jusqu'à {}", to),
+            Language::IT => format!("fino a {}", to),
+            Language::PT_PT | Language::PT_BR => format!("até {}", to),
+            Language::JA => format!("{}まで", to),
+            Language::KO => format!("{}까지", to),
+        },
+        (None, None) => String::new(),
+    }
+}
+
+fn format_temperature(temperature: &TemperatureValue, language: Language) -> String {
+    let value = format_number(widen(temperature.value), language);
+    // Changes of temperature are marked so that a decrease is not mistaken for a negative
+    // temperature
+    let value = match temperature.mode {
+        TemperatureMode::Delta if temperature.value >= 0. => format!("Δ+{}", value),
+        TemperatureMode::Delta => format!("Δ{}", value),
+        TemperatureMode::Absolute => value,
+    };
+    match temperature.unit.as_deref() {
+        Some("celsius") => format!("{} °C", value),
+        Some("fahrenheit") => format!("{} °F", value),
+        Some("kelvin") => format!("{} K", value),
+        Some("degree") | None => format!("{}°", value),
+        Some(unit) => format!("{} {}", value, unit),
+    }
+}
+
+fn format_relative_change(change: &RelativeChangeValue, language: Language) -> String {
+    match change.value {
+        Some(value) => {
+            let sign = match change.direction {
+                ChangeDirection::Increase => "+",
+                ChangeDirection::Decrease => "-",
+            };
+            let value = format_number(value, language);
+            match change.unit.as_deref() {
+                Some(unit) => format!("{}{} {}", sign, value, unit),
+                None => format!("{}{}", sign, value),
+            }
+        }
+        None => vague_change(change.direction, change.magnitude, language).to_string(),
+    }
+}
+
+/// Formats dosages as on prescriptions, e.g. "2 × 500 mg tablet", the number of units being
+/// omitted when a single unit is taken
+fn format_dosage(dosage: &DosageValue, language: Language) -> String {
+    let mut parts = vec![];
+    if dosage.count != 1. {
+        parts.push(format!("{} ×", format_number(dosage.count, language)));
+    }
+    if let Some(dose) = dosage.dose {
+        parts.push(format_number(dose, language));
+        parts.extend(dosage.unit.clone());
+    }
+    parts.extend(dosage.form.clone());
+    parts.join(" ")
+}
+
+/// Formats media positions as on a player, e.g. "1:20:00", offsets being signed, e.g. "-0:30"
+fn format_media_position(position: &MediaPositionValue) -> String {
+    let sign = match position.mode {
+        MediaPositionMode::Absolute => "",
+        MediaPositionMode::Offset if position.seconds < 0 => "-",
+        MediaPositionMode::Offset => "+",
+    };
+    let duration = position.duration();
+    if duration.hours > 0 {
+        format!(
+            "{}{}:{:02}:{:02}",
+            sign, duration.hours, duration.minutes, duration.seconds
+        )
+    } else {
+        format!("{}{}:{:02}", sign, duration.minutes, duration.seconds)
+    }
+}
+
+/// Phrase describing a change whose size is not specified, e.g. "a bit more"
+pub(crate) fn vague_change(
+    direction: ChangeDirection,
+    magnitude: Option<ChangeMagnitude>,
+    language: Language,
+) -> &'static str {
+    let (slight, moderate, large) = match (language, direction) {
+        (Language::DE, ChangeDirection::Increase) => ("etwas mehr", "mehr", "viel mehr"),
+        (Language::DE, ChangeDirection::Decrease) => ("etwas weniger", "weniger", "viel weniger"),
+        (Language::EN, ChangeDirection::Increase) => ("a bit more", "more", "a lot more"),
+        (Language::EN, ChangeDirection::Decrease) => ("a bit less", "less", "a lot less"),
+        (Language::ES, ChangeDirection::Increase) => ("un poco más", "más", "mucho más"),
+        (Language::ES, ChangeDirection::Decrease) => ("un poco menos", "menos", "mucho menos"),
+        (Language::FR, ChangeDirection::Increase) => ("un peu plus", "plus", "beaucoup plus"),
+        (Language::FR, ChangeDirection::Decrease) => ("un peu moins", "moins", "beaucoup moins"),
+        (Language::IT, ChangeDirection::Increase) => ("un po' di più", "di più", "molto di più"),
+        (Language::IT, ChangeDirection::Decrease) => ("un po' di meno", "di meno", "molto di meno"),
+        (Language::PT_PT, ChangeDirection::Increase)
+        | (Language::PT_BR, ChangeDirection::Increase) => ("um pouco mais", "mais", "muito mais"),
+        (Language::PT_PT, ChangeDirection::Decrease)
+        | (Language::PT_BR, ChangeDirection::Decrease) => {
+            ("um pouco menos", "menos", "muito menos")
+        }
+        (Language::JA, ChangeDirection::Increase) => ("少し多く", "多く", "ずっと多く"),
+        (Language::JA, ChangeDirection::Decrease) => ("少し少なく", "少なく", "ずっと少なく"),
+        (Language::KO, ChangeDirection::Increase) => ("조금 더", "더", "훨씬 더"),
+        (Language::KO, ChangeDirection::Decrease) => ("조금 덜", "덜", "훨씬 덜"),
+    };
+    match magnitude {
+        Some(ChangeMagnitude::Slight) => slight,
+        None => moderate,
+        Some(ChangeMagnitude::Large) => large,
+    }
+}
+
+fn duration_unit(grain: Grain, plural: bool, language: Language) -> &'static str {
+    let (singular_form, plural_form) = match (language, grain) {
+        (Language::DE, Grain::Year) => ("Jahr", "Jahre"),
+        (Language::DE, Grain::Quarter) => ("Quartal", "Quartale"),
+        (Language::DE, Grain::Month) => ("Monat", "Monate"),
+        (Language::DE, Grain::Week) => ("Woche", "Wochen"),
+        (Language::DE, Grain::Day) => ("Tag", "Tage"),
+        (Language::DE, Grain::Hour) => ("Stunde", "Stunden"),
+        (Language::DE, Grain::Minute) => ("Minute", "Minuten"),
+        (Language::DE, Grain::Second) => ("Sekunde", "Sekunden"),
+        (Language::EN, Grain::Year) => ("year", "years"),
+        (Language::EN, Grain::Quarter) => ("quarter", "quarters"),
+        (Language::EN, Grain::Month) => ("month", "months"),
+        (Language::EN, Grain::Week) => ("week", "weeks"),
+        (Language::EN, Grain::Day) => ("day", "days"),
+        (Language::EN, Grain::Hour) => ("hour", "hours"),
+        (Language::EN, Grain::Minute) => ("minute", "minutes"),
+        (Language::EN, Grain::Second) => ("second", "seconds"),
+        (Language::ES, Grain::Year) => ("año", "años"),
+        (Language::ES, Grain::Quarter) => ("trimestre", "trimestres"),
+        (Language::ES, Grain::Month) => ("mes", "meses"),
+        (Language::ES, Grain::Week) => ("semana", "semanas"),
+        (Language::ES, Grain::Day) => ("día", "días"),
+        (Language::ES, Grain::Hour) => ("hora", "horas"),
+        (Language::ES, Grain::Minute) => ("minuto", "minutos"),
+        (Language::ES, Grain::Second) => ("segundo", "segundos"),
+        (Language::FR, Grain::Year) => ("an", "ans"),
+        (Language::FR, Grain::Quarter) => ("trimestre", "trimestres"),
+        (Language::FR, Grain::Month) => ("mois", "mois"),
+        (Language::FR, Grain::Week) => ("semaine", "semaines"),
+        (Language::FR, Grain::Day) => ("jour", "jours"),
+        (Language::FR, Grain::Hour) => ("heure", "heures"),
+        (Language::FR, Grain::Minute) => ("minute", "minutes"),
+        (Language::FR, Grain::Second) => ("seconde", "secondes"),
+        (Language::IT, Grain::Year) => ("anno", "anni"),
+        (Language::IT, Grain::Quarter) => ("trimestre", "trimestri"),
+        (Language::IT, Grain::Month) => ("mese", "mesi"),
+        (Language::IT, Grain::Week) => ("settimana", "settimane"),
+        (Language::IT, Grain::Day) => ("giorno", "giorni"),
+        (Language::IT, Grain::Hour) => ("ora", "ore"),
+        (Language::IT, Grain::Minute) => ("minuto", "minuti"),
+        (Language::IT, Grain::Second) => ("secondo", "secondi"),
+        (Language::PT_PT, Grain::Year) | (Language::PT_BR, Grain::Year) => ("ano", "anos"),
+        (Language::PT_PT, Grain::Quarter) | (Language::PT_BR, Grain::Quarter) => {
+            ("trimestre", "trimestres")
+        }
+        (Language::PT_PT, Grain::Month) | (Language::PT_BR, Grain::Month) => ("mês", "meses"),
+        (Language::PT_PT, Grain::Week) | (Language::PT_BR, Grain::Week) => ("semana", "semanas"),
+        (Language::PT_PT, Grain::Day) | (Language::PT_BR, Grain::Day) => ("dia", "dias"),
+        (Language::PT_PT, Grain::Hour) | (Language::PT_BR, Grain::Hour) => ("hora", "horas"),
+        (Language::PT_PT, Grain::Minute) | (Language::PT_BR, Grain::Minute) => {
+            ("minuto", "minutos")
+        }
+        (Language::PT_PT, Grain::Second) | (Language::PT_BR, Grain::Second) => {
+            ("segundo", "segundos")
+        }
+        (Language::JA, Grain::Year) => ("年", "年"),
+        (Language::JA, Grain::Quarter) => ("四半期", "四半期"),
+        (Language::JA, Grain::Month) => ("か月", "か月"),
+        (Language::JA, Grain::Week) => ("週間", "週間"),
+        (Language::JA, Grain::Day) => ("日", "日"),
+        (Language::JA, Grain::Hour) => ("時間", "時間"),
+        (Language::JA, Grain::Minute) => ("分", "分"),
+        (Language::JA, Grain::Second) => ("秒", "秒"),
+        (Language::KO, Grain::Year) => ("년", "년"),
+        (Language::KO, Grain::Quarter) => ("분기", "분기"),
+        (Language::KO, Grain::Month) => ("개월", "개월"),
+        (Language::KO, Grain::Week) => ("주", "주"),
+        (Language::KO, Grain::Day) => ("일", "일"),
+        (Language::KO, Grain::Hour) => ("시간", "시간"),
+        (Language::KO, Grain::Minute) => ("분", "분"),
+        (Language::KO, Grain::Second) => ("초", "초"),
+    };
+    if plural {
+        plural_form
+    } else {
+        singular_form
+    }
+}
+
+fn format_duration(duration: &DurationValue, language: Language) -> String {
+    let components = [
+        (duration.years, Grain::Year),
+        (duration.quarters, Grain::Quarter),
+        (duration.months, Grain::Month),
+        (duration.weeks, Grain::Week),
+        (duration.days, Grain::Day),
+        (duration.hours, Grain::Hour),
+        (duration.minutes, Grain::Minute),
+        (duration.seconds, Grain::Second),
+    ];
+    let separator = match language {
+        Language::JA | Language::KO => "",
+        _ => " ",
+    };
+    let formatted = components
+        .iter()
+        .filter(|(count, _)| *count != 0)
+        .map(|(count, grain)| {
+            let unit = duration_unit(*grain, count.abs() != 1, language);
+            format!("{}{}{}", count, separator, unit)
+        })
+        .collect::<Vec<_>>();
+    if formatted.is_empty() {
+        format!(
+            "0{}{}",
+            separator,
+            duration_unit(Grain::Second, true, language)
+        )
+    } else {
+        formatted.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_human() {
+        // Given
+        let instant_time = SlotValue::InstantTime(InstantTimeValue {
+            value: "2017-06-13 18:00:00 +02:00".to_string(),
+            grain: Grain::Hour,
+            precision: Precision::Exact,
+        });
+        let duration = SlotValue::Duration(DurationValue {
+            years: 0,
+            quarters: 0,
+            months: 3,
+            weeks: 0,
+            days: 1,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            precision: Precision::Exact,
+        });
+        let temperature = SlotValue::Temperature(TemperatureValue {
+            value: 23.0,
+            unit: Some("celsius".to_string()),
+            mode: TemperatureMode::Absolute,
+        });
+        let interval = SlotValue::TimeInterval(TimeIntervalValue {
+            from: Some("2017-06-07 00:00:00 +02:00".to_string()),
+            to: Some("2017-06-09 00:00:00 +02:00".to_string()),
+            to_inclusive: false,
+        });
+
+        // Then
+        assert_eq!("13 June 2017 at 6 pm", instant_time.to_string());
+        assert_eq!(
+            "13 de junho de 2017 às 18:00",
+            instant_time.format_human(Language::PT_BR)
+        );
+        assert_eq!(
+            "2017年6月13日 18:00",
+            instant_time.format_human(Language::JA)
+        );
+        assert_eq!("3 months 1 day", duration.to_string());
+        assert_eq!("3 Monate 1 Tag", duration.format_human(Language::DE));
+        assert_eq!("23 °C", temperature.format_human(Language::FR));
+        let decimal_temperature = SlotValue::Temperature(TemperatureValue {
+            value: 20.3,
+            unit: Some("celsius".to_string()),
+            mode: TemperatureMode::Absolute,
+        });
+        assert_eq!("20.3 °C", decimal_temperature.to_string());
+        assert_eq!("20,3 °C", decimal_temperature.format_human(Language::FR));
+        let temperature_delta = SlotValue::Temperature(TemperatureValue {
+            value: 2.0,
+            unit: None,
+            mode: TemperatureMode::Delta,
+        });
+        assert_eq!("Δ+2°", temperature_delta.to_string());
+        assert_eq!("from 7 June 2017 to 8 June 2017", interval.to_string());
+        let evening = SlotValue::TimeInterval(TimeIntervalValue {
+            from: Some("2017-06-07 18:00:00 +02:00".to_string()),
+            to: Some("2017-06-08 00:00:00 +02:00".to_string()),
+            to_inclusive: false,
+        });
+        let inclusive_interval = SlotValue::TimeInterval(TimeIntervalValue {
+            to_inclusive: true,
+            ..interval.as_time_interval().unwrap().clone()
+        });
+        assert_eq!(
+            "from 7 June 2017 at 6 pm to 8 June 2017 at 12 am",
+            evening.to_string()
+        );
+        assert_eq!(
+            "from 7 June 2017 to 9 June 2017",
+            inclusive_interval.to_string()
+        );
+    }
+
+    #[test]
+    fn test_to_surface() {
+        // Given
+        let duration = SlotValue::Duration(DurationValue {
+            years: 0,
+            quarters: 0,
+            months: 3,
+            weeks: 0,
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            precision: Precision::Exact,
+        });
+        let spoken = SurfaceStyle::Spoken(VerbalizationOptions::default());
+
+        // Then
+        assert_eq!(
+            "3 mois",
+            duration
+                .to_surface(Language::FR, SurfaceStyle::Written)
+                .unwrap()
+        );
+        assert_eq!(
+            "trois mois",
+            duration.to_surface(Language::FR, spoken).unwrap()
+        );
+        assert!(duration.to_surface(Language::JA, spoken).is_err());
+    }
+
+    #[test]
+    fn test_format_numeric_values() {
+        // Given
+        let ordinal = SlotValue::Ordinal(OrdinalValue {
+            value: 2,
+            direction: OrdinalDirection::FromEnd,
+        });
+        let amount = SlotValue::AmountOfMoney(AmountOfMoneyValue::from_minor_units(
+            1005,
+            Precision::Exact,
+            Some("$".to_string()),
+        ));
+        let percentage = SlotValue::Percentage(PercentageValue { value: 12.5 });
+
+        // Then
+        assert_eq!("2nd to last", ordinal.to_string());
+        assert_eq!(
+            "2e en partant de la fin",
+            ordinal.format_human(Language::FR)
+        );
+        assert_eq!("$10.05", amount.to_string());
+        assert_eq!("10,05 $", amount.format_human(Language::ES));
+        let debt = SlotValue::AmountOfMoney(AmountOfMoneyValue::from_minor_units(
+            -5,
+            Precision::Exact,
+            Some("$".to_string()),
+        ));
+        let largest_debt = AmountOfMoneyValue::from_minor_units(i64::MIN, Precision::Exact, None);
+        assert_eq!("-$0.05", debt.to_string());
+        assert_eq!("-0,05 $", debt.format_human(Language::FR));
+        assert_eq!(
+            SlotValue::from_canonical(SlotValueKind::AmountOfMoney, &debt.to_string()).unwrap(),
+            debt
+        );
+        assert_eq!(
+            "-92,233,720,368,547,758.08",
+            largest_debt.format(Language::EN)
+        );
+        assert_eq!("12,5 %", percentage.format_human(Language::DE));
+    }
+
+    #[test]
+    fn test_format_relative_change() {
+        // Given
+        let cooler = SlotValue::RelativeChange(RelativeChangeValue {
+            direction: ChangeDirection::Decrease,
+            value: Some(2.5),
+            unit: Some("degree".to_string()),
+            magnitude: None,
+        });
+        let a_bit_louder = SlotValue::RelativeChange(RelativeChangeValue {
+            direction: ChangeDirection::Increase,
+            value: None,
+            unit: None,
+            magnitude: Some(ChangeMagnitude::Slight),
+        });
+
+        // Then
+        assert_eq!("-2.5 degree", cooler.to_string());
+        assert_eq!("-2,5 degree", cooler.format_human(Language::FR));
+        assert_eq!("a bit more", a_bit_louder.to_string());
+        assert_eq!("etwas mehr", a_bit_louder.format_human(Language::DE));
+    }
+
+    #[test]
+    fn test_locale_aware_number_formatting() {
+        // Given
+        let number = NumberValue { value: -1234567.5 };
+        let small_number = NumberValue { value: 1234.0 };
+        let amount = AmountOfMoneyValue::new(1234.5, "€");
+
+        // Then
+        assert_eq!("-1,234,567.5", number.format(Language::EN));
+        assert_eq!("-1.234.567,5", number.format(Language::DE));
+        assert_eq!("-1\u{202f}234\u{202f}567,5", number.format(Language::FR));
+        assert_eq!("1,234", small_number.format(Language::EN));
+        assert_eq!("1234", small_number.format(Language::ES));
+        assert_eq!("1,234.50 €", amount.format(Language::EN));
+        assert_eq!("1.234,50 €", amount.format(Language::DE));
+    }
+}
@@ -0,0 +1,319 @@
+//! Parsing of geographic coordinates, written in decimal degrees, in degrees, minutes and seconds,
+//! or spoken in English
+use crate::errors::*;
+use crate::ontology::GeoCoordinateValue;
+use failure::format_err;
+
+impl GeoCoordinateValue {
+    /// Parses a pair of coordinates, e.g. "48.8566, 2.3522", "48°51'24\"N 2°21'8\"E" or
+    /// "forty eight point eight north, two point three east"
+    ///
+    /// The latitude comes first unless hemispheres are specified.
+    pub fn from_coordinates(coordinates: &str) -> Result<Self> {
+        let invalid_coordinates = || format_err!("Invalid coordinates: '{}'", coordinates);
+        let tokens = tokenize(coordinates);
+        let mut parsed = vec![];
+        let mut current = Coordinate::default();
+        let mut i = 0;
+        while i < tokens.len() {
+            let token = tokens[i].as_str();
+            if let Some((number, consumed)) = parse_number(&tokens[i..]) {
+                if current.components.len() == 3 {
+                    return Err(invalid_coordinates());
+                }
+                current.components.push(number);
+                i += consumed;
+                continue;
+            }
+            match token {
+                "deg" | "degree" | "degrees" => current.set_last_unit(0),
+                "min" | "minute" | "minutes" => current.set_last_unit(1),
+                "sec" | "second" | "seconds" => current.set_last_unit(2),
+                "," | "and" => {
+                    if !current.components.is_empty() {
+                        parsed.push(current);
+                        current = Coordinate::default();
+                    }
+                }
+                _ => {
+                    let hemisphere =
+                        Hemisphere::from_word(token).ok_or_else(invalid_coordinates)?;
+                    if current.components.is_empty() {
+                        // The hemisphere precedes the number, as in "N 48.8566"
+                        current.hemisphere = Some(hemisphere);
+                    } else if current.hemisphere.is_none() {
+                        current.hemisphere = Some(hemisphere);
+                        parsed.push(current);
+                        current = Coordinate::default();
+                    } else {
+                        // The hemisphere precedes the next number, as in "S 22.9 W 43.1"
+                        parsed.push(current);
+                        current = Coordinate {
+                            hemisphere: Some(hemisphere),
+                            ..Coordinate::default()
+                        };
+                    }
+                }
+            }
+            i += 1;
+        }
+        if !current.components.is_empty() {
+            parsed.push(current);
+        }
+        if parsed.len() != 2 {
+            return Err(invalid_coordinates());
+        }
+        let (latitude, longitude) = match (parsed[0].hemisphere, parsed[1].hemisphere) {
+            (Some(Hemisphere::East), _)
+            | (Some(Hemisphere::West), _)
+            | (_, Some(Hemisphere::North))
+            | (_, Some(Hemisphere::South)) => (&parsed[1], &parsed[0]),
+            _ => (&parsed[0], &parsed[1]),
+        };
+        if latitude.hemisphere.is_some_and(|h| !h.is_latitude())
+            || longitude.hemisphere.is_some_and(|h| h.is_latitude())
+        {
+            return Err(invalid_coordinates());
+        }
+        let latitude = latitude.degrees().ok_or_else(invalid_coordinates)?;
+        let longitude = longitude.degrees().ok_or_else(invalid_coordinates)?;
+        if latitude.abs() > 90. || longitude.abs() > 180. {
+            return Err(invalid_coordinates());
+        }
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Hemisphere {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Hemisphere {
+    fn from_word(word: &str) -> Option<Self> {
+        Some(match word {
+            "n" | "north" => Hemisphere::North,
+            "s" | "south" => Hemisphere::South,
+            "e" | "east" => Hemisphere::East,
+            "w" | "west" => Hemisphere::West,
+            _ => return None,
+        })
+    }
+
+    fn is_latitude(self) -> bool {
+        self == Hemisphere::North || self == Hemisphere::South
+    }
+}
+
+#[derive(Debug, Default)]
+struct Coordinate {
+    /// Degrees, minutes and seconds, the last two being optional
+    components: Vec<f64>,
+    /// Units of the components, as indices within degrees, minutes and seconds
+    units: Vec<usize>,
+    hemisphere: Option<Hemisphere>,
+}
+
+impl Coordinate {
+    fn set_last_unit(&mut self, unit: usize) {
+        if !self.components.is_empty() {
+            self.units.resize(self.components.len() - 1, usize::MAX);
+            self.units.push(unit);
+        }
+    }
+
+    /// Signed decimal degrees, or `None` when the units are inconsistent
+    fn degrees(&self) -> Option<f64> {
+        let mut degrees = 0.;
+        let mut previous_unit = None;
+        for (i, component) in self.components.iter().enumerate() {
+            let unit = match self.units.get(i).cloned() {
+                Some(unit) if unit != usize::MAX => unit,
+                _ => previous_unit.map_or(0, |unit| unit + 1),
+            };
+            if unit > 2 || previous_unit.is_some_and(|previous| unit <= previous) {
+                return None;
+            }
+            if unit > 0 && (*component < 0. || *component >= 60.) {
+                return None;
+            }
+            degrees += component.abs() / 60f64.powi(unit as i32);
+            previous_unit = Some(unit);
+        }
+        let is_negative = self
+            .components
+            .first()
+            .is_some_and(|c| c.is_sign_negative())
+            || self.hemisphere == Some(Hemisphere::South)
+            || self.hemisphere == Some(Hemisphere::West);
+        Some(if is_negative { -degrees } else { degrees })
+    }
+}
+
+/// Splits the coordinates into lowercase words, numbers and symbols, the degree, minute and
+/// second symbols being replaced by "deg", "min" and "sec"
+fn tokenize(coordinates: &str) -> Vec<String> {
+    let mut spaced = String::with_capacity(coordinates.len());
+    let mut previous: Option<char> = None;
+    for c in coordinates.to_lowercase().chars() {
+        match c {
+            '°' | 'º' => spaced.push_str(" deg "),
+            '\'' | '′' | '’' => spaced.push_str(" min "),
+            '"' | '″' | '”' => spaced.push_str(" sec "),
+            ',' | ';' => spaced.push_str(" , "),
+            _ => {
+                let is_boundary = previous.is_some_and(|p| {
+                    (p.is_ascii_digit() && c.is_alphabetic())
+                        || (p.is_alphabetic() && c.is_ascii_digit())
+                });
+                if is_boundary {
+                    spaced.push(' ');
+                }
+                spaced.push(c);
+            }
+        }
+        previous = Some(c);
+    }
+    spaced.split_whitespace().map(|t| t.to_string()).collect()
+}
+
+/// Parses a number at the start of the tokens, written with digits or spoken in English, and
+/// returns it along with the number of tokens used
+fn parse_number(tokens: &[String]) -> Option<(f64, usize)> {
+    if let Some(number) = tokens[0].parse::<f64>().ok().filter(|n| n.is_finite()) {
+        return Some((number, 1));
+    }
+    let (sign, start) = match tokens[0].as_str() {
+        "minus" | "negative" => (-1., 1),
+        _ => (1., 0),
+    };
+    let mut integer = 0;
+    let mut current = 0;
+    let mut i = start;
+    let mut has_integer = false;
+    while let Some(token) = tokens.get(i) {
+        if let Some(value) = spoken_integer(token) {
+            current += value;
+        } else if token == "hundred" && has_integer {
+            current *= 100;
+        } else if token == "thousand" && has_integer {
+            integer += current * 1000;
+            current = 0;
+        } else {
+            break;
+        }
+        has_integer = true;
+        i += 1;
+    }
+    let mut number = (integer + current) as f64;
+    if tokens.get(i).map(|t| t.as_str()) == Some("point") {
+        let mut scale = 0.1;
+        let mut j = i + 1;
+        while let Some(digit) = tokens
+            .get(j)
+            .and_then(|t| spoken_integer(t))
+            .filter(|d| *d < 10)
+        {
+            number += digit as f64 * scale;
+            scale /= 10.;
+            j += 1;
+        }
+        if j > i + 1 {
+            has_integer = true;
+            i = j;
+        }
+    }
+    if has_integer {
+        Some((sign * number, i))
+    } else {
+        None
+    }
+}
+
+fn spoken_integer(word: &str) -> Option<u32> {
+    Some(match word {
+        "zero" | "oh" => 0,
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_coordinates(latitude: f64, longitude: f64, coordinates: &str) {
+        let value = GeoCoordinateValue::from_coordinates(coordinates).unwrap();
+        assert!(
+            (value.latitude - latitude).abs() < 1e-4,
+            "{}: {:?}",
+            coordinates,
+            value
+        );
+        assert!(
+            (value.longitude - longitude).abs() < 1e-4,
+            "{}: {:?}",
+            coordinates,
+            value
+        );
+    }
+
+    #[test]
+    fn test_geo_coordinate_from_coordinates() {
+        assert_coordinates(48.8566, 2.3522, "48.8566, 2.3522");
+        assert_coordinates(-33.8688, 151.2093, "33.8688° S, 151.2093° E");
+        assert_coordinates(48.8567, 2.3522, "48°51'24\"N 2°21'8\"E");
+        assert_coordinates(-22.9068, -43.1729, "W 43.1729 S 22.9068");
+        assert_coordinates(
+            48.8,
+            2.3,
+            "forty eight point eight north, two point three east",
+        );
+        assert_coordinates(
+            40.75,
+            -73.5,
+            "forty degrees forty five minutes north and seventy three degrees thirty minutes west",
+        );
+    }
+
+    #[test]
+    fn test_invalid_geo_coordinate() {
+        assert!(GeoCoordinateValue::from_coordinates("48.8566").is_err());
+        assert!(GeoCoordinateValue::from_coordinates("91 N, 2 E").is_err());
+        assert!(GeoCoordinateValue::from_coordinates("48 N, 2 S").is_err());
+        assert!(GeoCoordinateValue::from_coordinates("48°75' N, 2 E").is_err());
+        assert!(GeoCoordinateValue::from_coordinates("somewhere north").is_err());
+    }
+}
@@ -0,0 +1,158 @@
+//! Payloads of the hermes NLU messages, as published on the MQTT bus
+use crate::errors::*;
+use crate::ontology::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json;
+
+pub const NLU_QUERY_TOPIC: &str = "hermes/nlu/query";
+pub const NLU_INTENT_PARSED_TOPIC: &str = "hermes/nlu/intentParsed";
+pub const NLU_INTENT_NOT_RECOGNIZED_TOPIC: &str = "hermes/nlu/intentNotRecognized";
+
+/// A message which is published on a single, statically known, hermes topic
+pub trait HermesMessage: Serialize + DeserializeOwned {
+    const TOPIC: &'static str;
+
+    fn to_payload(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    fn from_payload(payload: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(payload)?)
+    }
+}
+
+/// Request sent to the NLU component in order to parse an input
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NluQueryMessage {
+    /// The text to parse
+    pub input: String,
+    /// Restricts the parsing to these intents
+    #[serde(default, alias = "intent_filter")]
+    pub intent_filter: Option<Vec<String>>,
+    /// Identifier of the request, copied in the response
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default, alias = "session_id")]
+    pub session_id: Option<String>,
+}
+
+impl HermesMessage for NluQueryMessage {
+    const TOPIC: &'static str = NLU_QUERY_TOPIC;
+}
+
+/// Response of the NLU component when an intent has been detected
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NluIntentParsedMessage {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub input: String,
+    pub intent: IntentClassifierResult,
+    pub slots: Vec<Slot>,
+    #[serde(default)]
+    pub alternatives: Vec<IntentParserAlternative>,
+    #[serde(default, alias = "session_id")]
+    pub session_id: Option<String>,
+}
+
+impl NluIntentParsedMessage {
+    pub fn from_intent_parser_result(
+        result: IntentParserResult,
+        id: Option<String>,
+        session_id: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            input: result.input,
+            intent: result.intent,
+            slots: result.slots,
+            alternatives: result.alternatives,
+            session_id,
+        }
+    }
+}
+
+impl HermesMessage for NluIntentParsedMessage {
+    const TOPIC: &'static str = NLU_INTENT_PARSED_TOPIC;
+}
+
+/// Response of the NLU component when no intent has been detected
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NluIntentNotRecognizedMessage {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub input: String,
+    #[serde(default, alias = "confidence_score")]
+    pub confidence_score: Option<f32>,
+    #[serde(default, alias = "session_id")]
+    pub session_id: Option<String>,
+}
+
+impl HermesMessage for NluIntentNotRecognizedMessage {
+    const TOPIC: &'static str = NLU_INTENT_NOT_RECOGNIZED_TOPIC;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nlu_query_message_from_payload() {
+        // Given
+        let payload = br#"{"input": "turn on the lights", "sessionId": "abc"}"#;
+
+        // When
+        let message = NluQueryMessage::from_payload(payload).unwrap();
+
+        // Then
+        let expected_message = NluQueryMessage {
+            input: "turn on the lights".to_string(),
+            intent_filter: None,
+            id: None,
+            session_id: Some("abc".to_string()),
+        };
+        assert_eq!(expected_message, message);
+        assert_eq!("hermes/nlu/query", NluQueryMessage::TOPIC);
+    }
+
+    #[test]
+    fn test_nlu_intent_parsed_message_round_trip() {
+        // Given
+        let result = IntentParserResult {
+            input: "set the temperature to 21 degrees".to_string(),
+            intent: IntentClassifierResult {
+                intent_name: Some("SetTemperature".to_string()),
+                confidence_score: 0.8,
+            },
+            slots: vec![Slot {
+                raw_value: "21 degrees".to_string(),
+                value: SlotValue::Temperature(TemperatureValue {
+                    value: 21.0,
+                    unit: None,
+                    mode: TemperatureMode::Absolute,
+                }),
+                alternatives: vec![],
+                range: 23..33,
+                entity: "snips/temperature".to_string(),
+                slot_name: "temperature".to_string(),
+                confidence_score: None,
+            }],
+            alternatives: vec![],
+        };
+        let message =
+            NluIntentParsedMessage::from_intent_parser_result(result, Some("42".to_string()), None);
+
+        // When
+        let payload = message.to_payload().unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+        let deserialized = NluIntentParsedMessage::from_payload(&payload).unwrap();
+
+        // Then
+        assert_eq!("SetTemperature", json["intent"]["intentName"]);
+        assert_eq!("temperature", json["slots"][0]["slotName"]);
+        assert_eq!(message, deserialized);
+    }
+}
@@ -1,5 +1,5 @@
+use crate::errors::*;
 use crate::language_enum;
-use failure::bail;
 
 language_enum!([DE, EN, ES, FR, IT, PT_PT, PT_BR, JA, KO]);
 
@@ -17,6 +17,32 @@ impl Language {
             Language::KO => "Korean",
         }
     }
+
+    /// Stable numeric code of the language, to be used in binary protocols
+    ///
+    /// Codes are never reassigned: new languages get new codes, regardless of their position in
+    /// the enum.
+    pub fn to_code(&self) -> u32 {
+        match *self {
+            Language::DE => 0,
+            Language::EN => 1,
+            Language::ES => 2,
+            Language::FR => 3,
+            Language::IT => 4,
+            Language::PT_PT => 5,
+            Language::PT_BR => 6,
+            Language::JA => 7,
+            Language::KO => 8,
+        }
+    }
+
+    pub fn from_code(code: u32) -> Result<Self> {
+        Language::all()
+            .iter()
+            .find(|language| language.to_code() == code)
+            .cloned()
+            .ok_or_else(|| format_err!("Unknown language code: {}", code))
+    }
 }
 
 #[cfg(test)]
@@ -35,4 +61,35 @@ mod test {
         let lang = Language::from_str("EN");
         assert!(lang.is_ok());
     }
+
+    #[test]
+    fn language_codes_round_trip() {
+        for language in Language::all() {
+            assert_eq!(*language, Language::from_code(language.to_code()).unwrap());
+        }
+        assert!(Language::from_code(1000).is_err());
+    }
+
+    #[test]
+    fn language_ser_de_uses_bcp_47_codes() {
+        // When
+        let serialized = serde_json::to_string(&[Language::PT_BR, Language::FR]).unwrap();
+        let deserialized: Vec<Language> =
+            serde_json::from_str(r#"["fr", "FR", "pt-BR", "pt_br", "PT_PT"]"#).unwrap();
+
+        // Then
+        assert_eq!(r#"["pt-BR","fr"]"#, serialized);
+        assert_eq!(
+            vec![
+                Language::FR,
+                Language::FR,
+                Language::PT_BR,
+                Language::PT_BR,
+                Language::PT_PT
+            ],
+            deserialized
+        );
+        assert_eq!("ja", format!("{}", Language::JA));
+        assert_eq!("pt_br", format!("{}", Language::PT_BR));
+    }
 }
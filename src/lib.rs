@@ -1,13 +1,67 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 #[macro_use]
 extern crate serde_derive;
 
+#[cfg(feature = "std")]
+pub mod annotation;
+#[cfg(all(feature = "std", feature = "proptest"))]
+pub mod arbitrary;
+#[cfg(feature = "std")]
+pub mod canonical;
+#[cfg(feature = "std")]
+pub mod codes;
+#[cfg(feature = "std")]
+pub mod context;
+#[cfg(feature = "std")]
+pub mod cooking;
+#[cfg(feature = "std")]
+pub mod datetime;
+#[cfg(all(feature = "std", feature = "bigdecimal"))]
+pub mod decimal;
+#[cfg(feature = "std")]
+pub mod dialect;
+#[cfg(feature = "std")]
+pub mod docs;
 pub mod entity;
 pub mod errors;
+#[cfg(feature = "std")]
+pub mod format;
+#[cfg(feature = "std")]
+pub mod geo;
+#[cfg(feature = "std")]
+pub mod hermes;
 pub mod language;
 pub mod macros;
 mod ontology;
-pub use entity::builtin_entity::{BuiltinEntity, BuiltinEntityKind, IntoBuiltinEntityKind};
+#[cfg(feature = "std")]
+pub mod preserved;
+#[cfg(feature = "std")]
+pub mod reference;
+#[cfg(feature = "std")]
+pub mod verbalization;
+pub use entity::builtin_entity::{
+    sort_builtin_entities, BuiltinEntity, BuiltinEntityKind, EntityKindGroup, IntoBuiltinEntityKind,
+};
+#[cfg(feature = "std")]
+pub use entity::catalog::*;
+#[cfg(feature = "std")]
+pub use entity::compound::*;
 pub use entity::gazetteer_entity::*;
 pub use entity::grammar_entity::*;
+#[cfg(feature = "std")]
+pub use entity::list::*;
+#[cfg(feature = "std")]
+pub use entity::overlap::*;
 pub use language::*;
 pub use ontology::*;
+
+/// Items of the standard prelude which are missing from the core one, for the modules which build
+/// without `std`, also used with fully qualified paths by the exported macros
+#[doc(hidden)]
+pub mod prelude {
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+    pub use alloc::{format, vec};
+}
@@ -1,7 +1,8 @@
 #[macro_export]
 macro_rules! language_enum {
     ([$($language:ident),*]) => {
-        #[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Hash, Eq)]
+        /// Supported languages, serialized as their BCP 47 code, e.g. `"en"` or `"pt-BR"`
+        #[derive(Debug, Copy, Clone, PartialEq, Hash, Eq)]
         #[allow(non_camel_case_types)]
         pub enum Language {
             $( $language, )*
@@ -12,12 +13,26 @@ macro_rules! language_enum {
                 static ALL: &[Language] = &[$( Language::$language ),*];
                 ALL
             }
+
+            /// BCP 47 code of the language, made of its ISO 639-1 code followed by its region if
+            /// any, e.g. `"en"` or `"pt-BR"`
+            pub fn code(&self) -> $crate::prelude::String {
+                let name = match self {
+                    $(
+                        &Language::$language => stringify!($language),
+                    )*
+                };
+                match name.find('_') {
+                    Some(index) => $crate::prelude::format!("{}-{}", name[..index].to_lowercase(), &name[index + 1..]),
+                    None => name.to_lowercase(),
+                }
+            }
         }
 
-        impl ::std::str::FromStr for Language {
-            type Err=::failure::Error;
-            fn from_str(s: &str) -> ::std::result::Result<Language, Self::Err> {
-                match &*s.to_uppercase() {
+        impl ::core::str::FromStr for Language {
+            type Err=$crate::errors::Error;
+            fn from_str(s: &str) -> ::core::result::Result<Language, Self::Err> {
+                match &*s.to_uppercase().replace('-', "_") {
                     $(
                         stringify!($language) => Ok(Language::$language),
                     )*
@@ -26,15 +41,37 @@ macro_rules! language_enum {
             }
         }
 
-        impl ::std::string::ToString for Language {
-            fn to_string(&self) -> String {
+        impl ::core::fmt::Display for Language {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                 match self {
                     $(
-                        &Language::$language => stringify!($language).to_lowercase(),
+                        &Language::$language => write!(f, "{}", stringify!($language).to_lowercase()),
                     )*
                 }
             }
         }
+
+        impl ::serde::Serialize for Language {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(&self.code())
+            }
+        }
+
+        /// Codes are case insensitive, and regions may be separated by an underscore, so that
+        /// the uppercase codes used before languages were serialized as BCP 47 codes, e.g.
+        /// `"PT_BR"`, are accepted
+        impl<'de> ::serde::Deserialize<'de> for Language {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Language, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let code = <$crate::prelude::String as ::serde::Deserialize>::deserialize(deserializer)?;
+                code.parse().map_err(::serde::de::Error::custom)
+            }
+        }
     }
 }
 
@@ -53,23 +90,23 @@ macro_rules! enum_kind {
             }
         }
 
-        impl ::std::str::FromStr for $kindname {
-            type Err=String;
-            fn from_str(s: &str) -> ::std::result::Result<$kindname, Self::Err> {
+        impl ::core::str::FromStr for $kindname {
+            type Err=$crate::prelude::String;
+            fn from_str(s: &str) -> ::core::result::Result<$kindname, Self::Err> {
                 match s {
                     $(
                         stringify!($varname) => Ok($kindname::$varname),
                     )*
-                    _ => Err(format!("{} is not a known {}", s, stringify!($kindname)))
+                    _ => Err($crate::prelude::format!("{} is not a known {}", s, stringify!($kindname)))
                 }
             }
         }
 
-        impl ::std::string::ToString for $kindname {
-            fn to_string(&self) -> String {
+        impl $crate::prelude::ToString for $kindname {
+            fn to_string(&self) -> $crate::prelude::String {
                 match self {
                     $(
-                        &$kindname::$varname => stringify!($varname).to_string(),
+                        &$kindname::$varname => $crate::prelude::String::from(stringify!($varname)),
                     )*
                 }
             }
@@ -98,23 +135,23 @@ macro_rules! sub_entity_kind {
             }
         }
 
-        impl ::std::str::FromStr for $kindname {
-            type Err=String;
-            fn from_str(s: &str) -> ::std::result::Result<$kindname, Self::Err> {
+        impl ::core::str::FromStr for $kindname {
+            type Err=$crate::prelude::String;
+            fn from_str(s: &str) -> ::core::result::Result<$kindname, Self::Err> {
                 match s {
                     $(
                         stringify!($varname) => Ok($kindname::$varname),
                     )*
-                    _ => Err(format!("{} is not a known {}", s, stringify!($kindname)))
+                    _ => Err($crate::prelude::format!("{} is not a known {}", s, stringify!($kindname)))
                 }
             }
         }
 
-        impl ::std::string::ToString for $kindname {
-            fn to_string(&self) -> String {
+        impl $crate::prelude::ToString for $kindname {
+            fn to_string(&self) -> $crate::prelude::String {
                 match self {
                     $(
-                        &$kindname::$varname => stringify!($varname).to_string(),
+                        &$kindname::$varname => $crate::prelude::String::from(stringify!($varname)),
                     )*
                 }
             }
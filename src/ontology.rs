@@ -1,6 +1,9 @@
-use std::ops::Range;
+use crate::errors::*;
+use crate::prelude::*;
+use core::ops::Range;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct IntentParserResult {
     pub input: String,
     pub intent: IntentClassifierResult,
@@ -9,34 +12,126 @@ pub struct IntentParserResult {
     pub alternatives: Vec<IntentParserAlternative>,
 }
 
+impl IntentParserResult {
+    pub fn new(input: String, intent: IntentClassifierResult, slots: Vec<Slot>) -> Self {
+        Self {
+            input,
+            intent,
+            slots,
+            alternatives: vec![],
+        }
+    }
+
+    pub fn with_alternatives(self, alternatives: Vec<IntentParserAlternative>) -> Self {
+        Self {
+            alternatives,
+            ..self
+        }
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn intent(&self) -> &IntentClassifierResult {
+        &self.intent
+    }
+
+    pub fn slots(&self) -> &[Slot] {
+        &self.slots
+    }
+
+    pub fn alternatives(&self) -> &[IntentParserAlternative] {
+        &self.alternatives
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct IntentParserAlternative {
     pub intent: IntentClassifierResult,
     pub slots: Vec<Slot>,
 }
 
+impl IntentParserAlternative {
+    pub fn new(intent: IntentClassifierResult, slots: Vec<Slot>) -> Self {
+        Self { intent, slots }
+    }
+
+    pub fn intent(&self) -> &IntentClassifierResult {
+        &self.intent
+    }
+
+    pub fn slots(&self) -> &[Slot] {
+        &self.slots
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct IntentClassifierResult {
+    #[serde(alias = "intent_name")]
     pub intent_name: Option<String>,
+    #[serde(alias = "confidence_score")]
     pub confidence_score: f32,
 }
 
+impl IntentClassifierResult {
+    pub fn new(intent_name: Option<String>, confidence_score: f32) -> Self {
+        Self {
+            intent_name,
+            confidence_score,
+        }
+    }
+
+    pub fn intent_name(&self) -> Option<&str> {
+        self.intent_name.as_deref()
+    }
+
+    pub fn confidence_score(&self) -> f32 {
+        self.confidence_score
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct Slot {
+    #[serde(alias = "raw_value")]
     pub raw_value: String,
     pub value: SlotValue,
     #[serde(default)]
     pub alternatives: Vec<SlotValue>,
     pub range: Range<usize>,
     pub entity: String,
+    #[serde(alias = "slot_name")]
     pub slot_name: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "confidence_score")]
     pub confidence_score: Option<f32>,
 }
 
 impl Slot {
+    /// Builds a slot without alternatives nor confidence score, which can be added using
+    /// `with_alternatives` and `with_confidence_score`
+    pub fn new(
+        raw_value: String,
+        value: SlotValue,
+        range: Range<usize>,
+        entity: String,
+        slot_name: String,
+    ) -> Slot {
+        Slot {
+            raw_value,
+            value,
+            alternatives: vec![],
+            range,
+            entity,
+            slot_name,
+            confidence_score: None,
+        }
+    }
+
     pub fn new_custom(
         value: String,
         range: Range<usize>,
@@ -69,6 +164,48 @@ impl Slot {
             confidence_score: self.confidence_score,
         }
     }
+
+    pub fn with_alternatives(self, alternatives: Vec<SlotValue>) -> Slot {
+        Slot {
+            alternatives,
+            ..self
+        }
+    }
+
+    pub fn with_confidence_score(self, confidence_score: f32) -> Slot {
+        Slot {
+            confidence_score: Some(confidence_score),
+            ..self
+        }
+    }
+
+    pub fn raw_value(&self) -> &str {
+        &self.raw_value
+    }
+
+    pub fn value(&self) -> &SlotValue {
+        &self.value
+    }
+
+    pub fn alternatives(&self) -> &[SlotValue] {
+        &self.alternatives
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    pub fn entity(&self) -> &str {
+        &self.entity
+    }
+
+    pub fn slot_name(&self) -> &str {
+        &self.slot_name
+    }
+
+    pub fn confidence_score(&self) -> Option<f32> {
+        self.confidence_score
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -89,25 +226,220 @@ pub enum SlotValue {
     City(StringValue),
     Country(StringValue),
     Region(StringValue),
+    RelativeChange(RelativeChangeValue),
+    AlphanumericCode(StringValue),
+    FlightNumber(FlightNumberValue),
+    ProductCode(ProductCodeValue),
+    IpAddress(StringValue),
+    Hostname(StringValue),
+    Hashtag(StringValue),
+    Mention(StringValue),
+    Version(VersionValue),
+    MediaPosition(MediaPositionValue),
+    Score(ScoreValue),
+    CookingQuantity(CookingQuantityValue),
+    BloodPressure(BloodPressureValue),
+    Dosage(DosageValue),
+    GeoCoordinate(GeoCoordinateValue),
+    Reference(ReferenceValue),
+}
+
+/// Kind of a `SlotValue`, without its content
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Hash, Eq)]
+pub enum SlotValueKind {
+    Custom,
+    Number,
+    Ordinal,
+    Percentage,
+    InstantTime,
+    TimeInterval,
+    AmountOfMoney,
+    Temperature,
+    Duration,
+    MusicAlbum,
+    MusicArtist,
+    MusicTrack,
+    City,
+    Country,
+    Region,
+    RelativeChange,
+    AlphanumericCode,
+    FlightNumber,
+    ProductCode,
+    IpAddress,
+    Hostname,
+    Hashtag,
+    Mention,
+    Version,
+    MediaPosition,
+    Score,
+    CookingQuantity,
+    BloodPressure,
+    Dosage,
+    GeoCoordinate,
+    Reference,
+}
+
+macro_rules! slot_value_accessors {
+    ($($accessor:ident => $variant:ident($value_type:ty),)*) => {
+        $(
+            pub fn $accessor(&self) -> Option<&$value_type> {
+                match self {
+                    SlotValue::$variant(value) => Some(value),
+                    _ => None,
+                }
+            }
+        )*
+    };
+}
+
+impl SlotValue {
+    pub fn kind(&self) -> SlotValueKind {
+        match *self {
+            SlotValue::Custom(_) => SlotValueKind::Custom,
+            SlotValue::Number(_) => SlotValueKind::Number,
+            SlotValue::Ordinal(_) => SlotValueKind::Ordinal,
+            SlotValue::Percentage(_) => SlotValueKind::Percentage,
+            SlotValue::InstantTime(_) => SlotValueKind::InstantTime,
+            SlotValue::TimeInterval(_) => SlotValueKind::TimeInterval,
+            SlotValue::AmountOfMoney(_) => SlotValueKind::AmountOfMoney,
+            SlotValue::Temperature(_) => SlotValueKind::Temperature,
+            SlotValue::Duration(_) => SlotValueKind::Duration,
+            SlotValue::MusicAlbum(_) => SlotValueKind::MusicAlbum,
+            SlotValue::MusicArtist(_) => SlotValueKind::MusicArtist,
+            SlotValue::MusicTrack(_) => SlotValueKind::MusicTrack,
+            SlotValue::City(_) => SlotValueKind::City,
+            SlotValue::Country(_) => SlotValueKind::Country,
+            SlotValue::Region(_) => SlotValueKind::Region,
+            SlotValue::RelativeChange(_) => SlotValueKind::RelativeChange,
+            SlotValue::AlphanumericCode(_) => SlotValueKind::AlphanumericCode,
+            SlotValue::FlightNumber(_) => SlotValueKind::FlightNumber,
+            SlotValue::ProductCode(_) => SlotValueKind::ProductCode,
+            SlotValue::IpAddress(_) => SlotValueKind::IpAddress,
+            SlotValue::Hostname(_) => SlotValueKind::Hostname,
+            SlotValue::Hashtag(_) => SlotValueKind::Hashtag,
+            SlotValue::Mention(_) => SlotValueKind::Mention,
+            SlotValue::Version(_) => SlotValueKind::Version,
+            SlotValue::MediaPosition(_) => SlotValueKind::MediaPosition,
+            SlotValue::Score(_) => SlotValueKind::Score,
+            SlotValue::CookingQuantity(_) => SlotValueKind::CookingQuantity,
+            SlotValue::BloodPressure(_) => SlotValueKind::BloodPressure,
+            SlotValue::Dosage(_) => SlotValueKind::Dosage,
+            SlotValue::GeoCoordinate(_) => SlotValueKind::GeoCoordinate,
+            SlotValue::Reference(_) => SlotValueKind::Reference,
+        }
+    }
+
+    slot_value_accessors! {
+        as_custom => Custom(StringValue),
+        as_number => Number(NumberValue),
+        as_ordinal => Ordinal(OrdinalValue),
+        as_percentage => Percentage(PercentageValue),
+        as_instant_time => InstantTime(InstantTimeValue),
+        as_time_interval => TimeInterval(TimeIntervalValue),
+        as_amount_of_money => AmountOfMoney(AmountOfMoneyValue),
+        as_temperature => Temperature(TemperatureValue),
+        as_duration => Duration(DurationValue),
+        as_music_album => MusicAlbum(StringValue),
+        as_music_artist => MusicArtist(StringValue),
+        as_music_track => MusicTrack(StringValue),
+        as_city => City(StringValue),
+        as_country => Country(StringValue),
+        as_region => Region(StringValue),
+        as_relative_change => RelativeChange(RelativeChangeValue),
+        as_alphanumeric_code => AlphanumericCode(StringValue),
+        as_flight_number => FlightNumber(FlightNumberValue),
+        as_product_code => ProductCode(ProductCodeValue),
+        as_ip_address => IpAddress(StringValue),
+        as_hostname => Hostname(StringValue),
+        as_hashtag => Hashtag(StringValue),
+        as_mention => Mention(StringValue),
+        as_version => Version(VersionValue),
+        as_media_position => MediaPosition(MediaPositionValue),
+        as_score => Score(ScoreValue),
+        as_cooking_quantity => CookingQuantity(CookingQuantityValue),
+        as_blood_pressure => BloodPressure(BloodPressureValue),
+        as_dosage => Dosage(DosageValue),
+        as_geo_coordinate => GeoCoordinate(GeoCoordinateValue),
+        as_reference => Reference(ReferenceValue),
+    }
+
+    /// Looks up a field of the value by its path in the JSON representation of the slot value,
+    /// e.g. "value", "unit" or "kind"
+    ///
+    /// Nested fields are separated with dots and array elements are accessed by index, e.g.
+    /// "externalIds.0.id". Keys can be written in either camelCase or snake_case. This allows
+    /// dynamic rules to inspect slot values without matching on their kind.
+    #[cfg(feature = "std")]
+    pub fn get(&self, path: &str) -> Option<serde_json::Value> {
+        let mut value = serde_json::to_value(self).ok()?;
+        for key in path.split('.') {
+            value = match value {
+                serde_json::Value::Object(mut map) => {
+                    map.remove(&crate::dialect::to_camel_case(key))?
+                }
+                serde_json::Value::Array(mut values) => {
+                    let index = key.parse::<usize>().ok()?;
+                    if index >= values.len() {
+                        return None;
+                    }
+                    values.swap_remove(index)
+                }
+                _ => return None,
+            };
+        }
+        Some(value)
+    }
 }
 
 /// This struct is required in order to use serde Internally tagged enum representation
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct StringValue {
     pub value: String,
+    /// Identifiers of the resolved value in external knowledge bases, which gazetteer entities
+    /// such as cities or music artists may provide
+    #[cfg(feature = "entity_linking")]
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        rename = "externalIds",
+        alias = "external_ids"
+    )]
+    pub external_ids: Vec<ExternalId>,
+}
+
+#[cfg(feature = "entity_linking")]
+impl StringValue {
+    pub fn with_external_ids(self, external_ids: Vec<ExternalId>) -> Self {
+        StringValue {
+            value: self.value,
+            external_ids,
+        }
+    }
+}
+
+/// Stable identifier of an entity in an external knowledge base
+#[cfg(feature = "entity_linking")]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExternalId {
+    /// Name of the knowledge base, e.g. "geonames" or "musicbrainz"
+    pub source: String,
+    pub id: String,
 }
 
 impl From<String> for StringValue {
     fn from(string: String) -> Self {
-        StringValue { value: string }
+        StringValue {
+            value: string,
+            #[cfg(feature = "entity_linking")]
+            external_ids: vec![],
+        }
     }
 }
 
 impl From<&'static str> for StringValue {
     fn from(str: &str) -> Self {
-        StringValue {
-            value: str.to_string(),
-        }
+        StringValue::from(str.to_string())
     }
 }
 
@@ -119,6 +451,17 @@ pub struct NumberValue {
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
 pub struct OrdinalValue {
     pub value: i64,
+    /// Whether the position is counted from the start or from the end, e.g. "second to last"
+    /// is resolved as a value of 2 counted from the end
+    #[serde(default)]
+    pub direction: OrdinalDirection,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Copy, Clone, Default)]
+pub enum OrdinalDirection {
+    #[default]
+    FromStart,
+    FromEnd,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
@@ -126,6 +469,16 @@ pub struct PercentageValue {
     pub value: f64,
 }
 
+impl PercentageValue {
+    /// Clamps the percentage between 0 and 100, as expected for levels such as the volume or the
+    /// brightness of a device
+    pub fn clamped(&self) -> PercentageValue {
+        PercentageValue {
+            value: self.value.clamp(0., 100.),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct InstantTimeValue {
     pub value: String,
@@ -134,22 +487,115 @@ pub struct InstantTimeValue {
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct TimeIntervalValue {
     pub from: Option<String>,
     pub to: Option<String>,
+    /// Whether the `to` bound belongs to the interval, e.g. "until 5 pm" as opposed to
+    /// "before 5 pm"
+    #[serde(default, alias = "to_inclusive")]
+    pub to_inclusive: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase", from = "RawAmountOfMoneyValue")]
 pub struct AmountOfMoneyValue {
     pub value: f32,
     pub precision: Precision,
     pub unit: Option<String>,
+    /// The amount expressed as an integer number of minor units of the currency (e.g. cents),
+    /// which is not subject to floating point rounding when the amount is built from its minor
+    /// units or parsed with `SlotValue::from_canonical`
+    pub minor_units: i64,
+}
+
+impl AmountOfMoneyValue {
+    /// Builds an exact amount of money, e.g. `AmountOfMoneyValue::new(10.0, "EUR")`
+    ///
+    /// The minor units are rounded from the `f32` value, which only holds about 7 significant
+    /// digits, so that large amounts may be off by a few minor units: use `from_minor_units` when
+    /// the exact amount is known.
+    pub fn new<S: Into<String>>(value: f32, unit: S) -> Self {
+        let unit = unit.into();
+        Self {
+            value,
+            precision: Precision::Exact,
+            minor_units: to_minor_units(value.into(), Some(&unit)),
+            unit: Some(unit),
+        }
+    }
+
+    pub fn from_minor_units(minor_units: i64, precision: Precision, unit: Option<String>) -> Self {
+        let exponent = minor_unit_exponent(unit.as_deref());
+        Self {
+            value: (minor_units as f64 / 10_f64.powi(exponent as i32)) as f32,
+            precision,
+            unit,
+            minor_units,
+        }
+    }
+
+    /// Number of decimal digits separating the major unit of the currency from its minor unit
+    pub fn minor_unit_exponent(&self) -> u32 {
+        minor_unit_exponent(self.unit.as_deref())
+    }
+}
+
+pub(crate) fn minor_unit_exponent(unit: Option<&str>) -> u32 {
+    match unit {
+        Some("¥") | Some("JPY") | Some("₩") | Some("KRW") | Some("cent") => 0,
+        Some("BHD") | Some("JOD") | Some("KWD") | Some("OMR") | Some("TND") => 3,
+        _ => 2,
+    }
+}
+
+pub(crate) fn to_minor_units(value: f64, unit: Option<&str>) -> i64 {
+    (value * 10_f64.powi(minor_unit_exponent(unit) as i32)).round() as i64
+}
+
+/// Payloads produced before the introduction of `minor_units` do not contain it, in which case
+/// it is computed from the floating value, read with double precision so that amounts up to
+/// about 15 significant digits get exact minor units
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAmountOfMoneyValue {
+    value: f64,
+    precision: Precision,
+    unit: Option<String>,
+    #[serde(default, alias = "minor_units")]
+    minor_units: Option<i64>,
+}
+
+impl From<RawAmountOfMoneyValue> for AmountOfMoneyValue {
+    fn from(raw: RawAmountOfMoneyValue) -> Self {
+        let minor_units = raw
+            .minor_units
+            .unwrap_or_else(|| to_minor_units(raw.value, raw.unit.as_deref()));
+        Self {
+            value: raw.value as f32,
+            precision: raw.precision,
+            unit: raw.unit,
+            minor_units,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct TemperatureValue {
+    /// The temperature, or the signed size of the change for deltas
     pub value: f32,
     pub unit: Option<String>,
+    /// Whether the value is a temperature, e.g. "set it to 20 degrees", or a change of
+    /// temperature, e.g. "raise it by 2 degrees"
+    #[serde(default)]
+    pub mode: TemperatureMode,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum TemperatureMode {
+    #[default]
+    Absolute,
+    Delta,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -165,6 +611,272 @@ pub struct DurationValue {
     pub precision: Precision,
 }
 
+impl DurationValue {
+    /// Builds an exact duration of the given number of seconds
+    ///
+    /// The duration is not normalized: `DurationValue::from_seconds(90)` has 90 seconds and no
+    /// minute, which is how "90 seconds" is resolved. The other components can be set using
+    /// struct update syntax, e.g. `DurationValue { hours: 1, ..DurationValue::from_minutes(30) }`.
+    pub fn from_seconds(seconds: i64) -> Self {
+        Self {
+            years: 0,
+            quarters: 0,
+            months: 0,
+            weeks: 0,
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds,
+            precision: Precision::Exact,
+        }
+    }
+
+    /// Builds an exact duration of the given number of seconds, split into hours, minutes and
+    /// seconds as on a clock, e.g. 4830 seconds give 1 hour, 20 minutes and 30 seconds
+    pub fn from_clock_seconds(seconds: i64) -> Self {
+        Self {
+            hours: seconds / 3600,
+            minutes: seconds % 3600 / 60,
+            ..Self::from_seconds(seconds % 60)
+        }
+    }
+
+    /// Builds an exact duration of the given number of minutes
+    pub fn from_minutes(minutes: i64) -> Self {
+        Self {
+            minutes,
+            ..Self::from_seconds(0)
+        }
+    }
+
+    /// Builds an exact duration of the given number of hours
+    pub fn from_hours(hours: i64) -> Self {
+        Self {
+            hours,
+            ..Self::from_seconds(0)
+        }
+    }
+
+    /// Builds an exact duration of the given number of days
+    pub fn from_days(days: i64) -> Self {
+        Self {
+            days,
+            ..Self::from_seconds(0)
+        }
+    }
+}
+
+/// Position within a media being played, e.g. "skip to one hour twenty", or offset from the
+/// current position, e.g. "go back 30 seconds"
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct MediaPositionValue {
+    pub mode: MediaPositionMode,
+    /// Number of seconds from the start of the media for positions, or from the current position
+    /// for offsets, negative offsets going backwards
+    pub seconds: i64,
+}
+
+impl MediaPositionValue {
+    /// Duration between the start of the media, or the current position, and the resolved
+    /// position, split into hours, minutes and seconds
+    pub fn duration(&self) -> DurationValue {
+        // The magnitude of `i64::MIN` does not fit in an `i64`, but its hours do
+        let seconds = self.seconds.unsigned_abs();
+        DurationValue {
+            hours: (seconds / 3600) as i64,
+            minutes: (seconds % 3600 / 60) as i64,
+            ..DurationValue::from_seconds((seconds % 60) as i64)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum MediaPositionMode {
+    Absolute,
+    Offset,
+}
+
+/// Change of a quantity relative to its current value, e.g. "increase by 3" or "a bit warmer"
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RelativeChangeValue {
+    pub direction: ChangeDirection,
+    /// Size of the change, which is never negative and is absent for vague changes such as
+    /// "a bit warmer"
+    pub value: Option<f64>,
+    pub unit: Option<String>,
+    /// Magnitude of vague changes, e.g. `Slight` for "a bit warmer" or `Large` for "a lot louder"
+    pub magnitude: Option<ChangeMagnitude>,
+}
+
+impl RelativeChangeValue {
+    /// Signed size of the change, which is negative for decreases
+    pub fn delta(&self) -> Option<f64> {
+        self.value.map(|value| match self.direction {
+            ChangeDirection::Increase => value,
+            ChangeDirection::Decrease => -value,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChangeDirection {
+    Increase,
+    Decrease,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChangeMagnitude {
+    Slight,
+    Large,
+}
+
+/// Flight number, e.g. "AF1234", made of the code of the airline and of the number of the flight
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct FlightNumberValue {
+    /// IATA code of the airline, e.g. "AF", or ICAO code, e.g. "AFR"
+    pub carrier: String,
+    pub number: u32,
+}
+
+/// Barcode-style product number, such as an ISBN or an EAN
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ProductCodeValue {
+    pub format: ProductCodeFormat,
+    /// The digits of the code, without separators, the check character of ISBN-10 codes being
+    /// possibly an "X"
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProductCodeFormat {
+    Isbn10,
+    Isbn13,
+    Ean13,
+}
+
+/// Quantity of an ingredient, e.g. "two tablespoons", along with its metric equivalent
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CookingQuantityValue {
+    pub value: f64,
+    pub unit: CookingUnit,
+    /// Equivalent of the quantity in milliliters for volumes, and in grams for weights
+    #[serde(alias = "metric_value")]
+    pub metric_value: f64,
+    #[serde(alias = "metric_unit")]
+    pub metric_unit: CookingMetricUnit,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CookingUnit {
+    Cup,
+    Tablespoon,
+    Teaspoon,
+    Pinch,
+    Dash,
+    FluidOunce,
+    Milliliter,
+    Centiliter,
+    Deciliter,
+    Liter,
+    Ounce,
+    Pound,
+    Gram,
+    Kilogram,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CookingMetricUnit {
+    Milliliter,
+    Gram,
+}
+
+/// Blood pressure, e.g. "one twenty over eighty", in millimeters of mercury
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BloodPressureValue {
+    pub systolic: u32,
+    pub diastolic: u32,
+}
+
+/// Dose of a medication, e.g. "two 500 milligram tablets"
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DosageValue {
+    /// Number of units taken, e.g. 2 for "two tablets", which is 1 when not specified
+    pub count: f64,
+    /// Amount of active ingredient per unit, e.g. 500 for "500 milligram tablets"
+    pub dose: Option<f64>,
+    /// Unit of the dose, e.g. "mg"
+    pub unit: Option<String>,
+    /// Form of the medication, e.g. "tablet"
+    pub form: Option<String>,
+}
+
+/// Geographic coordinates, in decimal degrees, southern latitudes and western longitudes being
+/// negative
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct GeoCoordinateValue {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Anaphora standing for a value which was not said, e.g. "the same time" or "that one", which
+/// dialogue management resolves using the context of the conversation
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ReferenceValue {
+    pub anaphora: Anaphora,
+}
+
+/// Way in which an anaphora refers to a value
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Anaphora {
+    /// Value mentioned before, e.g. "the same time"
+    Same,
+    /// Value pointed at, e.g. "that one"
+    Demonstrative,
+    /// Value differing from the one mentioned before, e.g. "another one"
+    Other,
+}
+
+/// Score of a match, e.g. "three two" or "2-1", in the order in which it was said
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScoreValue {
+    pub first: u32,
+    pub second: u32,
+}
+
+impl ScoreValue {
+    /// Scores of the home team and of the away team, given the order in which the score was said
+    pub fn home_and_away(&self, order: ScoreOrder) -> (u32, u32) {
+        match order {
+            ScoreOrder::HomeFirst => (self.first, self.second),
+            ScoreOrder::AwayFirst => (self.second, self.first),
+        }
+    }
+}
+
+/// Order in which the scores of the two teams are said
+///
+/// Most countries list the home team first, which is the default, including English speaking
+/// ones such as the United Kingdom, Ireland, Australia or India. North American scoreboards list
+/// the away team first: as languages carry no region, that order must be chosen by the caller,
+/// e.g. from the locale of the user.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum ScoreOrder {
+    #[default]
+    HomeFirst,
+    AwayFirst,
+}
+
+/// Version number, e.g. "v2.3.1"
+///
+/// Missing components are set to 0, so that "version 2" resolves to 2.0.0.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct VersionValue {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
 pub enum Grain {
     Year = 0,
@@ -177,18 +889,321 @@ pub enum Grain {
     Second = 7,
 }
 
+impl Grain {
+    /// Stable numeric code of the grain, to be used in binary protocols
+    pub fn to_code(&self) -> u32 {
+        *self as u32
+    }
+
+    pub fn from_code(code: u32) -> Result<Self> {
+        match code {
+            0 => Ok(Grain::Year),
+            1 => Ok(Grain::Quarter),
+            2 => Ok(Grain::Month),
+            3 => Ok(Grain::Week),
+            4 => Ok(Grain::Day),
+            5 => Ok(Grain::Hour),
+            6 => Ok(Grain::Minute),
+            7 => Ok(Grain::Second),
+            _ => bail!("Unknown grain code: {}", code),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Copy, Clone)]
 pub enum Precision {
     Approximate,
     Exact,
 }
 
+impl Precision {
+    /// Stable numeric code of the precision, to be used in binary protocols
+    pub fn to_code(&self) -> u32 {
+        match *self {
+            Precision::Approximate => 0,
+            Precision::Exact => 1,
+        }
+    }
+
+    pub fn from_code(code: u32) -> Result<Self> {
+        match code {
+            0 => Ok(Precision::Approximate),
+            1 => Ok(Precision::Exact),
+            _ => bail!("Unknown precision code: {}", code),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use serde_json;
+    use serde_json::{self, json};
 
     use super::*;
 
+    #[test]
+    fn test_slot_value_kind_and_accessors() {
+        // Given
+        let slot_value = SlotValue::Temperature(TemperatureValue {
+            value: 23.0,
+            unit: Some("celsius".to_string()),
+            mode: TemperatureMode::Absolute,
+        });
+
+        // Then
+        assert_eq!(SlotValueKind::Temperature, slot_value.kind());
+        assert_eq!(Some(23.0), slot_value.as_temperature().map(|t| t.value));
+        assert_eq!(None, slot_value.as_number());
+        assert_eq!(None, slot_value.as_custom());
+    }
+
+    #[cfg(feature = "entity_linking")]
+    #[test]
+    fn test_string_value_external_ids_ser_de() {
+        // Given
+        let city =
+            SlotValue::City(
+                StringValue::from("Paris").with_external_ids(vec![ExternalId {
+                    source: "geonames".to_string(),
+                    id: "2988507".to_string(),
+                }]),
+            );
+
+        // When
+        let serialized = serde_json::to_value(&city).unwrap();
+        let deserialized: SlotValue = serde_json::from_value(serialized.clone()).unwrap();
+        let without_ids: SlotValue =
+            serde_json::from_str(r#"{"kind": "City", "value": "Paris"}"#).unwrap();
+
+        // Then
+        assert_eq!("2988507", serialized["externalIds"][0]["id"]);
+        assert_eq!(city, deserialized);
+        assert_eq!(SlotValue::City("Paris".into()), without_ids);
+    }
+
+    #[test]
+    fn test_deserializing_amount_of_money_without_minor_units() {
+        // Given
+        let amount_json =
+            r#"{"kind": "AmountOfMoney", "value": 12.3, "precision": "Exact", "unit": "€"}"#;
+
+        // When
+        let deserialized: SlotValue = serde_json::from_str(amount_json).unwrap();
+
+        // Then
+        let expected_value = SlotValue::AmountOfMoney(AmountOfMoneyValue {
+            value: 12.3,
+            precision: Precision::Exact,
+            unit: Some("€".to_string()),
+            minor_units: 1230,
+        });
+        assert_eq!(expected_value, deserialized);
+    }
+
+    #[test]
+    fn test_deserializing_large_amount_of_money_without_minor_units() {
+        // Given
+        let amount_json = r#"{"value": 1234567.89, "precision": "Exact", "unit": "€"}"#;
+
+        // When
+        let deserialized: AmountOfMoneyValue = serde_json::from_str(amount_json).unwrap();
+
+        // Then
+        assert_eq!(123456789, deserialized.minor_units);
+    }
+
+    #[test]
+    fn test_media_position_duration() {
+        // Given
+        let offset = |seconds| MediaPositionValue {
+            mode: MediaPositionMode::Offset,
+            seconds,
+        };
+
+        // When
+        let duration = offset(-4830).duration();
+        let longest_duration = offset(i64::MIN).duration();
+
+        // Then
+        assert_eq!(
+            DurationValue {
+                hours: 1,
+                minutes: 20,
+                ..DurationValue::from_seconds(30)
+            },
+            duration
+        );
+        assert_eq!(2_562_047_788_015_215, longest_duration.hours);
+        assert_eq!(8, longest_duration.seconds);
+    }
+
+    #[test]
+    fn test_amount_of_money_from_minor_units() {
+        // When
+        let euros =
+            AmountOfMoneyValue::from_minor_units(1999, Precision::Exact, Some("EUR".into()));
+        let yens = AmountOfMoneyValue::from_minor_units(1999, Precision::Exact, Some("¥".into()));
+
+        // Then
+        assert_eq!(19.99, euros.value);
+        assert_eq!(1999., yens.value);
+    }
+
+    #[test]
+    fn test_score_home_and_away() {
+        // Given
+        let score = ScoreValue {
+            first: 3,
+            second: 2,
+        };
+
+        // When/Then
+        assert_eq!((3, 2), score.home_and_away(ScoreOrder::default()));
+        assert_eq!((2, 3), score.home_and_away(ScoreOrder::AwayFirst));
+    }
+
+    #[test]
+    fn test_slot_value_get() {
+        // Given
+        let amount = SlotValue::AmountOfMoney(AmountOfMoneyValue::new(10.5, "EUR"));
+        let custom = SlotValue::Custom("blue".into());
+
+        // Then
+        assert_eq!(Some(json!("EUR")), amount.get("unit"));
+        assert_eq!(Some(json!(1050)), amount.get("minor_units"));
+        assert_eq!(Some(json!("AmountOfMoney")), amount.get("kind"));
+        assert_eq!(Some(json!("blue")), custom.get("value"));
+        assert_eq!(None, custom.get("unit"));
+        assert_eq!(None, custom.get("value.length"));
+    }
+
+    #[test]
+    fn test_slot_builder() {
+        // When
+        let slot = Slot::new(
+            "three".to_string(),
+            SlotValue::Number(NumberValue { value: 3.0 }),
+            0..5,
+            "snips/number".to_string(),
+            "count".to_string(),
+        )
+        .with_confidence_score(0.8);
+
+        // Then
+        let expected_slot = Slot {
+            raw_value: "three".to_string(),
+            value: SlotValue::Number(NumberValue { value: 3.0 }),
+            alternatives: vec![],
+            range: 0..5,
+            entity: "snips/number".to_string(),
+            slot_name: "count".to_string(),
+            confidence_score: Some(0.8),
+        };
+        assert_eq!(expected_slot, slot);
+    }
+
+    #[test]
+    fn test_intent_parser_result_accessors() {
+        // Given
+        let slot = Slot::new(
+            "three".to_string(),
+            SlotValue::Number(NumberValue { value: 3.0 }),
+            0..5,
+            "snips/number".to_string(),
+            "count".to_string(),
+        );
+        let intent = IntentClassifierResult::new(Some("setTimer".to_string()), 0.9);
+
+        // When
+        let result = IntentParserResult::new("three timers".to_string(), intent, vec![slot]);
+
+        // Then
+        assert_eq!("three timers", result.input());
+        assert_eq!(Some("setTimer"), result.intent().intent_name());
+        assert_eq!(0.9, result.intent().confidence_score());
+        assert_eq!("count", result.slots()[0].slot_name());
+        assert_eq!(0..5, result.slots()[0].range());
+        assert_eq!(None, result.slots()[0].confidence_score());
+        assert!(result.alternatives().is_empty());
+    }
+
+    #[test]
+    fn test_clamped_percentage() {
+        assert_eq!(100., PercentageValue { value: 120. }.clamped().value);
+        assert_eq!(0., PercentageValue { value: -5. }.clamped().value);
+        assert_eq!(42.5, PercentageValue { value: 42.5 }.clamped().value);
+    }
+
+    #[test]
+    fn test_relative_change_delta() {
+        // Given
+        let cooler = RelativeChangeValue {
+            direction: ChangeDirection::Decrease,
+            value: Some(2.),
+            unit: Some("degree".to_string()),
+            magnitude: None,
+        };
+        let a_bit_warmer = RelativeChangeValue {
+            direction: ChangeDirection::Increase,
+            value: None,
+            unit: None,
+            magnitude: Some(ChangeMagnitude::Slight),
+        };
+
+        // Then
+        assert_eq!(Some(-2.), cooler.delta());
+        assert_eq!(None, a_bit_warmer.delta());
+    }
+
+    #[test]
+    fn test_semantic_constructors() {
+        // When
+        let amount = AmountOfMoneyValue::new(10.5, "EUR");
+        let duration = DurationValue::from_minutes(90);
+
+        // Then
+        assert_eq!(
+            AmountOfMoneyValue::from_minor_units(1050, Precision::Exact, Some("EUR".into())),
+            amount
+        );
+        assert_eq!(90, duration.minutes);
+        assert_eq!(0, duration.hours);
+        assert_eq!(Precision::Exact, duration.precision);
+    }
+
+    #[test]
+    fn test_deserializing_time_interval_with_exclusive_default() {
+        // Given
+        let interval_json =
+            r#"{"kind": "TimeInterval", "from": null, "to": "2017-06-07 17:00:00 +02:00"}"#;
+
+        // When
+        let deserialized: SlotValue = serde_json::from_str(interval_json).unwrap();
+
+        // Then
+        let expected_value = SlotValue::TimeInterval(TimeIntervalValue {
+            from: None,
+            to: Some("2017-06-07 17:00:00 +02:00".to_string()),
+            to_inclusive: false,
+        });
+        assert_eq!(expected_value, deserialized);
+    }
+
+    #[test]
+    fn test_deserializing_ordinal_with_default_direction() {
+        // Given
+        let ordinal_json = r#"{"kind": "Ordinal", "value": 3}"#;
+
+        // When
+        let deserialized: SlotValue = serde_json::from_str(ordinal_json).unwrap();
+
+        // Then
+        let expected_value = SlotValue::Ordinal(OrdinalValue {
+            value: 3,
+            direction: OrdinalDirection::FromStart,
+        });
+        assert_eq!(expected_value, deserialized);
+    }
+
     #[test]
     fn test_deserializing_with_default_alternatives() {
         // Given
@@ -0,0 +1,199 @@
+//! Lossless deserialization of ontology payloads
+//!
+//! Payloads produced by a more recent version of the ontology may contain fields which are
+//! unknown to the current version, and which are dropped by a regular deserialization.
+//! Wrapping the payload type in `Preserved` keeps these fields, so that they can be relayed.
+use crate::dialect::{to_camel_case, to_snake_case};
+use serde::de::{DeserializeOwned, Error as DeError};
+use serde::ser::Error as SerError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{self, Map, Value};
+use std::ops::{Deref, DerefMut};
+
+/// Wrapper around a deserializable type which preserves the unknown fields of the payload and
+/// serializes them back
+#[derive(Debug, Clone, PartialEq)]
+pub struct Preserved<T> {
+    pub value: T,
+    /// Fields of the original payload which are not part of `value`, if any
+    pub unknown_fields: Option<Value>,
+}
+
+impl<T> Preserved<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            unknown_fields: None,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Preserved<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Preserved<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Serialize> Serialize for Preserved<T> {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut json = serde_json::to_value(&self.value).map_err(S::Error::custom)?;
+        if let Some(ref unknown_fields) = self.unknown_fields {
+            merge_unknown_fields(&mut json, unknown_fields);
+        }
+        json.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Preserved<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = Value::deserialize(deserializer)?;
+        let value: T = serde_json::from_value(raw.clone()).map_err(D::Error::custom)?;
+        let known = serde_json::to_value(&value).map_err(D::Error::custom)?;
+        Ok(Self {
+            value,
+            unknown_fields: unknown_fields(&raw, &known),
+        })
+    }
+}
+
+/// Computes the parts of `raw` which do not appear in `known`
+///
+/// Unknown fields of array elements are stored at the same index, with `null` for the elements
+/// which do not have any.
+fn unknown_fields(raw: &Value, known: &Value) -> Option<Value> {
+    match (raw, known) {
+        (Value::Object(raw_map), Value::Object(known_map)) => {
+            let unknown = raw_map
+                .iter()
+                .filter_map(|(key, raw_value)| match known_field(known_map, key) {
+                    Some(known_value) => {
+                        unknown_fields(raw_value, known_value).map(|unknown| (key.clone(), unknown))
+                    }
+                    None => Some((key.clone(), raw_value.clone())),
+                })
+                .collect::<Map<String, Value>>();
+            if unknown.is_empty() {
+                None
+            } else {
+                Some(Value::Object(unknown))
+            }
+        }
+        (Value::Array(raw_values), Value::Array(known_values))
+            if raw_values.len() == known_values.len() =>
+        {
+            let unknown = raw_values
+                .iter()
+                .zip(known_values)
+                .map(|(r, k)| unknown_fields(r, k).unwrap_or(Value::Null))
+                .collect::<Vec<_>>();
+            if unknown.iter().all(Value::is_null) {
+                None
+            } else {
+                Some(Value::Array(unknown))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Fields are also looked up in the alternative naming convention, as the ontology types
+/// accept both camelCase and snake_case keys
+fn known_field<'a>(known_map: &'a Map<String, Value>, key: &str) -> Option<&'a Value> {
+    known_map
+        .get(key)
+        .or_else(|| known_map.get(&to_camel_case(key)))
+        .or_else(|| known_map.get(&to_snake_case(key)))
+}
+
+fn merge_unknown_fields(json: &mut Value, unknown: &Value) {
+    match (json, unknown) {
+        (Value::Object(map), Value::Object(unknown_map)) => {
+            for (key, unknown_value) in unknown_map {
+                match map.get_mut(key) {
+                    Some(value) => merge_unknown_fields(value, unknown_value),
+                    None => {
+                        map.insert(key.clone(), unknown_value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(values), Value::Array(unknown_values)) => {
+            for (value, unknown_value) in values.iter_mut().zip(unknown_values) {
+                merge_unknown_fields(value, unknown_value);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::*;
+
+    #[test]
+    fn test_unknown_fields_are_preserved() {
+        // Given
+        let payload = r#"{
+            "input": "turn on the light",
+            "intent": {"intentName": "TurnOn", "confidenceScore": 0.8, "origin": "classifier"},
+            "slots": [
+                {
+                    "rawValue": "light",
+                    "value": {"kind": "Custom", "value": "light", "synonyms": ["lamp"]},
+                    "range": {"start": 12, "end": 17},
+                    "entity": "device",
+                    "slot_name": "device"
+                }
+            ],
+            "alternatives": [],
+            "modelVersion": "1.2.0"
+        }"#;
+
+        // When
+        let result: Preserved<IntentParserResult> = serde_json::from_str(payload).unwrap();
+        let json = serde_json::to_value(&result).unwrap();
+
+        // Then
+        assert_eq!(Some("TurnOn"), result.intent.intent_name.as_deref());
+        assert_eq!("1.2.0", json["modelVersion"]);
+        assert_eq!("classifier", json["intent"]["origin"]);
+        assert_eq!("lamp", json["slots"][0]["value"]["synonyms"][0]);
+        assert_eq!("device", json["slots"][0]["slotName"]);
+        assert!(json["slots"][0].get("slot_name").is_none());
+    }
+
+    #[test]
+    fn test_no_unknown_fields() {
+        // Given
+        let payload = r#"{"kind": "Number", "value": 3.0}"#;
+
+        // When
+        let value: Preserved<SlotValue> = serde_json::from_str(payload).unwrap();
+
+        // Then
+        let expected_value = Preserved::new(SlotValue::Number(NumberValue { value: 3.0 }));
+        assert_eq!(expected_value, value);
+    }
+}
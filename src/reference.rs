@@ -0,0 +1,133 @@
+//! Recognition of anaphoras, such as "the same time" or "that one", standing for slot values
+use crate::language::Language;
+use crate::ontology::{Anaphora, ReferenceValue};
+
+impl ReferenceValue {
+    /// Recognizes the anaphora used in the given words, e.g. "the same time", "celui-là" or
+    /// "otro"
+    ///
+    /// Only the English, French, German and Spanish vocabularies are available. Words marking a
+    /// different value take precedence, so that "that other one" refers to another value.
+    pub fn from_anaphora(anaphora: &str, language: Language) -> Option<Self> {
+        let (same, demonstrative, other): (&[&str], &[&str], &[&str]) = match language {
+            Language::DE => (
+                &[
+                    "gleiche",
+                    "gleichen",
+                    "gleicher",
+                    "gleiches",
+                    "selbe",
+                    "selben",
+                    "dieselbe",
+                    "denselben",
+                    "derselbe",
+                    "dasselbe",
+                ],
+                &[
+                    "diese", "dieser", "dieses", "diesen", "jene", "jener", "jenes",
+                ],
+                &["andere", "anderen", "anderer", "anderes", "anderem"],
+            ),
+            Language::EN => (
+                &["same", "usual", "again"],
+                &["this", "that", "these", "those"],
+                &["other", "another", "different", "else"],
+            ),
+            Language::ES => (
+                &["mismo", "misma", "mismos", "mismas"],
+                &[
+                    "este", "esta", "esto", "ese", "esa", "eso", "aquel", "aquella", "aquello",
+                ],
+                &[
+                    "otro",
+                    "otra",
+                    "otros",
+                    "otras",
+                    "diferente",
+                    "distinto",
+                    "distinta",
+                ],
+            ),
+            Language::FR => (
+                &["même", "mêmes", "habitude"],
+                &[
+                    "ce", "cet", "cette", "ces", "celui", "celle", "ceux", "celles", "ça", "cela",
+                ],
+                &["autre", "autres", "différent", "différente"],
+            ),
+            _ => return None,
+        };
+        let anaphora = anaphora.to_lowercase();
+        let words = anaphora
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>();
+        let contains_any = |cues: &[&str]| words.iter().any(|word| cues.contains(word));
+        let anaphora = if contains_any(other) {
+            Anaphora::Other
+        } else if contains_any(same) {
+            Anaphora::Same
+        } else if contains_any(demonstrative) {
+            Anaphora::Demonstrative
+        } else {
+            return None;
+        };
+        Some(Self { anaphora })
+    }
+}
+
+impl Anaphora {
+    /// Short phrase standing for a value referred to in this way, e.g. "the same"
+    pub(crate) fn phrase(&self, language: Language) -> &'static str {
+        match (language, self) {
+            (Language::DE, Anaphora::Same) => "der gleiche",
+            (Language::DE, Anaphora::Demonstrative) => "dieser",
+            (Language::DE, Anaphora::Other) => "ein anderer",
+            (Language::ES, Anaphora::Same) => "el mismo",
+            (Language::ES, Anaphora::Demonstrative) => "ese",
+            (Language::ES, Anaphora::Other) => "otro",
+            (Language::FR, Anaphora::Same) => "le même",
+            (Language::FR, Anaphora::Demonstrative) => "celui-là",
+            (Language::FR, Anaphora::Other) => "un autre",
+            (_, Anaphora::Same) => "the same",
+            (_, Anaphora::Demonstrative) => "that one",
+            (_, Anaphora::Other) => "another one",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anaphora(text: &str, language: Language) -> Option<Anaphora> {
+        ReferenceValue::from_anaphora(text, language).map(|reference| reference.anaphora)
+    }
+
+    #[test]
+    fn test_reference_from_anaphora() {
+        assert_eq!(
+            Some(Anaphora::Same),
+            anaphora("the same time", Language::EN)
+        );
+        assert_eq!(
+            Some(Anaphora::Demonstrative),
+            anaphora("That one", Language::EN)
+        );
+        assert_eq!(
+            Some(Anaphora::Other),
+            anaphora("that other one", Language::EN)
+        );
+        assert_eq!(
+            Some(Anaphora::Demonstrative),
+            anaphora("celui-là", Language::FR)
+        );
+        assert_eq!(
+            Some(Anaphora::Same),
+            anaphora("dieselbe Uhrzeit", Language::DE)
+        );
+        assert_eq!(Some(Anaphora::Other), anaphora("otro", Language::ES));
+        assert_eq!(None, anaphora("tomorrow", Language::EN));
+        assert_eq!(None, anaphora("the same", Language::JA));
+    }
+}
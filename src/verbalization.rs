@@ -0,0 +1,1094 @@
+//! Verbalization of slot values into text which can be read by a text-to-speech engine
+//!
+//! Verbalization is currently available in English and French.
+use crate::datetime::DatetimeComponents;
+use crate::errors::*;
+use crate::format::{vague_change, widen};
+use crate::language::Language;
+use crate::ontology::*;
+use failure::bail;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockFormat {
+    /// e.g. "six thirty pm"
+    #[default]
+    TwelveHour,
+    /// e.g. "eighteen thirty"
+    TwentyFourHour,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateFormat {
+    /// The year is verbalized, e.g. "June thirteenth twenty seventeen"
+    #[default]
+    Long,
+    /// The year is omitted, e.g. "June thirteenth"
+    Short,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VerbalizationOptions {
+    pub clock_format: ClockFormat,
+    pub date_format: DateFormat,
+}
+
+impl SlotValue {
+    /// Verbalizes the value, e.g. "twenty-three degrees Celsius"
+    ///
+    /// An error is returned when the language is not supported, or when the value contains an
+    /// invalid datetime.
+    pub fn verbalize(&self, language: Language, options: VerbalizationOptions) -> Result<String> {
+        let verbalizer: &dyn Verbalizer = match language {
+            Language::EN => &EnglishVerbalizer,
+            Language::FR => &FrenchVerbalizer,
+            _ => bail!("Verbalization is not supported in {}", language.full_name()),
+        };
+        Ok(match self {
+            SlotValue::Custom(value)
+            | SlotValue::MusicAlbum(value)
+            | SlotValue::MusicArtist(value)
+            | SlotValue::MusicTrack(value)
+            | SlotValue::City(value)
+            | SlotValue::Country(value)
+            | SlotValue::Region(value)
+            | SlotValue::IpAddress(value)
+            | SlotValue::Hostname(value)
+            | SlotValue::Hashtag(value)
+            | SlotValue::Mention(value) => value.value.clone(),
+            SlotValue::AlphanumericCode(code) => spell_out(&code.value),
+            SlotValue::Number(number) => verbalizer.decimal(number.value),
+            SlotValue::Ordinal(ordinal) => verbalizer.ordinal_value(ordinal),
+            SlotValue::Percentage(percentage) => verbalizer.percentage(percentage.value),
+            SlotValue::InstantTime(instant_time) => verbalize_datetime(
+                verbalizer,
+                &instant_time.value,
+                Some(instant_time.grain),
+                options,
+            )?,
+            SlotValue::TimeInterval(interval) => {
+                let from = match interval.from {
+                    Some(ref from) => Some(verbalize_datetime(verbalizer, from, None, options)?),
+                    None => None,
+                };
+                let to = match interval.displayed_to() {
+                    Some(ref to) => Some(verbalize_datetime(
+                        verbalizer,
+                        to,
+                        interval.displayed_to_grain(),
+                        options,
+                    )?),
+                    None => None,
+                };
+                verbalizer.interval(from, to)
+            }
+            SlotValue::AmountOfMoney(amount) => verbalizer.amount_of_money(amount),
+            SlotValue::Temperature(temperature) => match temperature.mode {
+                TemperatureMode::Absolute => verbalizer.temperature(temperature),
+                TemperatureMode::Delta => verbalizer.relative_change(
+                    if temperature.value < 0. {
+                        ChangeDirection::Decrease
+                    } else {
+                        ChangeDirection::Increase
+                    },
+                    widen(temperature.value.abs()),
+                    &temperature
+                        .unit
+                        .clone()
+                        .or_else(|| Some("degree".to_string())),
+                ),
+            },
+            SlotValue::Duration(duration) => verbalizer.duration(duration),
+            SlotValue::RelativeChange(change) => match change.value {
+                Some(value) => verbalizer.relative_change(change.direction, value, &change.unit),
+                None => vague_change(change.direction, change.magnitude, language).to_string(),
+            },
+            SlotValue::FlightNumber(flight) => {
+                spell_out(&format!("{}{}", flight.carrier, flight.number))
+            }
+            SlotValue::ProductCode(product_code) => spell_out(&product_code.value),
+            SlotValue::CookingQuantity(quantity) => format!(
+                "{} {}",
+                verbalizer.decimal(quantity.value),
+                quantity.unit.name(language, quantity.value != 1.)
+            ),
+            SlotValue::BloodPressure(pressure) => {
+                let connector = match language {
+                    Language::FR => "sur",
+                    _ => "over",
+                };
+                format!(
+                    "{} {} {}",
+                    verbalizer.decimal(pressure.systolic as f64),
+                    connector,
+                    verbalizer.decimal(pressure.diastolic as f64)
+                )
+            }
+            SlotValue::Dosage(_) | SlotValue::Reference(_) => self.format_human(language),
+            SlotValue::GeoCoordinate(coordinates) => {
+                let (degrees, north, south, east, west) = match language {
+                    Language::FR => ("degrés", "nord", "sud", "est", "ouest"),
+                    _ => ("degrees", "north", "south", "east", "west"),
+                };
+                format!(
+                    "{} {} {}, {} {} {}",
+                    verbalizer.decimal(coordinates.latitude.abs()),
+                    degrees,
+                    if coordinates.latitude < 0. {
+                        south
+                    } else {
+                        north
+                    },
+                    verbalizer.decimal(coordinates.longitude.abs()),
+                    degrees,
+                    if coordinates.longitude < 0. {
+                        west
+                    } else {
+                        east
+                    }
+                )
+            }
+            SlotValue::Score(score) => {
+                let connector = match language {
+                    Language::FR => "à",
+                    _ => "to",
+                };
+                format!(
+                    "{} {} {}",
+                    verbalizer.decimal(score.first as f64),
+                    connector,
+                    verbalizer.decimal(score.second as f64)
+                )
+            }
+            SlotValue::MediaPosition(position) => match position.mode {
+                MediaPositionMode::Absolute => verbalizer.duration(&position.duration()),
+                MediaPositionMode::Offset if position.seconds < 0 => verbalizer.relative_change(
+                    ChangeDirection::Decrease,
+                    -position.seconds as f64,
+                    &Some("second".to_string()),
+                ),
+                MediaPositionMode::Offset => verbalizer.relative_change(
+                    ChangeDirection::Increase,
+                    position.seconds as f64,
+                    &Some("second".to_string()),
+                ),
+            },
+            SlotValue::Version(version) => {
+                format!("{}.{}.{}", version.major, version.minor, version.patch)
+            }
+        })
+    }
+}
+
+fn verbalize_datetime(
+    verbalizer: &dyn Verbalizer,
+    datetime: &str,
+    grain: Option<Grain>,
+    options: VerbalizationOptions,
+) -> Result<String> {
+    let datetime = DatetimeComponents::parse(datetime)?;
+    let grain = grain.unwrap_or(if datetime.is_midnight() {
+        Grain::Day
+    } else {
+        Grain::Minute
+    });
+    Ok(match grain {
+        Grain::Year => verbalizer.year(datetime.year),
+        Grain::Quarter | Grain::Month => verbalizer.month(&datetime),
+        Grain::Week | Grain::Day => verbalizer.date(&datetime, options.date_format),
+        Grain::Hour | Grain::Minute | Grain::Second => verbalizer.date_and_time(
+            &verbalizer.date(&datetime, options.date_format),
+            &verbalizer.time_of_day(&datetime, options.clock_format),
+        ),
+    })
+}
+
+/// Digits of the decimal part of a number, as displayed
+fn decimal_digits(value: f64) -> String {
+    let formatted = format!("{}", value.abs());
+    formatted
+        .split('.')
+        .nth(1)
+        .map(|digits| digits.to_string())
+        .unwrap_or_default()
+}
+
+trait Verbalizer {
+    fn cardinal(&self, value: i64) -> String;
+    fn ordinal(&self, value: i64) -> String;
+    fn decimal(&self, value: f64) -> String;
+    fn ordinal_value(&self, ordinal: &OrdinalValue) -> String;
+    fn percentage(&self, value: f64) -> String;
+    fn year(&self, year: i32) -> String;
+    fn month(&self, datetime: &DatetimeComponents) -> String;
+    fn date(&self, datetime: &DatetimeComponents, date_format: DateFormat) -> String;
+    fn time_of_day(&self, datetime: &DatetimeComponents, clock_format: ClockFormat) -> String;
+    fn date_and_time(&self, date: &str, time: &str) -> String;
+    fn interval(&self, from: Option<String>, to: Option<String>) -> String;
+    fn amount_of_money(&self, amount: &AmountOfMoneyValue) -> String;
+    fn temperature(&self, temperature: &TemperatureValue) -> String;
+    fn duration(&self, duration: &DurationValue) -> String;
+    fn relative_change(
+        &self,
+        direction: ChangeDirection,
+        value: f64,
+        unit: &Option<String>,
+    ) -> String;
+}
+
+/// Spells codes out character by character, e.g. "A B 1 2"
+fn spell_out(code: &str) -> String {
+    code.chars()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Verbalizes the size of a relative change, using the temperature and percentage units when
+/// they apply
+fn change_size(verbalizer: &dyn Verbalizer, value: f64, unit: &Option<String>) -> String {
+    match unit.as_deref() {
+        Some("degree") | Some("celsius") | Some("fahrenheit") | Some("kelvin") => verbalizer
+            .temperature(&TemperatureValue {
+                value: value as f32,
+                unit: unit.clone(),
+                mode: TemperatureMode::Absolute,
+            }),
+        Some("%") | Some("percent") => verbalizer.percentage(value),
+        Some("second") => verbalizer.duration(&DurationValue::from_clock_seconds(value as i64)),
+        Some(unit) => format!("{} {}", verbalizer.decimal(value), unit),
+        None => verbalizer.decimal(value),
+    }
+}
+
+fn duration_components(duration: &DurationValue) -> Vec<(i64, Grain)> {
+    vec![
+        (duration.years, Grain::Year),
+        (duration.quarters, Grain::Quarter),
+        (duration.months, Grain::Month),
+        (duration.weeks, Grain::Week),
+        (duration.days, Grain::Day),
+        (duration.hours, Grain::Hour),
+        (duration.minutes, Grain::Minute),
+        (duration.seconds, Grain::Second),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count != 0)
+    .collect()
+}
+
+/// Joins items as in "a, b and c"
+fn enumerate(items: Vec<String>, and: &str) -> String {
+    match items.len() {
+        0 => String::new(),
+        1 => items[0].clone(),
+        n => format!("{} {} {}", items[..n - 1].join(", "), and, items[n - 1]),
+    }
+}
+
+const EN_UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const EN_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const EN_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+struct EnglishVerbalizer;
+
+impl EnglishVerbalizer {
+    fn below_thousand(&self, value: i64) -> String {
+        let (hundreds, rest) = (value / 100, value % 100);
+        let rest_words = match rest {
+            0 => None,
+            1..=19 => Some(EN_UNITS[rest as usize].to_string()),
+            _ if rest % 10 == 0 => Some(EN_TENS[(rest / 10) as usize].to_string()),
+            _ => Some(format!(
+                "{}-{}",
+                EN_TENS[(rest / 10) as usize],
+                EN_UNITS[(rest % 10) as usize]
+            )),
+        };
+        match (hundreds, rest_words) {
+            (0, Some(rest_words)) => rest_words,
+            (h, None) => format!("{} hundred", EN_UNITS[h as usize]),
+            (h, Some(rest_words)) => format!("{} hundred {}", EN_UNITS[h as usize], rest_words),
+        }
+    }
+}
+
+impl Verbalizer for EnglishVerbalizer {
+    fn cardinal(&self, value: i64) -> String {
+        if value == 0 {
+            return "zero".to_string();
+        }
+        if value < 0 {
+            return format!("minus {}", self.cardinal(-value));
+        }
+        let scales = [
+            (1_000_000_000_000, "trillion"),
+            (1_000_000_000, "billion"),
+            (1_000_000, "million"),
+            (1_000, "thousand"),
+        ];
+        let mut words = vec![];
+        let mut rest = value;
+        for (scale, scale_name) in scales.iter() {
+            if rest >= *scale {
+                words.push(format!("{} {}", self.cardinal(rest / scale), scale_name));
+                rest %= scale;
+            }
+        }
+        if rest > 0 {
+            words.push(self.below_thousand(rest));
+        }
+        words.join(" ")
+    }
+
+    fn ordinal(&self, value: i64) -> String {
+        let cardinal = self.cardinal(value);
+        let split_index = cardinal.rfind([' ', '-']).map(|i| i + 1).unwrap_or(0);
+        let (prefix, last_word) = cardinal.split_at(split_index);
+        let last_word = match last_word {
+            "one" => "first".to_string(),
+            "two" => "second".to_string(),
+            "three" => "third".to_string(),
+            "five" => "fifth".to_string(),
+            "eight" => "eighth".to_string(),
+            "nine" => "ninth".to_string(),
+            "twelve" => "twelfth".to_string(),
+            w if w.ends_with('y') => format!("{}ieth", &w[..w.len() - 1]),
+            w => format!("{}th", w),
+        };
+        format!("{}{}", prefix, last_word)
+    }
+
+    fn decimal(&self, value: f64) -> String {
+        let integer_part = self.cardinal(value.trunc() as i64);
+        let integer_part = if value < 0.0 && value.trunc() == 0.0 {
+            format!("minus {}", integer_part)
+        } else {
+            integer_part
+        };
+        let digits = decimal_digits(value);
+        if digits.is_empty() {
+            return integer_part;
+        }
+        let digits = digits
+            .chars()
+            .map(|d| EN_UNITS[d.to_digit(10).unwrap_or(0) as usize])
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("{} point {}", integer_part, digits)
+    }
+
+    fn ordinal_value(&self, ordinal: &OrdinalValue) -> String {
+        match ordinal.direction {
+            OrdinalDirection::FromStart => self.ordinal(ordinal.value),
+            OrdinalDirection::FromEnd if ordinal.value == 1 => "last".to_string(),
+            OrdinalDirection::FromEnd => format!("{} to last", self.ordinal(ordinal.value)),
+        }
+    }
+
+    fn percentage(&self, value: f64) -> String {
+        format!("{} percent", self.decimal(value))
+    }
+
+    fn year(&self, year: i32) -> String {
+        let (century, rest) = (year / 100, year % 100);
+        match (century, rest) {
+            (20, r) if r < 10 => self.cardinal(year as i64),
+            (11..=99, 0) => format!("{} hundred", self.cardinal(century as i64)),
+            (11..=99, r) if r < 10 => format!(
+                "{} oh {}",
+                self.cardinal(century as i64),
+                self.cardinal(r as i64)
+            ),
+            (11..=99, r) => format!(
+                "{} {}",
+                self.cardinal(century as i64),
+                self.cardinal(r as i64)
+            ),
+            _ => self.cardinal(year as i64),
+        }
+    }
+
+    fn month(&self, datetime: &DatetimeComponents) -> String {
+        format!(
+            "{} {}",
+            EN_MONTHS[(datetime.month - 1) as usize],
+            self.year(datetime.year)
+        )
+    }
+
+    fn date(&self, datetime: &DatetimeComponents, date_format: DateFormat) -> String {
+        let date = format!(
+            "{} {}",
+            EN_MONTHS[(datetime.month - 1) as usize],
+            self.ordinal(datetime.day as i64)
+        );
+        match date_format {
+            DateFormat::Long => format!("{} {}", date, self.year(datetime.year)),
+            DateFormat::Short => date,
+        }
+    }
+
+    fn time_of_day(&self, datetime: &DatetimeComponents, clock_format: ClockFormat) -> String {
+        let (hour, minute) = (datetime.hour as i64, datetime.minute as i64);
+        let minutes = match minute {
+            0 => None,
+            1..=9 => Some(format!("oh {}", self.cardinal(minute))),
+            _ => Some(self.cardinal(minute)),
+        };
+        match clock_format {
+            ClockFormat::TwelveHour => {
+                let period = if hour < 12 { "am" } else { "pm" };
+                let hour = match hour % 12 {
+                    0 => 12,
+                    h => h,
+                };
+                match minutes {
+                    Some(minutes) => format!("{} {} {}", self.cardinal(hour), minutes, period),
+                    None => format!("{} {}", self.cardinal(hour), period),
+                }
+            }
+            ClockFormat::TwentyFourHour => match minutes {
+                Some(minutes) => format!("{} {}", self.cardinal(hour), minutes),
+                None => format!("{} hundred", self.cardinal(hour)),
+            },
+        }
+    }
+
+    fn date_and_time(&self, date: &str, time: &str) -> String {
+        format!("{} at {}", date, time)
+    }
+
+    fn interval(&self, from: Option<String>, to: Option<String>) -> String {
+        match (from, to) {
+            (Some(from), Some(to)) => format!("from {} to {}", from, to),
+            (Some(from), None) => format!("from {}", from),
+            (None, Some(to)) => format!("until {}", to),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn amount_of_money(&self, amount: &AmountOfMoneyValue) -> String {
+        let names = match amount.unit.as_deref() {
+            Some("$") | Some("USD") => Some((("dollar", "dollars"), Some(("cent", "cents")))),
+            Some("€") | Some("EUR") => Some((("euro", "euros"), Some(("cent", "cents")))),
+            Some("£") | Some("GBP") => Some((("pound", "pounds"), Some(("penny", "pence")))),
+            Some("¥") | Some("JPY") => Some((("yen", "yen"), None)),
+            Some("cent") => Some((("cent", "cents"), None)),
+            _ => None,
+        };
+        let exponent = amount.minor_unit_exponent();
+        let major_units = amount.minor_units / 10_i64.pow(exponent);
+        let minor_units = amount.minor_units % 10_i64.pow(exponent);
+        match names {
+            Some(((major_singular, major_plural), minor_names)) => {
+                let major_name = if major_units.abs() == 1 {
+                    major_singular
+                } else {
+                    major_plural
+                };
+                let major = format!("{} {}", self.cardinal(major_units), major_name);
+                match minor_names {
+                    Some((minor_singular, minor_plural)) if minor_units != 0 => {
+                        let minor_name = if minor_units.abs() == 1 {
+                            minor_singular
+                        } else {
+                            minor_plural
+                        };
+                        format!(
+                            "{} and {} {}",
+                            major,
+                            self.cardinal(minor_units.abs()),
+                            minor_name
+                        )
+                    }
+                    _ => major,
+                }
+            }
+            None => {
+                let value = self.decimal(widen(amount.value));
+                match amount.unit {
+                    Some(ref unit) => format!("{} {}", value, unit),
+                    None => value,
+                }
+            }
+        }
+    }
+
+    fn temperature(&self, temperature: &TemperatureValue) -> String {
+        let value = widen(temperature.value);
+        let degrees = if value.abs() == 1.0 {
+            "degree"
+        } else {
+            "degrees"
+        };
+        let value = self.decimal(value);
+        match temperature.unit.as_deref() {
+            Some("celsius") => format!("{} {} Celsius", value, degrees),
+            Some("fahrenheit") => format!("{} {} Fahrenheit", value, degrees),
+            Some("kelvin") => format!("{} kelvin", value),
+            _ => format!("{} {}", value, degrees),
+        }
+    }
+
+    fn duration(&self, duration: &DurationValue) -> String {
+        let components = duration_components(duration)
+            .into_iter()
+            .map(|(count, grain)| {
+                let unit = match grain {
+                    Grain::Year => "year",
+                    Grain::Quarter => "quarter",
+                    Grain::Month => "month",
+                    Grain::Week => "week",
+                    Grain::Day => "day",
+                    Grain::Hour => "hour",
+                    Grain::Minute => "minute",
+                    Grain::Second => "second",
+                };
+                let plural = if count.abs() == 1 { "" } else { "s" };
+                format!("{} {}{}", self.cardinal(count), unit, plural)
+            })
+            .collect::<Vec<_>>();
+        if components.is_empty() {
+            "zero seconds".to_string()
+        } else {
+            enumerate(components, "and")
+        }
+    }
+
+    fn relative_change(
+        &self,
+        direction: ChangeDirection,
+        value: f64,
+        unit: &Option<String>,
+    ) -> String {
+        let sign = match direction {
+            ChangeDirection::Increase => "plus",
+            ChangeDirection::Decrease => "minus",
+        };
+        format!("{} {}", sign, change_size(self, value, unit))
+    }
+}
+
+const FR_UNITS: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+
+const FR_TENS: [&str; 7] = [
+    "",
+    "dix",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+
+const FR_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+struct FrenchVerbalizer;
+
+impl FrenchVerbalizer {
+    fn below_hundred(&self, value: i64, is_final: bool) -> String {
+        match value {
+            0..=16 => FR_UNITS[value as usize].to_string(),
+            17..=19 => format!("dix-{}", FR_UNITS[(value - 10) as usize]),
+            80 if is_final => "quatre-vingts".to_string(),
+            80 => "quatre-vingt".to_string(),
+            _ => {
+                let (tens, units) = match value / 10 {
+                    7 => (6, value - 60),
+                    8 | 9 => (8, value - 80),
+                    t => (t, value % 10),
+                };
+                let tens_words = if tens == 8 {
+                    "quatre-vingt"
+                } else {
+                    FR_TENS[tens as usize]
+                };
+                match units {
+                    0 => tens_words.to_string(),
+                    1 | 11 if tens != 8 => {
+                        format!("{} et {}", tens_words, self.below_hundred(units, is_final))
+                    }
+                    _ => format!("{}-{}", tens_words, self.below_hundred(units, is_final)),
+                }
+            }
+        }
+    }
+
+    fn below_thousand(&self, value: i64, is_final: bool) -> String {
+        let (hundreds, rest) = (value / 100, value % 100);
+        let hundreds_words = match hundreds {
+            0 => None,
+            1 => Some("cent".to_string()),
+            h if rest == 0 && is_final => Some(format!("{} cents", FR_UNITS[h as usize])),
+            h => Some(format!("{} cent", FR_UNITS[h as usize])),
+        };
+        match (hundreds_words, rest) {
+            (None, rest) => self.below_hundred(rest, is_final),
+            (Some(hundreds_words), 0) => hundreds_words,
+            (Some(hundreds_words), rest) => {
+                format!("{} {}", hundreds_words, self.below_hundred(rest, is_final))
+            }
+        }
+    }
+}
+
+impl Verbalizer for FrenchVerbalizer {
+    fn cardinal(&self, value: i64) -> String {
+        if value == 0 {
+            return "zéro".to_string();
+        }
+        if value < 0 {
+            return format!("moins {}", self.cardinal(-value));
+        }
+        let scales = [
+            (1_000_000_000_000, "billion"),
+            (1_000_000_000, "milliard"),
+            (1_000_000, "million"),
+        ];
+        let mut words = vec![];
+        let mut rest = value;
+        for (scale, scale_name) in scales.iter() {
+            if rest >= *scale {
+                let count = rest / scale;
+                let plural = if count > 1 { "s" } else { "" };
+                words.push(format!("{} {}{}", self.cardinal(count), scale_name, plural));
+                rest %= scale;
+            }
+        }
+        if rest >= 1000 {
+            match rest / 1000 {
+                1 => words.push("mille".to_string()),
+                thousands => words.push(format!("{} mille", self.below_thousand(thousands, false))),
+            }
+            rest %= 1000;
+        }
+        if rest > 0 {
+            words.push(self.below_thousand(rest, true));
+        }
+        words.join(" ")
+    }
+
+    fn ordinal(&self, value: i64) -> String {
+        if value == 1 {
+            return "premier".to_string();
+        }
+        let cardinal = self.cardinal(value);
+        let stem = match cardinal.as_str() {
+            c if c.ends_with("cinq") => format!("{}u", c),
+            c if c.ends_with("neuf") => format!("{}v", &c[..c.len() - 1]),
+            c if c.ends_with("cents") || c.ends_with("vingts") => c[..c.len() - 1].to_string(),
+            c if c.ends_with('e') => c[..c.len() - 1].to_string(),
+            c => c.to_string(),
+        };
+        format!("{}ième", stem)
+    }
+
+    fn decimal(&self, value: f64) -> String {
+        let integer_part = self.cardinal(value.trunc() as i64);
+        let integer_part = if value < 0.0 && value.trunc() == 0.0 {
+            format!("moins {}", integer_part)
+        } else {
+            integer_part
+        };
+        let digits = decimal_digits(value);
+        if digits.is_empty() {
+            return integer_part;
+        }
+        let leading_zeros = digits.chars().take_while(|c| *c == '0').count();
+        let mut decimal_words = vec!["zéro"; leading_zeros]
+            .into_iter()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>();
+        if let Ok(rest) = digits[leading_zeros..].parse::<i64>() {
+            decimal_words.push(self.cardinal(rest));
+        }
+        format!("{} virgule {}", integer_part, decimal_words.join(" "))
+    }
+
+    fn ordinal_value(&self, ordinal: &OrdinalValue) -> String {
+        match ordinal.direction {
+            OrdinalDirection::FromStart => self.ordinal(ordinal.value),
+            OrdinalDirection::FromEnd if ordinal.value == 1 => "dernier".to_string(),
+            OrdinalDirection::FromEnd if ordinal.value == 2 => "avant-dernier".to_string(),
+            OrdinalDirection::FromEnd => {
+                format!("{} en partant de la fin", self.ordinal(ordinal.value))
+            }
+        }
+    }
+
+    fn percentage(&self, value: f64) -> String {
+        format!("{} pour cent", self.decimal(value))
+    }
+
+    fn year(&self, year: i32) -> String {
+        self.cardinal(year as i64)
+    }
+
+    fn month(&self, datetime: &DatetimeComponents) -> String {
+        format!(
+            "{} {}",
+            FR_MONTHS[(datetime.month - 1) as usize],
+            self.year(datetime.year)
+        )
+    }
+
+    fn date(&self, datetime: &DatetimeComponents, date_format: DateFormat) -> String {
+        let day = match datetime.day {
+            1 => "premier".to_string(),
+            d => self.cardinal(d as i64),
+        };
+        let date = format!("le {} {}", day, FR_MONTHS[(datetime.month - 1) as usize]);
+        match date_format {
+            DateFormat::Long => format!("{} {}", date, self.year(datetime.year)),
+            DateFormat::Short => date,
+        }
+    }
+
+    fn time_of_day(&self, datetime: &DatetimeComponents, clock_format: ClockFormat) -> String {
+        let (hour, minute) = (datetime.hour as i64, datetime.minute as i64);
+        let minutes = if minute == 0 {
+            String::new()
+        } else {
+            format!(" {}", self.cardinal(minute))
+        };
+        let hours = |h: i64| match h {
+            0 => "zéro heure".to_string(),
+            1 => "une heure".to_string(),
+            h => format!("{} heures", self.cardinal(h)),
+        };
+        match clock_format {
+            ClockFormat::TwelveHour => match hour {
+                0 => format!("minuit{}", minutes),
+                12 => format!("midi{}", minutes),
+                1..=11 => format!("{}{} du matin", hours(hour), minutes),
+                13..=17 => format!("{}{} de l'après-midi", hours(hour - 12), minutes),
+                _ => format!("{}{} du soir", hours(hour - 12), minutes),
+            },
+            ClockFormat::TwentyFourHour => format!("{}{}", hours(hour), minutes),
+        }
+    }
+
+    fn date_and_time(&self, date: &str, time: &str) -> String {
+        format!("{} à {}", date, time)
+    }
+
+    fn interval(&self, from: Option<String>, to: Option<String>) -> String {
+        let from = from.map(|from| match from.strip_prefix("le ") {
+            Some(date) => format!("du {}", date),
+            None => format!("de {}", from),
+        });
+        let to = to.map(|to| match to.strip_prefix("le ") {
+            Some(date) => format!("au {}", date),
+            None => format!("à {}", to),
+        });
+        match (from, to) {
+            (Some(from), Some(to)) => format!("{} {}", from, to),
+            (Some(from), None) => format!("à partir {}", from),
+            (None, Some(to)) => format!("jusqu'{}", to),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn amount_of_money(&self, amount: &AmountOfMoneyValue) -> String {
+        let names = match amount.unit.as_deref() {
+            Some("$") | Some("USD") => Some((("dollar", "dollars"), Some(("cent", "cents")))),
+            Some("€") | Some("EUR") => Some((("euro", "euros"), Some(("centime", "centimes")))),
+            Some("£") | Some("GBP") => Some((("livre", "livres"), Some(("penny", "pence")))),
+            Some("¥") | Some("JPY") => Some((("yen", "yens"), None)),
+            Some("cent") => Some((("centime", "centimes"), None)),
+            _ => None,
+        };
+        let exponent = amount.minor_unit_exponent();
+        let major_units = amount.minor_units / 10_i64.pow(exponent);
+        let minor_units = amount.minor_units % 10_i64.pow(exponent);
+        match names {
+            Some(((major_singular, major_plural), minor_names)) => {
+                let major_name = if major_units.abs() < 2 {
+                    major_singular
+                } else {
+                    major_plural
+                };
+                let major = format!("{} {}", self.cardinal(major_units), major_name);
+                match minor_names {
+                    Some((minor_singular, minor_plural)) if minor_units != 0 => {
+                        let minor_name = if minor_units.abs() < 2 {
+                            minor_singular
+                        } else {
+                            minor_plural
+                        };
+                        format!(
+                            "{} et {} {}",
+                            major,
+                            self.cardinal(minor_units.abs()),
+                            minor_name
+                        )
+                    }
+                    _ => major,
+                }
+            }
+            None => {
+                let value = self.decimal(widen(amount.value));
+                match amount.unit {
+                    Some(ref unit) => format!("{} {}", value, unit),
+                    None => value,
+                }
+            }
+        }
+    }
+
+    fn temperature(&self, temperature: &TemperatureValue) -> String {
+        let value = widen(temperature.value);
+        let degrees = if value.abs() < 2.0 {
+            "degré"
+        } else {
+            "degrés"
+        };
+        let value = self.decimal(value);
+        match temperature.unit.as_deref() {
+            Some("celsius") => format!("{} {} Celsius", value, degrees),
+            Some("fahrenheit") => format!("{} {} Fahrenheit", value, degrees),
+            Some("kelvin") => format!("{} kelvins", value),
+            _ => format!("{} {}", value, degrees),
+        }
+    }
+
+    fn duration(&self, duration: &DurationValue) -> String {
+        let components = duration_components(duration)
+            .into_iter()
+            .map(|(count, grain)| {
+                let (singular, plural) = match grain {
+                    Grain::Year => ("an", "ans"),
+                    Grain::Quarter => ("trimestre", "trimestres"),
+                    Grain::Month => ("mois", "mois"),
+                    Grain::Week => ("semaine", "semaines"),
+                    Grain::Day => ("jour", "jours"),
+                    Grain::Hour => ("heure", "heures"),
+                    Grain::Minute => ("minute", "minutes"),
+                    Grain::Second => ("seconde", "secondes"),
+                };
+                let is_feminine = matches!(
+                    grain,
+                    Grain::Week | Grain::Hour | Grain::Minute | Grain::Second
+                );
+                match count {
+                    1 if is_feminine => format!("une {}", singular),
+                    1 | -1 => format!("{} {}", self.cardinal(count), singular),
+                    _ => format!("{} {}", self.cardinal(count), plural),
+                }
+            })
+            .collect::<Vec<_>>();
+        if components.is_empty() {
+            "zéro seconde".to_string()
+        } else {
+            enumerate(components, "et")
+        }
+    }
+
+    fn relative_change(
+        &self,
+        direction: ChangeDirection,
+        value: f64,
+        unit: &Option<String>,
+    ) -> String {
+        let sign = match direction {
+            ChangeDirection::Increase => "plus",
+            ChangeDirection::Decrease => "moins",
+        };
+        format!("{} {}", sign, change_size(self, value, unit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbalize_in_english() {
+        // Given
+        let options = VerbalizationOptions::default();
+        let temperature = SlotValue::Temperature(TemperatureValue {
+            value: 23.0,
+            unit: Some("celsius".to_string()),
+            mode: TemperatureMode::Absolute,
+        });
+        let decimal_temperature = SlotValue::Temperature(TemperatureValue {
+            value: 20.3,
+            unit: Some("celsius".to_string()),
+            mode: TemperatureMode::Absolute,
+        });
+        let instant_time = SlotValue::InstantTime(InstantTimeValue {
+            value: "2017-06-13 18:05:00 +02:00".to_string(),
+            grain: Grain::Minute,
+            precision: Precision::Exact,
+        });
+        let amount = SlotValue::AmountOfMoney(AmountOfMoneyValue::from_minor_units(
+            1005,
+            Precision::Exact,
+            Some("$".to_string()),
+        ));
+        let number = SlotValue::Number(NumberValue { value: 1_234_567.5 });
+        let code = SlotValue::AlphanumericCode(StringValue::from("AB12"));
+
+        // When
+        let short_time = instant_time.verbalize(
+            Language::EN,
+            VerbalizationOptions {
+                clock_format: ClockFormat::TwentyFourHour,
+                date_format: DateFormat::Short,
+            },
+        );
+
+        // Then
+        assert_eq!(
+            "twenty-three degrees Celsius",
+            temperature.verbalize(Language::EN, options).unwrap()
+        );
+        assert_eq!(
+            "twenty point three degrees Celsius",
+            decimal_temperature
+                .verbalize(Language::EN, options)
+                .unwrap()
+        );
+        assert_eq!(
+            "June thirteenth twenty seventeen at six oh five pm",
+            instant_time.verbalize(Language::EN, options).unwrap()
+        );
+        assert_eq!("June thirteenth at eighteen oh five", short_time.unwrap());
+        assert_eq!(
+            "ten dollars and five cents",
+            amount.verbalize(Language::EN, options).unwrap()
+        );
+        assert_eq!(
+            "one million two hundred thirty-four thousand five hundred sixty-seven point five",
+            number.verbalize(Language::EN, options).unwrap()
+        );
+        assert_eq!("A B 1 2", code.verbalize(Language::EN, options).unwrap());
+    }
+
+    #[test]
+    fn test_verbalize_in_french() {
+        // Given
+        let options = VerbalizationOptions {
+            clock_format: ClockFormat::TwentyFourHour,
+            date_format: DateFormat::Long,
+        };
+        let interval = SlotValue::TimeInterval(TimeIntervalValue {
+            from: Some("2017-06-01 00:00:00 +02:00".to_string()),
+            to: Some("2017-06-13 00:00:00 +02:00".to_string()),
+            to_inclusive: false,
+        });
+        let ordinal = SlotValue::Ordinal(OrdinalValue {
+            value: 81,
+            direction: OrdinalDirection::FromStart,
+        });
+        let duration = SlotValue::Duration(DurationValue {
+            years: 0,
+            quarters: 0,
+            months: 0,
+            weeks: 0,
+            days: 0,
+            hours: 1,
+            minutes: 71,
+            seconds: 0,
+            precision: Precision::Exact,
+        });
+
+        // Then
+        assert_eq!(
+            "du premier juin deux mille dix-sept au douze juin deux mille dix-sept",
+            interval.verbalize(Language::FR, options).unwrap()
+        );
+        let midnight = SlotValue::InstantTime(InstantTimeValue {
+            value: "2017-06-13 00:00:00 +02:00".to_string(),
+            grain: Grain::Minute,
+            precision: Precision::Exact,
+        });
+        assert_eq!(
+            "le treize juin deux mille dix-sept à zéro heure",
+            midnight.verbalize(Language::FR, options).unwrap()
+        );
+        let evening = SlotValue::TimeInterval(TimeIntervalValue {
+            from: Some("2017-06-12 18:00:00 +02:00".to_string()),
+            to: Some("2017-06-13 00:00:00 +02:00".to_string()),
+            to_inclusive: false,
+        });
+        assert_eq!(
+            "du douze juin deux mille dix-sept à dix-huit heures au treize juin deux mille dix-sept à zéro heure",
+            evening.verbalize(Language::FR, options).unwrap()
+        );
+        assert_eq!(
+            "quatre-vingt-unième",
+            ordinal.verbalize(Language::FR, options).unwrap()
+        );
+        assert_eq!(
+            "une heure et soixante et onze minutes",
+            duration.verbalize(Language::FR, options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verbalize_in_unsupported_language() {
+        // Given
+        let number = SlotValue::Number(NumberValue { value: 3.0 });
+
+        // When
+        let verbalized = number.verbalize(Language::KO, VerbalizationOptions::default());
+
+        // Then
+        assert!(verbalized.is_err());
+    }
+}
@@ -0,0 +1,113 @@
+//! Conformance suite shared with the bindings
+//!
+//! Each file in `tests/conformance` describes an operation and lists cases made of a JSON input
+//! and of the expected JSON output, a `null` output meaning that the input must be rejected.
+//! Outputs are compared as JSON values, regardless of the order of the keys:
+//!
+//! - `*_ser_de`: the input is deserialized into the named type and serialized back
+//! - `slot_value_from_canonical`: the input is an object with a `kind` and a `canonical` string,
+//!   which is parsed into a slot value
+//! - `slot_value_format_human`: the input is an object with a slot `value` and a `language`, and
+//!   the output is the formatted string
+//!
+//! Bindings run the same files to check that they behave like this crate. When a change of
+//! behavior is intended, the outputs can be regenerated by running this test with the
+//! `UPDATE_CONFORMANCE` environment variable set, and reviewing the resulting diff.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use snips_nlu_ontology::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Serialize, Deserialize)]
+struct ConformanceFile {
+    operation: String,
+    description: String,
+    cases: Vec<ConformanceCase>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ConformanceCase {
+    description: String,
+    input: Value,
+    output: Value,
+}
+
+#[derive(Deserialize)]
+struct CanonicalInput {
+    kind: SlotValueKind,
+    canonical: String,
+}
+
+#[derive(Deserialize)]
+struct FormatInput {
+    value: SlotValue,
+    language: Language,
+}
+
+fn conformance_files() -> Vec<PathBuf> {
+    let conformance_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/conformance");
+    let mut files = fs::read_dir(conformance_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+fn ser_de<T>(input: &Value) -> Value
+where
+    T: Serialize + DeserializeOwned,
+{
+    serde_json::from_value::<T>(input.clone())
+        .map(|value| serde_json::to_value(value).unwrap())
+        .unwrap_or(Value::Null)
+}
+
+fn run(operation: &str, input: &Value) -> Value {
+    match operation {
+        "slot_value_ser_de" => ser_de::<SlotValue>(input),
+        "builtin_entity_ser_de" => ser_de::<BuiltinEntity>(input),
+        "intent_parser_result_ser_de" => ser_de::<IntentParserResult>(input),
+        "language_ser_de" => ser_de::<Language>(input),
+        "slot_value_from_canonical" => {
+            let input: CanonicalInput = serde_json::from_value(input.clone()).unwrap();
+            SlotValue::from_canonical(input.kind, &input.canonical)
+                .map(|value| serde_json::to_value(value).unwrap())
+                .unwrap_or(Value::Null)
+        }
+        "slot_value_format_human" => {
+            let input: FormatInput = serde_json::from_value(input.clone()).unwrap();
+            Value::String(input.value.format_human(input.language))
+        }
+        _ => panic!("Unknown conformance operation '{}'", operation),
+    }
+}
+
+#[test]
+fn test_conformance_suite() {
+    let update = std::env::var_os("UPDATE_CONFORMANCE").is_some();
+    for path in conformance_files() {
+        let content = fs::read_to_string(&path).unwrap();
+        let mut file: ConformanceFile = serde_json::from_str(&content)
+            .unwrap_or_else(|e| panic!("Cannot read {:?}: {}", path, e));
+        for case in file.cases.iter_mut() {
+            let output = run(&file.operation, &case.input);
+            if update {
+                case.output = output;
+            } else {
+                assert_eq!(case.output, output, "{:?}: {}", path, case.description);
+            }
+        }
+        if update {
+            let updated = serde_json::to_string_pretty(&file).unwrap();
+            fs::write(&path, updated + "\n").unwrap();
+        }
+    }
+}
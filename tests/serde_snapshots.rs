@@ -0,0 +1,135 @@
+//! JSON snapshots of the ontology types, as serialized by previous releases
+//!
+//! Each directory in `tests/snapshots` is named after the release which produced the snapshots
+//! it contains, the `unreleased` one holding the snapshots of the upcoming release, which is
+//! renamed when releasing. A change which breaks the serialization of a type is acknowledged by
+//! updating its snapshot in `unreleased`, rather than updating the ones of previous releases.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use snips_nlu_ontology::*;
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn snapshot_versions() -> Vec<PathBuf> {
+    let snapshots_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    let mut versions = fs::read_dir(snapshots_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    versions.sort();
+    versions
+}
+
+/// Checks that the snapshots of all the releases deserialize, and that the serialization of the
+/// latest snapshot matches it, only new keys being allowed
+///
+/// A change to the serialization which renames or drops a key, or changes a value, therefore
+/// requires a new snapshot in a newer directory, `unreleased` holding the snapshots of the
+/// upcoming release.
+fn assert_snapshot_compatibility<T>(snapshot_name: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let mut latest_snapshot = None;
+    for version_dir in snapshot_versions() {
+        let snapshot_path = version_dir.join(format!("{}.json", snapshot_name));
+        if !snapshot_path.exists() {
+            continue;
+        }
+        let snapshot = fs::read_to_string(&snapshot_path).unwrap();
+        let deserialized: T = serde_json::from_str(&snapshot).unwrap_or_else(|e| {
+            panic!("Cannot deserialize {:?}: {}", snapshot_path, e);
+        });
+        let serialized = serde_json::to_string(&deserialized).unwrap();
+        let round_tripped: T = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, round_tripped, "{:?}", snapshot_path);
+        latest_snapshot = Some((snapshot_path, snapshot, serialized));
+    }
+    let (snapshot_path, snapshot, serialized) =
+        latest_snapshot.unwrap_or_else(|| panic!("No snapshot named {}", snapshot_name));
+    let snapshot: Value = serde_json::from_str(&snapshot).unwrap();
+    let serialized: Value = serde_json::from_str(&serialized).unwrap();
+    assert_only_added_keys(&snapshot, &serialized, &snapshot_path.display().to_string());
+}
+
+/// Checks that the serialized value matches the snapshot, ignoring the keys which are not part of
+/// the snapshot
+fn assert_only_added_keys(snapshot: &Value, serialized: &Value, path: &str) {
+    match (snapshot, serialized) {
+        (Value::Object(snapshot), Value::Object(serialized)) => {
+            for (key, snapshot_value) in snapshot {
+                let serialized_value = serialized
+                    .get(key)
+                    .unwrap_or_else(|| panic!("{}: key '{}' is no longer serialized", path, key));
+                assert_only_added_keys(
+                    snapshot_value,
+                    serialized_value,
+                    &format!("{}.{}", path, key),
+                );
+            }
+        }
+        (Value::Array(snapshot), Value::Array(serialized)) => {
+            assert_eq!(snapshot.len(), serialized.len(), "{}", path);
+            for (index, (snapshot_value, serialized_value)) in
+                snapshot.iter().zip(serialized.iter()).enumerate()
+            {
+                assert_only_added_keys(
+                    snapshot_value,
+                    serialized_value,
+                    &format!("{}[{}]", path, index),
+                );
+            }
+        }
+        (Value::Number(snapshot), Value::Number(serialized)) => {
+            assert_eq!(snapshot.as_f64(), serialized.as_f64(), "{}", path)
+        }
+        _ => assert_eq!(snapshot, serialized, "{}", path),
+    }
+}
+
+#[test]
+fn test_slot_values_snapshots() {
+    assert_snapshot_compatibility::<Vec<SlotValue>>("slot_values");
+}
+
+#[test]
+fn test_intent_parser_result_snapshots() {
+    assert_snapshot_compatibility::<IntentParserResult>("intent_parser_result");
+}
+
+#[test]
+fn test_builtin_entities_snapshots() {
+    assert_snapshot_compatibility::<Vec<BuiltinEntity>>("builtin_entities");
+}
+
+#[test]
+fn test_languages_snapshots() {
+    assert_snapshot_compatibility::<Vec<Language>>("languages");
+}
+
+#[test]
+fn test_builtin_entity_kinds_snapshots() {
+    assert_snapshot_compatibility::<Vec<BuiltinEntityKind>>("builtin_entity_kinds");
+}
+
+#[test]
+fn test_added_keys_are_compatible() {
+    assert_only_added_keys(
+        &json!([{"kind": "Number", "value": 1.0}]),
+        &json!([{"kind": "Number", "value": 1.0, "unit": null}]),
+        "snapshot",
+    );
+}
+
+#[test]
+#[should_panic(expected = "key 'toInclusive' is no longer serialized")]
+fn test_dropped_keys_are_incompatible() {
+    assert_only_added_keys(
+        &json!({"from": null, "to": null, "toInclusive": false}),
+        &json!({"from": null, "to": null, "to_inclusive": false}),
+        "snapshot",
+    );
+}